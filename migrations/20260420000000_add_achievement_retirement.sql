@@ -0,0 +1,4 @@
+-- Retired achievements stay in the catalog so users who earned them keep them,
+-- but they no longer accrue progress.
+ALTER TABLE achievements ADD COLUMN is_retired BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE achievements ADD COLUMN retired_at TIMESTAMPTZ;
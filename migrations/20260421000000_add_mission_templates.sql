@@ -0,0 +1,25 @@
+-- Templates the mission rotation draws from when generating daily_missions.
+-- "{target}" in the description is replaced with the rolled target count.
+CREATE TABLE IF NOT EXISTS mission_templates (
+    id UUID PRIMARY KEY,
+    description VARCHAR(255) NOT NULL,
+    mission_type VARCHAR(50) NOT NULL,
+    min_target INT NOT NULL CHECK (min_target > 0),
+    max_target INT NOT NULL,
+    min_reward INT NOT NULL CHECK (min_reward >= 0),
+    max_reward INT NOT NULL,
+    weight INT NOT NULL DEFAULT 1 CHECK (weight > 0),
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (max_target >= min_target),
+    CHECK (max_reward >= min_reward)
+);
+
+ALTER TABLE daily_missions
+    ADD COLUMN template_id UUID REFERENCES mission_templates(id) ON DELETE SET NULL;
+
+-- Lets several instances run the scheduler without duplicating a day's missions.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_daily_missions_date_template
+    ON daily_missions(date, template_id);
+
+CREATE INDEX IF NOT EXISTS idx_daily_missions_date ON daily_missions(date);
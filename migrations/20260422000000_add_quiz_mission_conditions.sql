@@ -0,0 +1,9 @@
+-- Optional thresholds a quiz has to meet to count towards a Quiz mission.
+-- min_accuracy is a percentage (0-100), like quiz_history.accuracy.
+ALTER TABLE daily_missions
+    ADD COLUMN min_score INT CHECK (min_score >= 0),
+    ADD COLUMN min_accuracy DOUBLE PRECISION CHECK (min_accuracy BETWEEN 0 AND 100);
+
+ALTER TABLE mission_templates
+    ADD COLUMN min_score INT CHECK (min_score >= 0),
+    ADD COLUMN min_accuracy DOUBLE PRECISION CHECK (min_accuracy BETWEEN 0 AND 100);
@@ -0,0 +1,4 @@
+-- What an achievement counts, as a tagged JSON object, e.g. {"type": "cumulative_score"}.
+-- Existing achievements keep their old behaviour of counting every completed quiz.
+ALTER TABLE achievements
+    ADD COLUMN criteria JSONB NOT NULL DEFAULT '{"type": "quizzes_completed"}';
@@ -0,0 +1,11 @@
+-- Daily reading streak per user. Days are counted in the user's timezone,
+-- stored as a fixed UTC offset in minutes.
+CREATE TABLE IF NOT EXISTS user_streaks (
+    user_id UUID PRIMARY KEY REFERENCES engine_users(user_id) ON DELETE CASCADE,
+    current_streak INT NOT NULL DEFAULT 0 CHECK (current_streak >= 0),
+    longest_streak INT NOT NULL DEFAULT 0 CHECK (longest_streak >= 0),
+    last_active_date DATE,
+    freezes_available INT NOT NULL DEFAULT 0 CHECK (freezes_available >= 0),
+    utc_offset_minutes INT NOT NULL DEFAULT 0 CHECK (utc_offset_minutes BETWEEN -720 AND 840),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
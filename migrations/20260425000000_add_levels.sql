@@ -0,0 +1,34 @@
+-- Level curve: a user is at the highest level whose min_score their total_score reaches.
+-- Edit the rows to retune the curve; level 1 must start at 0.
+CREATE TABLE IF NOT EXISTS levels (
+    level INT PRIMARY KEY CHECK (level > 0),
+    title VARCHAR(100) NOT NULL,
+    min_score INT NOT NULL UNIQUE CHECK (min_score >= 0)
+);
+
+INSERT INTO levels (level, title, min_score) VALUES
+    (1, 'Pembaca Pemula', 0),
+    (2, 'Pembaca Tekun', 100),
+    (3, 'Penjelajah Berita', 300),
+    (4, 'Kutu Buku', 600),
+    (5, 'Analis Muda', 1000),
+    (6, 'Cendekiawan', 1500),
+    (7, 'Pakar Literasi', 2500),
+    (8, 'Maestro Bacaan', 4000),
+    (9, 'Legenda Yomu', 6000)
+ON CONFLICT (level) DO NOTHING;
+
+-- One row per level a user reached, written alongside the score change.
+-- seen_at stays NULL until the client has shown the level-up.
+CREATE TABLE IF NOT EXISTS user_level_ups (
+    user_id UUID NOT NULL REFERENCES shadow_users(user_id) ON DELETE CASCADE,
+    level INT NOT NULL,
+    title VARCHAR(100) NOT NULL,
+    total_score INT NOT NULL,
+    reached_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    seen_at TIMESTAMPTZ,
+    PRIMARY KEY (user_id, level)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_level_ups_unseen
+    ON user_level_ups(user_id) WHERE seen_at IS NULL;
@@ -0,0 +1,6 @@
+-- Order of the badges a user pins on their profile (is_shown_on_profile).
+ALTER TABLE user_achievements
+    ADD COLUMN showcase_position INT CHECK (showcase_position > 0);
+
+CREATE INDEX IF NOT EXISTS idx_user_achievements_showcase
+    ON user_achievements(user_id) WHERE is_shown_on_profile;
@@ -0,0 +1,32 @@
+-- Missions run over a [starts_at, ends_at) window on the user's wall clock,
+-- split into daily or weekly instances, or kept whole for Custom (event) missions.
+-- `date` stays as the first day of the window for the rotation's unique index.
+ALTER TABLE daily_missions
+    ADD COLUMN period VARCHAR(20) NOT NULL DEFAULT 'Daily'
+        CHECK (period IN ('Daily', 'Weekly', 'Custom')),
+    ADD COLUMN starts_at TIMESTAMP,
+    ADD COLUMN ends_at TIMESTAMP;
+
+UPDATE daily_missions
+SET starts_at = date::timestamp,
+    ends_at = date::timestamp + INTERVAL '1 day';
+
+ALTER TABLE daily_missions
+    ALTER COLUMN starts_at SET NOT NULL,
+    ALTER COLUMN ends_at SET NOT NULL,
+    ADD CONSTRAINT daily_missions_window_check CHECK (ends_at > starts_at);
+
+CREATE INDEX IF NOT EXISTS idx_daily_missions_window ON daily_missions(starts_at, ends_at);
+
+-- Progress and claims are kept per period instance.
+ALTER TABLE user_missions ADD COLUMN period_start TIMESTAMP;
+
+UPDATE user_missions um
+SET period_start = dm.starts_at
+FROM daily_missions dm
+WHERE dm.id = um.mission_id;
+
+ALTER TABLE user_missions
+    ALTER COLUMN period_start SET NOT NULL,
+    DROP CONSTRAINT user_missions_pkey,
+    ADD PRIMARY KEY (user_id, mission_id, period_start);
@@ -0,0 +1,23 @@
+-- Append-only record of every point award. shadow_users.total_score is a
+-- projection of this table: each award inserts a row and bumps the total in
+-- the same statement, and the admin recompute rebuilds the total from it.
+CREATE TABLE IF NOT EXISTS points_ledger (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES shadow_users(user_id) ON DELETE CASCADE,
+    delta INT NOT NULL,
+    source_type VARCHAR(32) NOT NULL
+        CHECK (source_type IN ('opening_balance', 'quiz', 'mission_claim', 'achievement', 'streak_milestone')),
+    source_id UUID,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_points_ledger_user
+    ON points_ledger(user_id, id DESC);
+
+-- Scores earned before the ledger existed become one opening entry each, so
+-- every user's ledger already sums to their total.
+INSERT INTO points_ledger (user_id, delta, source_type, occurred_at)
+SELECT user_id, total_score, 'opening_balance', created_at
+FROM shadow_users
+WHERE total_score <> 0
+  AND NOT EXISTS (SELECT 1 FROM points_ledger pl WHERE pl.user_id = shadow_users.user_id);
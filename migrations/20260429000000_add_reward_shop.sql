@@ -0,0 +1,49 @@
+-- Reward shop: items bought with earned points.
+CREATE TABLE IF NOT EXISTS reward_items (
+    id UUID PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('StreakFreeze', 'Cosmetic', 'ScoreBoost')),
+    price INT NOT NULL CHECK (price > 0),
+    stock INT CHECK (stock >= 0),                          -- NULL means unlimited
+    per_user_limit INT CHECK (per_user_limit > 0),         -- NULL means no limit
+    boost_percent INT CHECK (boost_percent BETWEEN 1 AND 100),
+    boost_hours INT CHECK (boost_hours > 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((kind = 'ScoreBoost') = (boost_percent IS NOT NULL AND boost_hours IS NOT NULL))
+);
+
+-- Purchase history; the price is debited from points_ledger under the purchase id.
+CREATE TABLE IF NOT EXISTS reward_purchases (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES shadow_users(user_id) ON DELETE CASCADE,
+    item_id UUID NOT NULL REFERENCES reward_items(id),
+    price INT NOT NULL,
+    purchased_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_reward_purchases_user
+    ON reward_purchases(user_id, purchased_at DESC);
+CREATE INDEX IF NOT EXISTS idx_reward_purchases_user_item
+    ON reward_purchases(user_id, item_id);
+
+-- What each user holds; quantity counts purchases not yet used up.
+CREATE TABLE IF NOT EXISTS user_inventory (
+    user_id UUID NOT NULL REFERENCES shadow_users(user_id) ON DELETE CASCADE,
+    item_id UUID NOT NULL REFERENCES reward_items(id),
+    quantity INT NOT NULL CHECK (quantity >= 0),
+    active_until TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, item_id)
+);
+
+ALTER TABLE points_ledger DROP CONSTRAINT IF EXISTS points_ledger_source_type_check;
+ALTER TABLE points_ledger ADD CONSTRAINT points_ledger_source_type_check
+    CHECK (source_type IN ('opening_balance', 'quiz', 'mission_claim', 'achievement', 'streak_milestone', 'purchase'));
+
+-- Spending must never take a balance below zero. NOT VALID skips checking
+-- historical rows but still applies to every write from here on.
+ALTER TABLE shadow_users ADD CONSTRAINT shadow_users_total_score_non_negative
+    CHECK (total_score >= 0) NOT VALID;
@@ -0,0 +1,11 @@
+-- Achievement chains such as "Reader I/II/III": each tier names its chain and
+-- 1-based position. A tier unlocks once the tier below it is completed.
+ALTER TABLE achievements ADD COLUMN chain VARCHAR(100);
+ALTER TABLE achievements ADD COLUMN chain_tier INT;
+
+ALTER TABLE achievements
+    ADD CONSTRAINT achievements_chain_tier_check
+    CHECK ((chain IS NULL) = (chain_tier IS NULL) AND (chain_tier IS NULL OR chain_tier > 0));
+
+CREATE UNIQUE INDEX uq_achievements_chain_tier ON achievements(chain, chain_tier)
+    WHERE chain IS NOT NULL;
@@ -0,0 +1,7 @@
+-- Upstream event id of a quiz sync. Retried deliveries carry the same id, and the
+-- unique constraint lets the engine record and score each event only once.
+ALTER TABLE quiz_history ADD COLUMN event_id UUID;
+ALTER TABLE quiz_history ADD CONSTRAINT quiz_history_event_id_key UNIQUE (event_id);
+
+-- Result of the first delivery, returned again to a replay.
+ALTER TABLE quiz_history ADD COLUMN missions_updated INT;
@@ -0,0 +1,19 @@
+-- Events for the Java Core service, written in the same transaction as the change
+-- they describe and delivered afterwards by the outbox relay.
+CREATE TABLE IF NOT EXISTS engine_outbox (
+    id UUID PRIMARY KEY,
+    event_type VARCHAR(50) NOT NULL,
+    aggregate_id UUID NOT NULL,                            -- the user or clan the event is about
+    payload JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'delivered', 'dead_letter')),
+    attempts INT NOT NULL DEFAULT 0 CHECK (attempts >= 0),
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    delivered_at TIMESTAMPTZ
+);
+
+-- The relay only scans what is still waiting to go out.
+CREATE INDEX IF NOT EXISTS idx_engine_outbox_due
+    ON engine_outbox(next_attempt_at) WHERE status = 'pending';
@@ -0,0 +1,15 @@
+-- The user's timezone as a fixed UTC offset in minutes, synced from the Java
+-- core. NULL means it was never reported and DEFAULT_UTC_OFFSET_MINUTES applies.
+ALTER TABLE shadow_users
+    ADD COLUMN IF NOT EXISTS utc_offset_minutes INT
+        CHECK (utc_offset_minutes BETWEEN -720 AND 840);
+
+-- Until now the offset was kept with the reading streak; it moves to the user
+-- so that missions and streaks read "today" in the same zone.
+UPDATE shadow_users s
+SET utc_offset_minutes = st.utc_offset_minutes
+FROM user_streaks st
+WHERE st.user_id = s.user_id
+  AND s.utc_offset_minutes IS NULL;
+
+ALTER TABLE user_streaks DROP COLUMN IF EXISTS utc_offset_minutes;
@@ -0,0 +1,25 @@
+-- Quiz syncs held back by the fraud detectors. Nothing in a held quiz is
+-- credited until an admin approves it; rejected ones are kept for the record.
+CREATE TABLE IF NOT EXISTS quiz_quarantine (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES shadow_users(user_id) ON DELETE CASCADE,
+    article_id UUID NOT NULL,
+    score INT NOT NULL,
+    accuracy DECIMAL(5,2) NOT NULL,
+    event_id UUID UNIQUE,                                  -- redeliveries of a held event stay held
+    reason VARCHAR(30) NOT NULL
+        CHECK (reason IN ('rate_limit', 'impossible_frequency', 'article_spam', 'score_inconsistent')),
+    detail TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    quiz_id UUID,                                          -- quiz_history row credited on approval
+    received_at TIMESTAMPTZ NOT NULL,
+    reviewed_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_quiz_quarantine_status
+    ON quiz_quarantine(status, received_at DESC);
+
+-- The detectors read each user's most recent quizzes.
+CREATE INDEX IF NOT EXISTS idx_quiz_history_user_completed
+    ON quiz_history(user_id, completed_at DESC);
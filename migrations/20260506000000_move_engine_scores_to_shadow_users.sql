@@ -0,0 +1,15 @@
+-- User scores are kept in shadow_users.total_score, next to the points ledger.
+-- Scores the engine wrote to engine_users.total_score before the move are
+-- added to the user's shadow row (created when missing) and cleared, so each
+-- point is counted in one place only.
+INSERT INTO shadow_users (user_id, total_score, created_at)
+SELECT user_id, total_score, NOW()
+FROM engine_users
+WHERE total_score <> 0
+ON CONFLICT (user_id) DO UPDATE
+    SET total_score = shadow_users.total_score + EXCLUDED.total_score;
+
+UPDATE engine_users SET total_score = 0 WHERE total_score <> 0;
+
+COMMENT ON COLUMN engine_users.total_score IS
+    'Unused; user scores live in shadow_users.total_score.';
@@ -0,0 +1,9 @@
+-- When the user finished a held quiz. Approval credits the quiz as of this
+-- time, so its history row, streak day and mission period are the ones the
+-- user earned it in. Reviews held before the column existed were received
+-- the moment they were completed.
+ALTER TABLE quiz_quarantine ADD COLUMN IF NOT EXISTS completed_at TIMESTAMPTZ;
+
+UPDATE quiz_quarantine SET completed_at = received_at WHERE completed_at IS NULL;
+
+ALTER TABLE quiz_quarantine ALTER COLUMN completed_at SET NOT NULL;
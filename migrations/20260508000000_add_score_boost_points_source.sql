@@ -0,0 +1,9 @@
+-- Active score boosts from the reward shop add their extra to each quiz score
+-- as a separate ledger entry, so the ledger still shows what the quiz earned.
+ALTER TABLE points_ledger DROP CONSTRAINT IF EXISTS points_ledger_source_type_check;
+ALTER TABLE points_ledger ADD CONSTRAINT points_ledger_source_type_check
+    CHECK (source_type IN ('opening_balance', 'quiz', 'mission_claim', 'achievement', 'streak_milestone', 'purchase', 'score_boost'));
+
+-- Quiz syncs look up the user's boosts that are still running.
+CREATE INDEX IF NOT EXISTS idx_user_inventory_active
+    ON user_inventory(user_id, active_until) WHERE active_until IS NOT NULL;
@@ -5,21 +5,57 @@ pub mod modules;
 pub mod shared;
 
 // Re-export AppState for use in modules
+pub use config::AppConfig;
 pub use config::database::{init_postgres_pool, init_redis_pool};
+use modules::gamification::domain::repositories::{
+    AchievementRepository, MissionRepository, StreakRepository,
+};
+use modules::league::infrastructure::realtime::LeaderboardHub;
 use redis::aio::MultiplexedConnection;
 use serde::{Deserialize, Serialize};
 pub use shared::domain::base_error::AppError;
+use shared::domain::user_timezone::UserClock;
+use shared::infrastructure::database::PostgresUserTimezoneRepository;
 pub use shared::infrastructure::metrics::AppMetrics;
 pub use shared::utils::response::ApiResponse;
 use sqlx::PgPool;
 use std::sync::Arc;
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
     pub redis: MultiplexedConnection,
     pub metrics: Arc<AppMetrics>,
+    pub leaderboard_hub: LeaderboardHub,
+    pub config: Arc<AppConfig>,
+    pub mission_repo: Arc<dyn MissionRepository>,
+    pub achievement_repo: Arc<dyn AchievementRepository>,
+    pub streak_repo: Arc<dyn StreakRepository>,
+}
+
+impl AppState {
+    /// Resolves users' timezones from the database, falling back to
+    /// `DEFAULT_UTC_OFFSET_MINUTES`.
+    pub fn user_clock(&self) -> UserClock {
+        UserClock::new(
+            Arc::new(PostgresUserTimezoneRepository::new(self.db.clone())),
+            self.config.default_timezone(),
+        )
+    }
+}
+
+impl std::fmt::Debug for AppState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AppState")
+            .field("db", &self.db)
+            .field("redis", &self.redis)
+            .field("metrics", &self.metrics)
+            .field("leaderboard_hub", &self.leaderboard_hub)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -40,9 +76,59 @@ pub struct HealthResponse {
         crate::modules::league::presentation::controllers::clan_controller::get_user_tier_handler,
         // League - Leaderboard
         crate::modules::league::presentation::controllers::score_controller::get_leaderboard_handler,
+        crate::modules::league::presentation::controllers::score_controller::stream_leaderboard_handler,
         // User Sync
         crate::modules::user_sync::presentation::controllers::internal_user_controller::sync_user_handler,
         crate::modules::user_sync::presentation::controllers::quiz_history_controller::sync_quiz_history_handler,
+        // Gamification - Missions
+        crate::modules::gamification::presentation::controllers::mission_controller::get_user_missions_handler,
+        crate::modules::gamification::presentation::controllers::mission_controller::get_today_missions_handler,
+        crate::modules::gamification::presentation::controllers::mission_controller::claim_mission_handler,
+        crate::modules::gamification::presentation::controllers::mission_controller::record_daily_login_handler,
+        // Gamification - Achievements
+        crate::modules::gamification::presentation::controllers::achievement_controller::list_achievements_handler,
+        crate::modules::gamification::presentation::controllers::achievement_controller::get_user_achievements_handler,
+        crate::modules::gamification::presentation::controllers::achievement_controller::get_user_achievements_internal_handler,
+        crate::modules::gamification::presentation::controllers::showcase_controller::get_showcase_handler,
+        crate::modules::gamification::presentation::controllers::showcase_controller::update_showcase_handler,
+        // Gamification - Streaks
+        crate::modules::gamification::presentation::controllers::streak_controller::get_user_streak_handler,
+        // Gamification - Levels
+        crate::modules::gamification::presentation::controllers::level_controller::get_user_level_handler,
+        crate::modules::gamification::presentation::controllers::level_controller::acknowledge_level_ups_handler,
+        // Gamification - Profile
+        crate::modules::gamification::presentation::controllers::profile_controller::get_user_profile_handler,
+        // Gamification - Points ledger
+        crate::modules::gamification::presentation::controllers::points_controller::get_points_ledger_handler,
+        // Gamification - Reward shop
+        crate::modules::gamification::presentation::controllers::reward_controller::list_reward_items_handler,
+        crate::modules::gamification::presentation::controllers::reward_controller::purchase_reward_handler,
+        crate::modules::gamification::presentation::controllers::reward_controller::get_inventory_handler,
+        crate::modules::gamification::presentation::controllers::reward_controller::get_purchase_history_handler,
+        // Admin - Achievement catalog
+        crate::modules::gamification::presentation::controllers::admin_achievement_controller::list_achievement_catalog_handler,
+        crate::modules::gamification::presentation::controllers::admin_achievement_controller::create_achievement_handler,
+        crate::modules::gamification::presentation::controllers::admin_achievement_controller::update_achievement_handler,
+        crate::modules::gamification::presentation::controllers::admin_achievement_controller::retire_achievement_handler,
+        crate::modules::gamification::presentation::controllers::admin_achievement_controller::backfill_achievements_handler,
+        crate::modules::gamification::presentation::controllers::admin_mission_controller::list_mission_templates_handler,
+        crate::modules::gamification::presentation::controllers::admin_mission_controller::create_mission_template_handler,
+        crate::modules::gamification::presentation::controllers::admin_mission_controller::preview_mission_schedule_handler,
+        crate::modules::gamification::presentation::controllers::admin_mission_controller::generate_mission_schedule_handler,
+        crate::modules::gamification::presentation::controllers::admin_points_controller::verify_points_balances_handler,
+        crate::modules::gamification::presentation::controllers::admin_points_controller::recompute_points_balances_handler,
+        crate::modules::gamification::presentation::controllers::admin_reward_controller::list_reward_catalog_handler,
+        crate::modules::gamification::presentation::controllers::admin_reward_controller::create_reward_item_handler,
+        // Admin - Webhooks
+        crate::modules::webhooks::presentation::controllers::admin_webhook_controller::list_webhook_subscriptions_handler,
+        crate::modules::webhooks::presentation::controllers::admin_webhook_controller::create_webhook_subscription_handler,
+        crate::modules::webhooks::presentation::controllers::admin_webhook_controller::deactivate_webhook_subscription_handler,
+        crate::modules::webhooks::presentation::controllers::admin_webhook_controller::list_webhook_deliveries_handler,
+        crate::modules::webhooks::presentation::controllers::admin_webhook_controller::replay_webhook_deliveries_handler,
+        // Admin - Quiz Review Queue
+        crate::modules::user_sync::presentation::controllers::admin_quiz_review_controller::list_quiz_reviews_handler,
+        crate::modules::user_sync::presentation::controllers::admin_quiz_review_controller::approve_quiz_review_handler,
+        crate::modules::user_sync::presentation::controllers::admin_quiz_review_controller::reject_quiz_review_handler,
     ),
     components(
         schemas(
@@ -64,20 +150,92 @@ pub struct HealthResponse {
             crate::modules::user_sync::application::dto::SyncUserResponseDto,
             crate::modules::user_sync::application::dto::QuizHistoryRequestDto,
             crate::modules::user_sync::application::dto::QuizHistoryResponseDto,
+            crate::modules::user_sync::application::dto::QuizQuarantineDto,
+            crate::modules::user_sync::application::dto::QuizReviewDto,
             // User Sync Entities
             crate::modules::user_sync::domain::entities::shadow_user::ShadowUser,
             crate::modules::user_sync::domain::entities::quiz_history::QuizHistory,
+            // Gamification DTOs
+            crate::modules::gamification::application::dto::ClaimMissionDto,
+            crate::modules::gamification::application::dto::ClaimMissionResponseDto,
+            crate::modules::gamification::application::dto::UserMissionDto,
+            crate::modules::gamification::application::dto::TodayMissionsDto,
+            crate::modules::gamification::application::dto::DailyLoginResponseDto,
+            crate::modules::gamification::application::dto::AchievementDto,
+            crate::modules::gamification::application::dto::AchievementRequestDto,
+            crate::modules::gamification::application::dto::AchievementBackfillRequestDto,
+            crate::modules::gamification::application::dto::AchievementBackfillReportDto,
+            crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria,
+            crate::modules::gamification::application::dto::MissionTemplateDto,
+            crate::modules::gamification::application::dto::MissionTemplateRequestDto,
+            crate::modules::gamification::application::dto::ScheduledMissionDto,
+            crate::modules::gamification::application::dto::UserAchievementDto,
+            crate::modules::gamification::application::dto::AchievementChainDto,
+            crate::modules::gamification::application::dto::UserAchievementOverviewDto,
+            crate::modules::gamification::application::dto::ShowcaseBadgeDto,
+            crate::modules::gamification::application::dto::UpdateShowcaseRequestDto,
+            crate::modules::gamification::application::dto::UserStreakDto,
+            crate::modules::gamification::application::dto::UserLevelDto,
+            crate::modules::gamification::application::dto::LevelUpDto,
+            crate::modules::gamification::application::dto::LevelUpsSeenResponseDto,
+            crate::modules::gamification::application::dto::UserProfileDto,
+            crate::modules::gamification::application::dto::PointsLedgerDto,
+            crate::modules::gamification::application::dto::PointsLedgerEntryDto,
+            crate::modules::gamification::application::dto::PointsBalanceReportDto,
+            crate::modules::gamification::application::dto::BalanceMismatchDto,
+            crate::modules::gamification::application::dto::RewardItemDto,
+            crate::modules::gamification::application::dto::RewardItemRequestDto,
+            crate::modules::gamification::application::dto::PurchaseRewardDto,
+            crate::modules::gamification::application::dto::RewardPurchaseDto,
+            crate::modules::gamification::application::dto::InventoryItemDto,
+            // Webhook DTOs
+            crate::modules::webhooks::application::dto::WebhookSubscriptionRequestDto,
+            crate::modules::webhooks::application::dto::WebhookSubscriptionDto,
+            crate::modules::webhooks::application::dto::WebhookDeliveryDto,
+            crate::modules::webhooks::application::dto::DeliveryAttemptDto,
+            crate::modules::webhooks::application::dto::ReplayWebhookDeliveriesDto,
+            crate::modules::webhooks::application::dto::ReplayWebhookDeliveriesResponseDto,
         )
     ),
     tags(
         (name = "clans", description = "Clan management endpoints - create, join, and view clan details"),
         (name = "leaderboard", description = "Leaderboard endpoints for clan rankings by tier"),
-        (name = "User Sync", description = "User synchronization endpoints from Java backend")
+        (name = "User Sync", description = "User synchronization endpoints from Java backend"),
+        (name = "missions", description = "Daily mission progress and reward claims"),
+        (name = "achievements", description = "Achievement catalog and per-user progress"),
+        (name = "streaks", description = "Daily reading streaks and streak freezes"),
+        (name = "levels", description = "XP levels derived from total score, and level-up notices"),
+        (name = "profile", description = "Aggregated gamification profile for the profile screen"),
+        (name = "points", description = "Append-only ledger of every point award and spend"),
+        (name = "rewards", description = "Reward shop: spend points on items, inventory and purchase history"),
+        (name = "admin", description = "Catalog, mission rotation, points ledger, reward shop, webhook and quiz review maintenance, requires the x-admin-key header")
     ),
+    modifiers(&ApiKeySecurity),
     info(
         title = "Yomu Backend Rust API",
         version = env!("CARGO_PKG_VERSION"),
-        description = "Gamification engine API for Yomu learning platform. Provides clan management, leaderboards, and user synchronization capabilities."
+        description = "Gamification engine API for Yomu learning platform. Provides clan management, leaderboards, daily missions, achievements, and user synchronization capabilities."
     )
 )]
 pub struct ApiDoc;
+
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "admin_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(
+                    shared::infrastructure::http::admin_auth::ADMIN_KEY_HEADER,
+                ))),
+            );
+            components.add_security_scheme(
+                "internal_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(
+                    shared::infrastructure::http::internal_auth::INTERNAL_KEY_HEADER,
+                ))),
+            );
+        }
+    }
+}
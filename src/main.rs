@@ -1,10 +1,23 @@
-mod config;
-mod modules;
-mod shared;
+use yomu_backend_rust::{config, modules, shared};
 
+use crate::modules::gamification::infrastructure::database::postgres::{
+    PostgresAchievementRepository, PostgresMissionRepository, PostgresMissionTemplateRepository,
+    PostgresStreakRepository,
+};
+use crate::modules::gamification::infrastructure::scheduler::spawn_mission_rotation;
+use crate::modules::league::infrastructure::realtime::{LeaderboardHub, spawn_redis_relay};
+use crate::modules::webhooks::infrastructure::database::postgres::PostgresWebhookRepository;
+use crate::modules::webhooks::infrastructure::dispatcher::{
+    WebhookDispatcher, spawn_webhook_dispatcher,
+};
 use crate::shared::domain::base_error::AppError;
+use crate::shared::infrastructure::database::PostgresOutboxRepository;
+use crate::shared::infrastructure::http::admin_auth::require_admin_key;
+use crate::shared::infrastructure::http::client::HttpClient;
+use crate::shared::infrastructure::http::internal_auth::require_internal_key;
 use crate::shared::infrastructure::logging::init_logging;
 use crate::shared::infrastructure::metrics::routes::metrics_routes;
+use crate::shared::infrastructure::outbox::{OutboxRelay, spawn_outbox_relay};
 use crate::shared::infrastructure::telemetry::{init_telemetry, init_telemetry_subscriber};
 use crate::shared::utils::response::ApiResponse;
 use axum::{Extension, Router, extract::State, response::Json, routing::get};
@@ -125,10 +138,59 @@ async fn async_main(app_config: config::AppConfig) {
     let metrics = Arc::new(AppMetrics::new());
     let (prometheus_layer, _) = PrometheusMetricLayer::pair();
 
+    let leaderboard_hub = if app_config.leaderboard_pubsub == "local" {
+        LeaderboardHub::local()
+    } else {
+        let hub = LeaderboardHub::redis(redis_pool.clone());
+        match redis::Client::open(app_config.redis_url.as_str()) {
+            Ok(client) => {
+                spawn_redis_relay(client, hub.clone());
+            }
+            Err(e) => {
+                tracing::error!("Invalid Redis URL for leaderboard relay: {}", e);
+                std::process::exit(1);
+            }
+        }
+        hub
+    };
+
+    spawn_mission_rotation(
+        Arc::new(PostgresMissionTemplateRepository::new(db_pool.clone())),
+        app_config.mission_rotation(),
+        app_config.mission_rotation_days_ahead,
+    );
+
+    spawn_outbox_relay(
+        OutboxRelay::new(
+            Arc::new(PostgresOutboxRepository::new(db_pool.clone())),
+            HttpClient::new(
+                app_config.java_core_url.clone(),
+                app_config.java_core_api_key.clone(),
+            ),
+            app_config.outbox_retry_policy(),
+        ),
+        Duration::from_secs(app_config.outbox_relay_interval_secs.max(1)),
+    );
+
+    // Subscriber URLs are absolute, so the client carries no Java Core base URL or key.
+    spawn_webhook_dispatcher(
+        WebhookDispatcher::new(
+            Arc::new(PostgresWebhookRepository::new(db_pool.clone())),
+            HttpClient::new(String::new(), String::new()),
+            app_config.webhook_retry_policy(),
+        ),
+        Duration::from_secs(app_config.webhook_dispatch_interval_secs.max(1)),
+    );
+
     let state = AppState {
+        mission_repo: Arc::new(PostgresMissionRepository::new(db_pool.clone())),
+        achievement_repo: Arc::new(PostgresAchievementRepository::new(db_pool.clone())),
+        streak_repo: Arc::new(PostgresStreakRepository::new(db_pool.clone())),
         db: db_pool,
         redis: redis_pool,
         metrics: metrics.clone(),
+        leaderboard_hub,
+        config: Arc::new(app_config.clone()),
     };
 
     let middleware_stack = ServiceBuilder::new()
@@ -145,9 +207,24 @@ async fn async_main(app_config: config::AppConfig) {
                 .allow_headers(Any),
         );
 
-    let api_v1_router = Router::new().merge(modules::league::presentation::routes::league_routes());
-    let internal_api_router =
-        Router::new().merge(modules::user_sync::presentation::routes::user_sync_routes());
+    let api_v1_router = Router::new()
+        .merge(modules::league::presentation::routes::league_routes())
+        .merge(modules::gamification::presentation::routes::gamification_routes());
+    let internal_api_router = Router::new()
+        .merge(modules::user_sync::presentation::routes::user_sync_routes())
+        .merge(modules::gamification::presentation::routes::gamification_internal_routes())
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            require_internal_key,
+        ));
+    let admin_api_router = Router::new()
+        .merge(modules::gamification::presentation::routes::gamification_admin_routes())
+        .merge(modules::webhooks::presentation::routes::webhook_admin_routes())
+        .merge(modules::user_sync::presentation::routes::user_sync_admin_routes())
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            require_admin_key,
+        ));
 
     let swagger = SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi());
 
@@ -158,6 +235,7 @@ async fn async_main(app_config: config::AppConfig) {
         .merge(metrics_routes())
         .nest("/api/v1", api_v1_router)
         .nest("/api/internal", internal_api_router)
+        .nest("/api/admin", admin_api_router)
         .with_state(state)
         .layer(middleware_stack)
         .layer(Extension(metrics))
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AchievementDto {
+    pub id: Uuid,
+    pub name: String,
+    pub milestone_target: i32,
+    pub achievement_type: String,
+    pub reward_points: i32,
+    pub is_retired: bool,
+    pub retired_at: Option<DateTime<Utc>>,
+    pub criteria: AchievementCriteria,
+    pub chain: Option<String>,
+    pub chain_tier: Option<i32>,
+}
+
+impl From<&Achievement> for AchievementDto {
+    fn from(achievement: &Achievement) -> Self {
+        Self {
+            id: achievement.id(),
+            name: achievement.name().to_string(),
+            milestone_target: achievement.milestone_target(),
+            achievement_type: achievement.achievement_type().to_string(),
+            reward_points: achievement.reward_points(),
+            is_retired: achievement.is_retired(),
+            retired_at: achievement.retired_at(),
+            criteria: *achievement.criteria(),
+            chain: achievement.chain().map(|link| link.chain.clone()),
+            chain_tier: achievement.chain().map(|link| link.tier),
+        }
+    }
+}
+
+/// Admin payload for creating or updating a catalog entry.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AchievementRequestDto {
+    pub name: String,
+    pub milestone_target: i32,
+    /// One of `Common`, `Rare`, `Epic`, `Legendary` (case-insensitive).
+    pub achievement_type: String,
+    pub reward_points: i32,
+    /// What the achievement counts. New achievements default to counting
+    /// completed quizzes; updates keep the current criteria when omitted.
+    #[serde(default)]
+    pub criteria: Option<AchievementCriteria>,
+    /// Chain the achievement is a tier of, e.g. `Reader`; sent together with
+    /// `chain_tier`. Updates keep the current chain when both are omitted.
+    #[serde(default)]
+    pub chain: Option<String>,
+    /// 1-based tier within `chain`; targets must rise with the tier.
+    #[serde(default)]
+    pub chain_tier: Option<i32>,
+}
+
+/// An achievement from the catalog together with one user's progress on it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserAchievementDto {
+    pub achievement_id: Uuid,
+    pub name: String,
+    pub achievement_type: String,
+    pub reward_points: i32,
+    pub milestone_target: i32,
+    pub chain: Option<String>,
+    pub chain_tier: Option<i32>,
+    pub is_retired: bool,
+    pub current_progress: i32,
+    pub is_completed: bool,
+    pub is_shown_on_profile: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl UserAchievementDto {
+    pub fn new(achievement: &Achievement, progress: Option<&UserAchievement>) -> Self {
+        Self {
+            achievement_id: achievement.id(),
+            name: achievement.name().to_string(),
+            achievement_type: achievement.achievement_type().to_string(),
+            reward_points: achievement.reward_points(),
+            milestone_target: achievement.milestone_target(),
+            chain: achievement.chain().map(|link| link.chain.clone()),
+            chain_tier: achievement.chain().map(|link| link.tier),
+            is_retired: achievement.is_retired(),
+            current_progress: progress.map_or(0, |p| p.current_progress()),
+            is_completed: progress.is_some_and(|p| p.is_completed()),
+            is_shown_on_profile: progress.is_some_and(|p| p.is_shown_on_profile()),
+            completed_at: progress.and_then(|p| p.completed_at()),
+        }
+    }
+}
+
+/// One achievement chain with the user's place in it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AchievementChainDto {
+    pub chain: String,
+    pub total_tiers: i32,
+    /// Highest tier the user has completed; `None` before the first one.
+    pub current_tier: Option<UserAchievementDto>,
+    /// The tier being worked on; `None` once every tier is completed.
+    pub next_tier: Option<UserAchievementDto>,
+    pub current_progress: i32,
+    pub next_target: Option<i32>,
+}
+
+/// A user's achievements, chains grouped by their current tier.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserAchievementOverviewDto {
+    pub chains: Vec<AchievementChainDto>,
+    /// Achievements that are not part of a chain.
+    pub achievements: Vec<UserAchievementDto>,
+}
+
+/// Admin payload for replaying quiz history into achievement progress.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AchievementBackfillRequestDto {
+    /// Only this achievement, together with the rest of its chain. Every
+    /// achievement quiz history can count when omitted.
+    #[serde(default)]
+    pub achievement_id: Option<Uuid>,
+    /// Also credit the reward points of achievements the backfill completes.
+    #[serde(default)]
+    pub grant_rewards: bool,
+    /// Users per batch, 1 to 1000. Defaults to 100.
+    #[serde(default)]
+    pub batch_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AchievementBackfillReportDto {
+    /// The achievements evaluated, chain tiers included.
+    pub achievement_ids: Vec<Uuid>,
+    pub users_scanned: usize,
+    /// Progress rows raised by the backfill, completions included.
+    pub progress_updated: usize,
+    pub completed: usize,
+    /// Zero unless `grant_rewards` was set.
+    pub points_awarded: i64,
+}
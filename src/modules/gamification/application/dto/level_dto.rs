@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::level::{LevelProgress, LevelUp};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LevelUpDto {
+    pub level: i32,
+    pub title: String,
+    pub total_score: i32,
+    pub reached_at: DateTime<Utc>,
+}
+
+impl From<&LevelUp> for LevelUpDto {
+    fn from(level_up: &LevelUp) -> Self {
+        Self {
+            level: level_up.level,
+            title: level_up.title.clone(),
+            total_score: level_up.total_score,
+            reached_at: level_up.reached_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserLevelDto {
+    pub user_id: Uuid,
+    pub level: i32,
+    pub title: String,
+    pub total_score: i32,
+    pub level_min_score: i32,
+    /// Absent at the top level.
+    pub next_level_min_score: Option<i32>,
+    pub progress_percent: f64,
+    /// Level-ups not yet shown to the user; acknowledge them once displayed.
+    pub pending_level_ups: Vec<LevelUpDto>,
+}
+
+impl UserLevelDto {
+    pub fn new(user_id: Uuid, progress: LevelProgress, pending: &[LevelUp]) -> Self {
+        Self {
+            user_id,
+            level: progress.level,
+            title: progress.title,
+            total_score: progress.total_score,
+            level_min_score: progress.level_min_score,
+            next_level_min_score: progress.next_level_min_score,
+            progress_percent: progress.progress_percent,
+            pending_level_ups: pending.iter().map(LevelUpDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LevelUpsSeenResponseDto {
+    pub user_id: Uuid,
+    pub marked_seen: u64,
+}
@@ -0,0 +1,84 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::mission_period::MissionWindow;
+use crate::modules::gamification::domain::entities::user_mission::UserMission;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClaimMissionDto {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DailyLoginResponseDto {
+    pub user_id: Uuid,
+    pub missions_updated: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClaimMissionResponseDto {
+    pub user_id: Uuid,
+    pub mission_id: Uuid,
+    pub reward_points: i32,
+}
+
+/// A mission instance together with one user's progress on it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserMissionDto {
+    pub mission_id: Uuid,
+    pub description: String,
+    pub mission_type: String,
+    /// First day of the mission's window.
+    pub date: NaiveDate,
+    /// `Daily`, `Weekly` or `Custom`.
+    pub period: String,
+    /// The running period instance, on the user's clock; progress resets when it ends.
+    pub period_start: NaiveDateTime,
+    pub period_end: NaiveDateTime,
+    pub target_count: i32,
+    pub reward_points: i32,
+    pub current_progress: i32,
+    pub is_completed: bool,
+    pub is_claimed: bool,
+    /// Completed and not yet claimed.
+    pub is_claimable: bool,
+}
+
+impl UserMissionDto {
+    pub fn new(
+        mission: &DailyMission,
+        instance: &MissionWindow,
+        progress: Option<&UserMission>,
+    ) -> Self {
+        let current_progress = progress.map_or(0, |p| p.current_progress());
+        let is_completed = current_progress >= mission.target_count();
+        let is_claimed = progress.is_some_and(|p| p.is_claimed());
+        Self {
+            mission_id: mission.id(),
+            description: mission.description().to_string(),
+            mission_type: format!("{:?}", mission.mission_type()),
+            date: mission.date(),
+            period: format!("{:?}", mission.period()),
+            period_start: instance.starts_at,
+            period_end: instance.ends_at,
+            target_count: mission.target_count(),
+            reward_points: mission.reward_points(),
+            current_progress,
+            is_completed,
+            is_claimed,
+            is_claimable: is_completed && !is_claimed,
+        }
+    }
+}
+
+/// The missions of the user's current local day.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodayMissionsDto {
+    pub user_id: Uuid,
+    /// The user's local date the missions belong to.
+    pub date: NaiveDate,
+    pub missions: Vec<UserMissionDto>,
+}
@@ -0,0 +1,97 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+
+/// Body for creating a mission template. `description` may contain
+/// `{target}`, which is replaced with the rolled target count.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MissionTemplateRequestDto {
+    pub description: String,
+    /// `ReadArticle`, `Quiz` or `DailyLogin` (case-insensitive).
+    pub mission_type: String,
+    pub min_target: i32,
+    pub max_target: i32,
+    pub min_reward: i32,
+    pub max_reward: i32,
+    pub weight: i32,
+    /// Only for `Quiz` templates: minimum quiz score that counts.
+    #[serde(default)]
+    pub min_score: Option<i32>,
+    /// Only for `Quiz` templates: minimum accuracy (0-100) that counts.
+    #[serde(default)]
+    pub min_accuracy: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MissionTemplateDto {
+    pub id: Uuid,
+    pub description: String,
+    pub mission_type: String,
+    pub min_target: i32,
+    pub max_target: i32,
+    pub min_reward: i32,
+    pub max_reward: i32,
+    pub weight: i32,
+    pub is_active: bool,
+    pub min_score: Option<i32>,
+    pub min_accuracy: Option<f64>,
+}
+
+impl From<&MissionTemplate> for MissionTemplateDto {
+    fn from(template: &MissionTemplate) -> Self {
+        Self {
+            id: template.id,
+            description: template.description.clone(),
+            mission_type: format!("{:?}", template.mission_type),
+            min_target: template.min_target,
+            max_target: template.max_target,
+            min_reward: template.min_reward,
+            max_reward: template.max_reward,
+            weight: template.weight,
+            is_active: template.is_active,
+            min_score: template.quiz_conditions.min_score,
+            min_accuracy: template.quiz_conditions.min_accuracy,
+        }
+    }
+}
+
+/// One upcoming daily mission in the schedule preview.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledMissionDto {
+    /// Set once the mission is stored; `None` for missions the rotation would still generate.
+    pub mission_id: Option<Uuid>,
+    pub date: NaiveDate,
+    pub description: String,
+    pub mission_type: String,
+    pub target_count: i32,
+    pub reward_points: i32,
+    pub template_id: Option<Uuid>,
+    pub is_scheduled: bool,
+}
+
+impl ScheduledMissionDto {
+    pub fn scheduled(mission: &DailyMission) -> Self {
+        Self {
+            mission_id: Some(mission.id()),
+            is_scheduled: true,
+            ..Self::planned(mission)
+        }
+    }
+
+    pub fn planned(mission: &DailyMission) -> Self {
+        Self {
+            mission_id: None,
+            date: mission.date(),
+            description: mission.description().to_string(),
+            mission_type: format!("{:?}", mission.mission_type()),
+            target_count: mission.target_count(),
+            reward_points: mission.reward_points(),
+            template_id: mission.template_id(),
+            is_scheduled: false,
+        }
+    }
+}
@@ -1,2 +1,33 @@
 // Gamification DTOs - Request/Response shapes
+pub mod achievement_dto;
+pub mod level_dto;
+pub mod mission_dto;
+pub mod mission_template_dto;
+pub mod points_ledger_dto;
+pub mod profile_dto;
 pub mod quiz_sync;
+pub mod reward_dto;
+pub mod showcase_dto;
+pub mod streak_dto;
+
+pub use achievement_dto::{
+    AchievementBackfillReportDto, AchievementBackfillRequestDto, AchievementChainDto,
+    AchievementDto, AchievementRequestDto, UserAchievementDto, UserAchievementOverviewDto,
+};
+pub use level_dto::{LevelUpDto, LevelUpsSeenResponseDto, UserLevelDto};
+pub use mission_dto::{
+    ClaimMissionDto, ClaimMissionResponseDto, DailyLoginResponseDto, TodayMissionsDto,
+    UserMissionDto,
+};
+pub use mission_template_dto::{
+    MissionTemplateDto, MissionTemplateRequestDto, ScheduledMissionDto,
+};
+pub use points_ledger_dto::{
+    BalanceMismatchDto, PointsBalanceReportDto, PointsLedgerDto, PointsLedgerEntryDto,
+};
+pub use profile_dto::UserProfileDto;
+pub use reward_dto::{
+    InventoryItemDto, PurchaseRewardDto, RewardItemDto, RewardItemRequestDto, RewardPurchaseDto,
+};
+pub use showcase_dto::{ShowcaseBadgeDto, UpdateShowcaseRequestDto};
+pub use streak_dto::UserStreakDto;
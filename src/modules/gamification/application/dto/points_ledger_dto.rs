@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::points_ledger::{BalanceMismatch, LedgerEntry};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PointsLedgerEntryDto {
+    pub id: i64,
+    pub delta: i32,
+    /// opening_balance, quiz, mission_claim, achievement, streak_milestone or purchase.
+    pub source_type: String,
+    /// The quiz, mission, achievement or purchase behind the entry, when there is one.
+    pub source_id: Option<Uuid>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl From<&LedgerEntry> for PointsLedgerEntryDto {
+    fn from(ledger_entry: &LedgerEntry) -> Self {
+        Self {
+            id: ledger_entry.id,
+            delta: ledger_entry.entry.delta,
+            source_type: ledger_entry.entry.source.to_string(),
+            source_id: ledger_entry.entry.source_id,
+            occurred_at: ledger_entry.entry.occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PointsLedgerDto {
+    pub user_id: Uuid,
+    pub total_score: i32,
+    /// Newest first.
+    pub entries: Vec<PointsLedgerEntryDto>,
+    /// Pass as `before` to fetch the next page; absent on the last page.
+    pub next_before: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BalanceMismatchDto {
+    pub user_id: Uuid,
+    pub total_score: i64,
+    pub ledger_total: i64,
+    /// `total_score - ledger_total`.
+    pub difference: i64,
+}
+
+impl From<&BalanceMismatch> for BalanceMismatchDto {
+    fn from(mismatch: &BalanceMismatch) -> Self {
+        Self {
+            user_id: mismatch.user_id,
+            total_score: i64::from(mismatch.total_score),
+            ledger_total: mismatch.ledger_total,
+            difference: mismatch.difference(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PointsBalanceReportDto {
+    pub mismatched_users: usize,
+    /// Whether the mismatched totals were rewritten from the ledger.
+    pub repaired: bool,
+    pub mismatches: Vec<BalanceMismatchDto>,
+}
+
+impl PointsBalanceReportDto {
+    pub fn new(mismatches: &[BalanceMismatch], repaired: bool) -> Self {
+        Self {
+            mismatched_users: mismatches.len(),
+            repaired,
+            mismatches: mismatches.iter().map(BalanceMismatchDto::from).collect(),
+        }
+    }
+}
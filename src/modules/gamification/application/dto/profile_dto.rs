@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{ShowcaseBadgeDto, UserAchievementDto, UserLevelDto, UserMissionDto, UserStreakDto};
+use crate::modules::league::application::dto::user_tier_dto::UserTierDto;
+
+/// Everything the profile screen shows, gathered in one response.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserProfileDto {
+    pub user_id: Uuid,
+    pub total_score: i32,
+    pub level: UserLevelDto,
+    pub streak: UserStreakDto,
+    /// Absent when the user is not in a clan or the league data is unavailable.
+    pub clan: Option<UserTierDto>,
+    pub missions_today: Vec<UserMissionDto>,
+    /// Most recently completed achievements, newest first.
+    pub recent_unlocks: Vec<UserAchievementDto>,
+    pub showcase: Vec<ShowcaseBadgeDto>,
+}
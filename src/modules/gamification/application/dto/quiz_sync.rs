@@ -1,10 +1,38 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use uuid::Uuid;
 
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::shared::domain::user_timezone::UserTimezone;
+
 #[derive(Debug, Deserialize)]
 pub struct SyncQuizHistoryRequestDto {
     pub user_id: Uuid,
     pub article_id: Uuid,
     pub score: i32,
     pub accuracy: f64,
+    /// The user's resolved timezone; the streak day and the missions the quiz
+    /// counts towards are read on its clock.
+    #[serde(skip)]
+    pub timezone: UserTimezone,
+    /// When the user finished the quiz. The streak day, mission period and
+    /// achievement and reward timestamps are taken from it.
+    #[serde(skip, default = "Utc::now")]
+    pub completed_at: DateTime<Utc>,
+}
+
+impl SyncQuizHistoryRequestDto {
+    /// A synced quiz means the user read the article and then finished its quiz.
+    pub fn events(&self, first_read: bool) -> [GamificationEvent; 2] {
+        [
+            GamificationEvent::ArticleRead {
+                article_id: self.article_id,
+                first_read,
+            },
+            GamificationEvent::QuizCompleted {
+                score: self.score,
+                accuracy: self.accuracy,
+            },
+        ]
+    }
 }
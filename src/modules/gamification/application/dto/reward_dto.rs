@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::reward_item::RewardItem;
+use crate::modules::gamification::domain::entities::reward_purchase::{
+    InventoryItem, RewardPurchase,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RewardItemDto {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    /// StreakFreeze, Cosmetic or ScoreBoost.
+    pub kind: String,
+    pub price: i32,
+    /// Absent when unlimited.
+    pub stock: Option<i32>,
+    /// Absent when a user may buy it any number of times.
+    pub per_user_limit: Option<i32>,
+    pub boost_percent: Option<i32>,
+    pub boost_hours: Option<i32>,
+    pub is_active: bool,
+}
+
+impl From<&RewardItem> for RewardItemDto {
+    fn from(item: &RewardItem) -> Self {
+        Self {
+            id: item.id(),
+            name: item.name().to_string(),
+            description: item.description().to_string(),
+            kind: item.kind().to_string(),
+            price: item.price(),
+            stock: item.stock(),
+            per_user_limit: item.per_user_limit(),
+            boost_percent: item.boost().map(|b| b.percent),
+            boost_hours: item.boost().map(|b| b.hours),
+            is_active: item.is_active(),
+        }
+    }
+}
+
+/// Admin payload for adding an item to the shop.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RewardItemRequestDto {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// One of `StreakFreeze`, `Cosmetic`, `ScoreBoost` (case-insensitive).
+    pub kind: String,
+    pub price: i32,
+    #[serde(default)]
+    pub stock: Option<i32>,
+    #[serde(default)]
+    pub per_user_limit: Option<i32>,
+    /// Required for ScoreBoost items, together with `boost_hours`.
+    #[serde(default)]
+    pub boost_percent: Option<i32>,
+    #[serde(default)]
+    pub boost_hours: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PurchaseRewardDto {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RewardPurchaseDto {
+    pub id: Uuid,
+    pub item_id: Uuid,
+    pub item_name: String,
+    pub kind: String,
+    pub price: i32,
+    pub purchased_at: DateTime<Utc>,
+    /// When a score boost bought here runs out.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<&RewardPurchase> for RewardPurchaseDto {
+    fn from(purchase: &RewardPurchase) -> Self {
+        Self {
+            id: purchase.id,
+            item_id: purchase.item_id,
+            item_name: purchase.item_name.clone(),
+            kind: purchase.kind.to_string(),
+            price: purchase.price,
+            purchased_at: purchase.purchased_at,
+            expires_at: purchase.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InventoryItemDto {
+    pub item_id: Uuid,
+    pub name: String,
+    pub kind: String,
+    pub quantity: i32,
+    pub active_until: Option<DateTime<Utc>>,
+}
+
+impl From<&InventoryItem> for InventoryItemDto {
+    fn from(item: &InventoryItem) -> Self {
+        Self {
+            item_id: item.item_id,
+            name: item.name.clone(),
+            kind: item.kind.to_string(),
+            quantity: item.quantity,
+            active_until: item.active_until,
+        }
+    }
+}
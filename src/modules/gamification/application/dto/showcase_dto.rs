@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateShowcaseRequestDto {
+    /// Completed achievements to pin, in display order. An empty list clears the showcase.
+    pub achievement_ids: Vec<Uuid>,
+}
+
+/// A badge pinned on a user's profile.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShowcaseBadgeDto {
+    pub position: i32,
+    pub achievement_id: Uuid,
+    pub name: String,
+    /// Rarity: Common, Rare, Epic or Legendary.
+    pub achievement_type: String,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl ShowcaseBadgeDto {
+    pub fn new(achievement: &Achievement, progress: &UserAchievement) -> Self {
+        Self {
+            position: progress.showcase_position().unwrap_or_default(),
+            achievement_id: achievement.id(),
+            name: achievement.name().to_string(),
+            achievement_type: achievement.achievement_type().to_string(),
+            completed_at: progress.completed_at(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::user_streak::{StreakPolicy, UserStreak};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserStreakDto {
+    pub user_id: Uuid,
+    /// Zero once the missed days since `last_active_date` exceed the available freezes.
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub last_active_date: Option<NaiveDate>,
+    pub freezes_available: i32,
+    pub next_milestone_days: Option<i32>,
+    pub next_milestone_reward: Option<i32>,
+}
+
+impl UserStreakDto {
+    pub fn new(streak: &UserStreak, policy: &StreakPolicy, today: NaiveDate) -> Self {
+        let current_streak = streak.current_as_of(today);
+        let next_milestone = policy.next_milestone(current_streak);
+        Self {
+            user_id: streak.user_id,
+            current_streak,
+            longest_streak: streak.longest_streak,
+            last_active_date: streak.last_active_date,
+            freezes_available: streak.freezes_available,
+            next_milestone_days: next_milestone.map(|m| m.days),
+            next_milestone_reward: next_milestone.map(|m| m.reward_points),
+        }
+    }
+}
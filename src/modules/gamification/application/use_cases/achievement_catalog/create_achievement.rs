@@ -0,0 +1,157 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{check_chain_tier, parse_achievement_type, parse_chain_link};
+use crate::modules::gamification::application::dto::{AchievementDto, AchievementRequestDto};
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+
+pub struct CreateAchievementUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl CreateAchievementUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: AchievementRequestDto,
+    ) -> Result<AchievementDto, GamificationError> {
+        let achievement_type = parse_achievement_type(&dto.achievement_type)?;
+        let chain = parse_chain_link(dto.chain, dto.chain_tier)?;
+
+        let achievement = Achievement::new(
+            Uuid::new_v4(),
+            dto.name,
+            dto.milestone_target,
+            achievement_type,
+            dto.reward_points,
+        )
+        .and_then(|achievement| achievement.with_criteria(dto.criteria.unwrap_or_default()))
+        .and_then(|achievement| achievement.with_chain(chain))
+        .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+        check_chain_tier(self.repository.as_ref(), &achievement).await?;
+
+        self.repository.create_achievement(&achievement).await?;
+
+        Ok(AchievementDto::from(&achievement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::achievement_chain::ChainLink;
+    use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+
+    fn request(name: &str, achievement_type: &str, target: i32) -> AchievementRequestDto {
+        AchievementRequestDto {
+            name: name.to_string(),
+            milestone_target: target,
+            achievement_type: achievement_type.to_string(),
+            reward_points: 150,
+            criteria: None,
+            chain: None,
+            chain_tier: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_achievement_persists_valid_entry() {
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_create_achievement()
+            .withf(|ach| ach.name() == "Kutu Buku" && ach.reward_points() == 150)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateAchievementUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(request("Kutu Buku", "epic", 20))
+            .await
+            .unwrap();
+
+        assert_eq!(result.achievement_type, "Epic");
+        assert_eq!(result.criteria, AchievementCriteria::default());
+        assert!(!result.is_retired);
+    }
+
+    #[tokio::test]
+    async fn test_create_achievement_rejects_invalid_input() {
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo.expect_create_achievement().never();
+        let use_case = CreateAchievementUseCase::new(Arc::new(mock_repo));
+
+        let unknown_type = use_case.execute(request("Kutu Buku", "Mythic", 20)).await;
+        assert!(matches!(
+            unknown_type,
+            Err(GamificationError::InvalidData(_))
+        ));
+
+        let mut bad_criteria = request("Kutu Buku", "Rare", 5);
+        bad_criteria.criteria = Some(AchievementCriteria::QuizzesCompleted {
+            min_accuracy: Some(150.0),
+        });
+        assert!(matches!(
+            use_case.execute(bad_criteria).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+
+        let zero_target = use_case.execute(request("Kutu Buku", "Rare", 0)).await;
+        assert!(matches!(
+            zero_target,
+            Err(GamificationError::InvalidData(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_chain_tier_is_checked_against_its_chain() {
+        let first = Achievement::new(
+            Uuid::new_v4(),
+            "Pembaca I".to_string(),
+            10,
+            AchievementType::Common,
+            50,
+        )
+        .unwrap()
+        .with_chain(Some(ChainLink::new("Pembaca".to_string(), 1).unwrap()))
+        .unwrap();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .returning(move || Ok(vec![first.clone()]));
+        mock_repo
+            .expect_create_achievement()
+            .withf(|ach| ach.chain().is_some_and(|link| link.tier == 2))
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = CreateAchievementUseCase::new(Arc::new(mock_repo));
+
+        let tier = |target, chain_tier| AchievementRequestDto {
+            chain: Some("Pembaca".to_string()),
+            chain_tier,
+            ..request("Pembaca II", "Rare", target)
+        };
+
+        let created = use_case.execute(tier(25, Some(2))).await.unwrap();
+        assert_eq!(created.chain.as_deref(), Some("Pembaca"));
+        assert_eq!(created.chain_tier, Some(2));
+
+        assert!(matches!(
+            use_case.execute(tier(5, Some(2))).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+        assert!(matches!(
+            use_case.execute(tier(25, None)).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::AchievementDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+
+pub struct ListAchievementCatalogUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl ListAchievementCatalogUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists every catalog entry, retired ones included, for administrators.
+    pub async fn execute(&self) -> Result<Vec<AchievementDto>, GamificationError> {
+        let achievements = self.repository.get_all_achievements().await?;
+        Ok(achievements.iter().map(AchievementDto::from).collect())
+    }
+}
@@ -0,0 +1,52 @@
+// Admin use cases for maintaining the achievement catalog
+pub mod create_achievement;
+pub mod list_achievement_catalog;
+pub mod retire_achievement;
+pub mod update_achievement;
+
+pub use create_achievement::CreateAchievementUseCase;
+pub use list_achievement_catalog::ListAchievementCatalogUseCase;
+pub use retire_achievement::RetireAchievementUseCase;
+pub use update_achievement::UpdateAchievementUseCase;
+
+use crate::modules::gamification::domain::entities::achievement::{Achievement, AchievementType};
+use crate::modules::gamification::domain::entities::achievement_chain::{
+    AchievementChain, ChainLink,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+
+fn parse_achievement_type(value: &str) -> Result<AchievementType, GamificationError> {
+    value
+        .parse()
+        .map_err(|e: &'static str| GamificationError::InvalidData(e.to_string()))
+}
+
+fn parse_chain_link(
+    chain: Option<String>,
+    tier: Option<i32>,
+) -> Result<Option<ChainLink>, GamificationError> {
+    match (chain, tier) {
+        (None, None) => Ok(None),
+        (Some(chain), Some(tier)) => ChainLink::new(chain, tier)
+            .map(Some)
+            .map_err(|e| GamificationError::InvalidData(e.to_string())),
+        _ => Err(GamificationError::InvalidData(
+            "Nama rantai dan tier achievement harus diisi bersamaan.".to_string(),
+        )),
+    }
+}
+
+/// Checks a chained achievement against the other tiers of its chain.
+async fn check_chain_tier(
+    repository: &dyn AchievementRepository,
+    achievement: &Achievement,
+) -> Result<(), GamificationError> {
+    if achievement.chain().is_none() {
+        return Ok(());
+    }
+
+    let catalog = repository.get_all_achievements().await?;
+    AchievementChain::check_tier(&catalog, achievement)
+        .map_err(|e| GamificationError::InvalidData(e.to_string()))
+}
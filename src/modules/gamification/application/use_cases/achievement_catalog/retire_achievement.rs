@@ -0,0 +1,86 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::AchievementDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+
+pub struct RetireAchievementUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl RetireAchievementUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Soft-deletes a catalog entry. Retiring an already retired achievement is a no-op.
+    pub async fn execute(&self, id: Uuid) -> Result<AchievementDto, GamificationError> {
+        let mut achievement = self
+            .repository
+            .get_achievement_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                GamificationError::AchievementNotFound(format!(
+                    "Achievement {} tidak ditemukan.",
+                    id
+                ))
+            })?;
+
+        if !achievement.is_retired() {
+            achievement.retire(Utc::now());
+            self.repository.update_achievement(&achievement).await?;
+        }
+
+        Ok(AchievementDto::from(&achievement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+
+    #[tokio::test]
+    async fn test_retire_marks_achievement_once() {
+        let id = Uuid::new_v4();
+        let active =
+            Achievement::new(id, "Musiman".to_string(), 1, AchievementType::Rare, 10).unwrap();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_achievement_by_id()
+            .returning(move |_| Ok(Some(active.clone())));
+        mock_repo
+            .expect_update_achievement()
+            .withf(|ach| ach.is_retired() && ach.retired_at().is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = RetireAchievementUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(id).await.unwrap();
+
+        assert!(result.is_retired);
+    }
+
+    #[tokio::test]
+    async fn test_retire_already_retired_skips_write() {
+        let id = Uuid::new_v4();
+        let mut retired =
+            Achievement::new(id, "Musiman".to_string(), 1, AchievementType::Rare, 10).unwrap();
+        retired.retire(Utc::now());
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_achievement_by_id()
+            .returning(move |_| Ok(Some(retired.clone())));
+        mock_repo.expect_update_achievement().never();
+
+        let use_case = RetireAchievementUseCase::new(Arc::new(mock_repo));
+        assert!(use_case.execute(id).await.is_ok());
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{check_chain_tier, parse_achievement_type, parse_chain_link};
+use crate::modules::gamification::application::dto::{AchievementDto, AchievementRequestDto};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+
+pub struct UpdateAchievementUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl UpdateAchievementUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Replaces the editable fields of a catalog entry. Retirement is left untouched,
+    /// and so are the criteria and chain when the request omits them.
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        dto: AchievementRequestDto,
+    ) -> Result<AchievementDto, GamificationError> {
+        let achievement_type = parse_achievement_type(&dto.achievement_type)?;
+        let chain = parse_chain_link(dto.chain, dto.chain_tier)?;
+
+        let mut achievement = self
+            .repository
+            .get_achievement_by_id(id)
+            .await?
+            .ok_or_else(|| {
+                GamificationError::AchievementNotFound(format!(
+                    "Achievement {} tidak ditemukan.",
+                    id
+                ))
+            })?;
+
+        achievement
+            .update_details(dto.name, dto.milestone_target, dto.reward_points)
+            .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+        achievement.set_achievement_type(achievement_type);
+        if let Some(criteria) = dto.criteria {
+            achievement
+                .set_criteria(criteria)
+                .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+        }
+        if chain.is_some() {
+            achievement
+                .set_chain(chain)
+                .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+        }
+        check_chain_tier(self.repository.as_ref(), &achievement).await?;
+
+        self.repository.update_achievement(&achievement).await?;
+
+        Ok(AchievementDto::from(&achievement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+
+    #[tokio::test]
+    async fn test_update_achievement_changes_rarity_and_reward() {
+        let id = Uuid::new_v4();
+        let existing =
+            Achievement::new(id, "Lama".to_string(), 5, AchievementType::Common, 10).unwrap();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_achievement_by_id()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_repo
+            .expect_update_achievement()
+            .withf(|ach| *ach.achievement_type() == AchievementType::Legendary)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateAchievementUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                id,
+                AchievementRequestDto {
+                    name: "Baru".to_string(),
+                    milestone_target: 8,
+                    achievement_type: "Legendary".to_string(),
+                    reward_points: 400,
+                    criteria: None,
+                    chain: None,
+                    chain_tier: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.name, "Baru");
+        assert_eq!(result.reward_points, 400);
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_achievement_returns_not_found() {
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_achievement_by_id()
+            .returning(|_| Ok(None));
+        mock_repo.expect_update_achievement().never();
+
+        let use_case = UpdateAchievementUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                Uuid::new_v4(),
+                AchievementRequestDto {
+                    name: "Baru".to_string(),
+                    milestone_target: 8,
+                    achievement_type: "Rare".to_string(),
+                    reward_points: 400,
+                    criteria: None,
+                    chain: None,
+                    chain_tier: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GamificationError::AchievementNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_criteria_when_omitted() {
+        let id = Uuid::new_v4();
+        let existing = Achievement::new(id, "Sempurna".to_string(), 3, AchievementType::Epic, 90)
+            .unwrap()
+            .with_criteria(AchievementCriteria::PerfectQuizzes)
+            .unwrap();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_achievement_by_id()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_repo.expect_update_achievement().returning(|_| Ok(()));
+
+        let use_case = UpdateAchievementUseCase::new(Arc::new(mock_repo));
+        let request = |criteria| AchievementRequestDto {
+            name: "Sempurna".to_string(),
+            milestone_target: 5,
+            achievement_type: "Epic".to_string(),
+            reward_points: 90,
+            criteria,
+            chain: None,
+            chain_tier: None,
+        };
+
+        let kept = use_case.execute(id, request(None)).await.unwrap();
+        assert_eq!(kept.criteria, AchievementCriteria::PerfectQuizzes);
+
+        let replaced = use_case
+            .execute(id, request(Some(AchievementCriteria::CumulativeScore)))
+            .await
+            .unwrap();
+        assert_eq!(replaced.criteria, AchievementCriteria::CumulativeScore);
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::level_repository::LevelRepository;
+
+pub struct AcknowledgeLevelUpsUseCase {
+    pub repository: Arc<dyn LevelRepository>,
+}
+
+impl AcknowledgeLevelUpsUseCase {
+    pub fn new(repository: Arc<dyn LevelRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Marks the user's pending level-ups as shown so they are reported only once.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<u64, GamificationError> {
+        Ok(self.repository.mark_level_ups_seen(user_id, now).await?)
+    }
+}
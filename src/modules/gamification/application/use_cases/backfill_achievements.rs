@@ -0,0 +1,262 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::{
+    AchievementBackfillReportDto, AchievementBackfillRequestDto,
+};
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::achievement_chain::AchievementChain;
+use crate::modules::gamification::domain::entities::achievement_replay::{
+    AchievementReplay, PastQuiz,
+};
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+use crate::shared::domain::unit_of_work::{UnitOfWork, UnitOfWorkFactory, finish};
+
+const DEFAULT_BATCH_SIZE: i64 = 100;
+const MAX_BATCH_SIZE: i64 = 1000;
+
+/// One backfill run: the achievements it evaluates and how it credits them.
+struct BackfillRun {
+    standalone: Vec<Achievement>,
+    chains: Vec<AchievementChain>,
+    reward_points: HashMap<Uuid, i32>,
+    grant_rewards: bool,
+    batch_size: i64,
+    now: DateTime<Utc>,
+}
+
+impl BackfillRun {
+    fn achievement_ids(&self) -> Vec<Uuid> {
+        self.standalone
+            .iter()
+            .chain(self.chains.iter().flat_map(|chain| chain.tiers()))
+            .filter(|ach| !ach.is_retired())
+            .map(|ach| ach.id())
+            .collect()
+    }
+}
+
+pub struct BackfillAchievementsUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+    pub unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl BackfillAchievementsUseCase {
+    pub fn new(
+        repository: Arc<dyn AchievementRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            repository,
+            unit_of_work,
+        }
+    }
+
+    /// Replays every user's quiz history into the progress of the requested
+    /// achievement, or of every active achievement quiz history can count, so
+    /// achievements added later credit what users already did. Completions are
+    /// dated at the quiz that reached the target and progress is never lowered,
+    /// so running it again changes nothing.
+    ///
+    /// Users are walked in batches of `batch_size`, each written in its own
+    /// unit of work; a failed run keeps the batches before it. Reward points are
+    /// only credited with `grant_rewards`, and backfilled unlocks are not
+    /// announced to Java Core.
+    pub async fn execute(
+        &self,
+        request: AchievementBackfillRequestDto,
+        now: DateTime<Utc>,
+    ) -> Result<AchievementBackfillReportDto, GamificationError> {
+        let batch_size = request.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+        if !(1..=MAX_BATCH_SIZE).contains(&batch_size) {
+            return Err(GamificationError::InvalidData(format!(
+                "Ukuran batch harus antara 1 dan {}.",
+                MAX_BATCH_SIZE
+            )));
+        }
+
+        let catalog = self.repository.get_all_achievements().await?;
+        let (standalone, chains) = Self::targets(&catalog, request.achievement_id)?;
+        let run = BackfillRun {
+            standalone,
+            chains,
+            reward_points: catalog
+                .iter()
+                .map(|ach| (ach.id(), ach.reward_points()))
+                .collect(),
+            grant_rewards: request.grant_rewards,
+            batch_size,
+            now,
+        };
+        let mut report = AchievementBackfillReportDto {
+            achievement_ids: run.achievement_ids(),
+            ..Default::default()
+        };
+
+        let mut after = None;
+        loop {
+            let uow = self.unit_of_work.begin().await?;
+            let batch = self
+                .backfill_batch(uow.as_ref(), &run, after, &mut report)
+                .await;
+            let user_ids = finish(uow.as_ref(), batch).await?;
+
+            if (user_ids.len() as i64) < run.batch_size {
+                break;
+            }
+            after = user_ids.last().copied();
+        }
+
+        tracing::info!(
+            achievements = report.achievement_ids.len(),
+            users_scanned = report.users_scanned,
+            progress_updated = report.progress_updated,
+            completed = report.completed,
+            points_awarded = report.points_awarded,
+            "Backfill achievement dari riwayat kuis selesai"
+        );
+        Ok(report)
+    }
+
+    /// The requested achievement with the rest of its chain, or every active
+    /// achievement whose criteria quiz history covers.
+    fn targets(
+        catalog: &[Achievement],
+        achievement_id: Option<Uuid>,
+    ) -> Result<(Vec<Achievement>, Vec<AchievementChain>), GamificationError> {
+        let Some(id) = achievement_id else {
+            return Ok((
+                catalog
+                    .iter()
+                    .filter(|ach| {
+                        !ach.is_retired()
+                            && ach.chain().is_none()
+                            && AchievementReplay::supports(ach.criteria())
+                    })
+                    .cloned()
+                    .collect(),
+                AchievementChain::from_catalog(catalog)
+                    .into_iter()
+                    .filter(|chain| {
+                        chain
+                            .tiers()
+                            .first()
+                            .is_some_and(|ach| AchievementReplay::supports(ach.criteria()))
+                    })
+                    .collect(),
+            ));
+        };
+
+        let achievement = catalog.iter().find(|ach| ach.id() == id).ok_or_else(|| {
+            GamificationError::AchievementNotFound(format!("Achievement {} tidak ditemukan.", id))
+        })?;
+        if achievement.is_retired() {
+            return Err(GamificationError::InvalidData(
+                "Achievement yang sudah dipensiunkan tidak bisa di-backfill.".to_string(),
+            ));
+        }
+        if !AchievementReplay::supports(achievement.criteria()) {
+            return Err(GamificationError::InvalidData(
+                "Progres achievement ini tidak bisa dihitung dari riwayat kuis.".to_string(),
+            ));
+        }
+
+        // tier rantai bergantung pada tier di bawahnya, jadi seluruh rantai ikut dihitung
+        Ok(match achievement.chain() {
+            Some(link) => (
+                Vec::new(),
+                AchievementChain::from_catalog(catalog)
+                    .into_iter()
+                    .filter(|chain| chain.name() == link.chain)
+                    .collect(),
+            ),
+            None => (vec![achievement.clone()], Vec::new()),
+        })
+    }
+
+    /// Backfills the next batch of users after `after`. Returns the users it
+    /// covered.
+    async fn backfill_batch(
+        &self,
+        uow: &dyn UnitOfWork,
+        run: &BackfillRun,
+        after: Option<Uuid>,
+        report: &mut AchievementBackfillReportDto,
+    ) -> Result<Vec<Uuid>, GamificationError> {
+        let quiz_histories = uow.quiz_histories();
+        let achievements = uow.achievements();
+        let user_ids = quiz_histories
+            .list_quiz_user_ids(after, run.batch_size)
+            .await?;
+
+        let mut changed = Vec::new();
+        let mut awards = Vec::new();
+        for &user_id in &user_ids {
+            uow.lock_user(user_id).await?;
+            let quizzes = quiz_histories
+                .get_quiz_histories_by_user(user_id)
+                .await?
+                .iter()
+                .map(|quiz| PastQuiz {
+                    article_id: quiz.article_id(),
+                    score: quiz.score(),
+                    accuracy: quiz.accuracy(),
+                    completed_at: quiz.completed_at(),
+                })
+                .collect();
+            let replay = AchievementReplay::new(user_id, quizzes);
+            let mut existing: HashMap<_, _> = achievements
+                .get_user_achievements(user_id)
+                .await?
+                .into_iter()
+                .map(|ua| (ua.achievement_id(), ua))
+                .collect();
+
+            let replayed = run.standalone.iter().map(|ach| replay.replay(ach)).chain(
+                run.chains
+                    .iter()
+                    .flat_map(|chain| replay.replay_chain(chain)),
+            );
+            for progress in replayed {
+                let achievement_id = progress.achievement_id();
+                let mut user_ach = existing
+                    .remove(&achievement_id)
+                    .unwrap_or_else(|| UserAchievement::new(user_id, achievement_id));
+                if !user_ach.catch_up(&progress) {
+                    continue;
+                }
+
+                report.progress_updated += 1;
+                if user_ach.is_completed() {
+                    report.completed += 1;
+                    let reward = run.reward_points.get(&achievement_id).copied().unwrap_or(0);
+                    if run.grant_rewards && reward > 0 {
+                        awards.push(PointsEntry::new(
+                            user_id,
+                            reward,
+                            PointsSource::Achievement,
+                            Some(achievement_id),
+                            run.now,
+                        ));
+                        report.points_awarded += i64::from(reward);
+                    }
+                }
+                changed.push(user_ach);
+            }
+        }
+
+        if !awards.is_empty() {
+            achievements.award_points(&awards).await?;
+        }
+        if !changed.is_empty() {
+            achievements.save_user_achievements(&changed).await?;
+        }
+        report.users_scanned += user_ids.len();
+        Ok(user_ids)
+    }
+}
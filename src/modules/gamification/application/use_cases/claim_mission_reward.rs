@@ -1,43 +1,134 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::track_achievement_progress::TrackAchievementProgressUseCase;
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
 use crate::modules::gamification::domain::repositories::mission_repository::MissionRepository;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+use crate::shared::domain::user_timezone::UserTimezone;
 
 pub struct ClaimMissionRewardUseCase {
     pub repository: Arc<dyn MissionRepository>,
+    pub achievements: Option<TrackAchievementProgressUseCase>,
 }
 
 impl ClaimMissionRewardUseCase {
     pub fn new(repository: Arc<dyn MissionRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            achievements: None,
+        }
     }
 
-    /// Claims reward for a completed daily mission.
-    ///
-    /// Validates user has sufficient progress, then marks reward as claimed
-    /// and adds reward points to user's total score.
-    pub async fn execute(&self, user_id: Uuid, mission_id: Uuid) -> Result<(), String> {
-        let mut user_mission = self
-            .repository
-            .get_user_mission(user_id, mission_id)
-            .await?
-            .ok_or("Progres misi tidak ditemukan untuk pengguna ini.")?;
+    /// Counts the claim towards achievements on `repository`, announcing the
+    /// ones it unlocks through `outbox`.
+    pub fn with_achievements(
+        mut self,
+        repository: Arc<dyn AchievementRepository>,
+        outbox: Arc<dyn OutboxRepository>,
+    ) -> Self {
+        self.achievements =
+            Some(TrackAchievementProgressUseCase::new(repository).with_outbox(outbox));
+        self
+    }
 
+    /// Claims reward for a completed mission instance: the one running at `at`
+    /// on the user's local clock, or the last one if the mission has already
+    /// closed.
+    ///
+    /// The claim is a single conditional write, so concurrent claims of one
+    /// instance pay out once. The reward points are recorded in the user's
+    /// points ledger at `at`. Returns the points granted.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        mission_id: Uuid,
+        at: DateTime<Utc>,
+        timezone: UserTimezone,
+    ) -> Result<i32, GamificationError> {
         let daily_mission = self
             .repository
             .get_daily_mission_by_id(mission_id)
             .await?
-            .ok_or("Data misi harian tidak ditemukan di sistem.")?;
+            .ok_or_else(|| {
+                GamificationError::MissionNotFound(
+                    "Data misi harian tidak ditemukan di sistem.".to_string(),
+                )
+            })?;
 
-        user_mission.claim_reward(daily_mission.target_count())?;
+        let instance = daily_mission
+            .claimable_instance_at(timezone.local_time(at))
+            .ok_or_else(|| {
+                GamificationError::MissionNotCompleted("Misi ini belum dimulai.".to_string())
+            })?;
 
-        self.repository.save_user_mission(&user_mission).await?;
+        let claimed = self
+            .repository
+            .claim_user_mission(
+                user_id,
+                mission_id,
+                instance.starts_at,
+                daily_mission.target_count(),
+            )
+            .await?;
+        if !claimed {
+            return Err(self
+                .rejection(user_id, mission_id, instance.starts_at)
+                .await);
+        }
 
         self.repository
-            .add_user_score(user_id, daily_mission.reward_points())
+            .award_points(&[PointsEntry::new(
+                user_id,
+                daily_mission.reward_points(),
+                PointsSource::MissionClaim,
+                Some(mission_id),
+                at,
+            )])
             .await?;
 
-        Ok(())
+        if let Some(achievements) = &self.achievements {
+            achievements
+                .execute(
+                    user_id,
+                    &[GamificationEvent::MissionClaimed { mission_id }],
+                    at,
+                )
+                .await?;
+        }
+
+        Ok(daily_mission.reward_points())
+    }
+
+    /// Why a claim of the instance starting at `period_start` was refused.
+    async fn rejection(
+        &self,
+        user_id: Uuid,
+        mission_id: Uuid,
+        period_start: NaiveDateTime,
+    ) -> GamificationError {
+        match self
+            .repository
+            .get_user_mission(user_id, mission_id, period_start)
+            .await
+        {
+            Err(e) => e.into(),
+            Ok(None) => GamificationError::MissionNotFound(
+                "Progres misi tidak ditemukan untuk pengguna ini.".to_string(),
+            ),
+            Ok(Some(progress)) if progress.is_claimed() => {
+                GamificationError::MissionAlreadyClaimed(
+                    "Reward untuk misi ini sudah di-claim sebelumnya.".to_string(),
+                )
+            }
+            Ok(Some(_)) => GamificationError::MissionNotCompleted(
+                "Misi belum selesai, tidak bisa claim reward.".to_string(),
+            ),
+        }
     }
 }
 
@@ -51,6 +142,14 @@ mod tests {
     use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
     use chrono::NaiveDate;
 
+    fn claimed_at() -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2026, 3, 6)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
     #[tokio::test]
     async fn test_execute_claim_success_adds_score() {
         let user_id = Uuid::new_v4();
@@ -70,19 +169,19 @@ mod tests {
         )
         .unwrap();
 
-        let mut user_mission = UserMission::new(user_id, mission_id);
-        user_mission.add_progress(target_count, target_count);
-
+        let period_start = daily_mission.window().starts_at;
         let mut mock_repo = MockMissionRepository::new();
 
         mock_repo
-            .expect_get_user_mission()
+            .expect_claim_user_mission()
             .with(
                 mockall::predicate::eq(user_id),
                 mockall::predicate::eq(mission_id),
+                mockall::predicate::eq(period_start),
+                mockall::predicate::eq(target_count),
             )
             .times(1)
-            .returning(move |_, _| Ok(Some(user_mission.clone())));
+            .returning(|_, _, _, _| Ok(true));
 
         mock_repo
             .expect_get_daily_mission_by_id()
@@ -91,22 +190,137 @@ mod tests {
             .returning(move |_| Ok(Some(daily_mission.clone())));
 
         mock_repo
-            .expect_save_user_mission()
+            .expect_award_points()
+            .withf(move |entries| {
+                matches!(entries, [entry] if entry.user_id == user_id
+                    && entry.delta == reward_points
+                    && entry.source == PointsSource::MissionClaim
+                    && entry.source_id == Some(mission_id)
+                    && entry.occurred_at == claimed_at())
+            })
             .times(1)
             .returning(|_| Ok(()));
 
+        let use_case = ClaimMissionRewardUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(user_id, mission_id, claimed_at(), UserTimezone::default())
+            .await;
+
+        assert!(result.is_ok(), "Eksekusi Use Case seharusnya berhasil");
+    }
+
+    #[tokio::test]
+    async fn test_execute_claim_fails_when_progress_missing() {
+        let mission_id = Uuid::new_v4();
+        let daily_mission = DailyMission::new(
+            mission_id,
+            "Baca 1 Artikel".to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2026, 3, 6).unwrap(),
+            20,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockMissionRepository::new();
         mock_repo
-            .expect_add_user_score()
-            .with(
-                mockall::predicate::eq(user_id),
-                mockall::predicate::eq(reward_points),
+            .expect_get_daily_mission_by_id()
+            .returning(move |_| Ok(Some(daily_mission.clone())));
+        mock_repo
+            .expect_claim_user_mission()
+            .returning(|_, _, _, _| Ok(false));
+        mock_repo
+            .expect_get_user_mission()
+            .returning(|_, _, _| Ok(None));
+        mock_repo.expect_award_points().never();
+
+        let use_case = ClaimMissionRewardUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                Uuid::new_v4(),
+                mission_id,
+                claimed_at(),
+                UserTimezone::default(),
             )
-            .times(1)
-            .returning(|_, _| Ok(()));
+            .await;
+
+        assert!(matches!(result, Err(GamificationError::MissionNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_execute_claim_twice_is_rejected() {
+        let user_id = Uuid::new_v4();
+        let mission_id = Uuid::new_v4();
+        let daily_mission = DailyMission::new(
+            mission_id,
+            "Baca 1 Artikel".to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2026, 3, 6).unwrap(),
+            20,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+
+        let mut user_mission =
+            UserMission::new(user_id, mission_id, daily_mission.window().starts_at);
+        user_mission.add_progress(1, 1);
+        user_mission.claim_reward(1).unwrap();
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_claim_user_mission()
+            .returning(|_, _, _, _| Ok(false));
+        mock_repo
+            .expect_get_user_mission()
+            .returning(move |_, _, _| Ok(Some(user_mission.clone())));
+        mock_repo
+            .expect_get_daily_mission_by_id()
+            .returning(move |_| Ok(Some(daily_mission.clone())));
+        mock_repo.expect_award_points().never();
 
         let use_case = ClaimMissionRewardUseCase::new(Arc::new(mock_repo));
-        let result = use_case.execute(user_id, mission_id).await;
+        let result = use_case
+            .execute(user_id, mission_id, claimed_at(), UserTimezone::default())
+            .await;
 
-        assert!(result.is_ok(), "Eksekusi Use Case seharusnya berhasil");
+        assert!(matches!(
+            result,
+            Err(GamificationError::MissionAlreadyClaimed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_claim_before_mission_starts_is_rejected() {
+        let mission_id = Uuid::new_v4();
+        let daily_mission = DailyMission::new(
+            mission_id,
+            "Baca 1 Artikel".to_string(),
+            1,
+            NaiveDate::from_ymd_opt(2026, 3, 7).unwrap(),
+            20,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_daily_mission_by_id()
+            .returning(move |_| Ok(Some(daily_mission.clone())));
+        mock_repo.expect_claim_user_mission().never();
+
+        let use_case = ClaimMissionRewardUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                Uuid::new_v4(),
+                mission_id,
+                claimed_at(),
+                UserTimezone::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GamificationError::MissionNotCompleted(_))
+        ));
     }
 }
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::AchievementDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+
+pub struct GetAchievementsUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl GetAchievementsUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists the achievements users can still work towards; retired ones are hidden.
+    pub async fn execute(&self) -> Result<Vec<AchievementDto>, GamificationError> {
+        let achievements = self.repository.get_all_achievements().await?;
+        Ok(achievements
+            .iter()
+            .filter(|ach| !ach.is_retired())
+            .map(AchievementDto::from)
+            .collect())
+    }
+}
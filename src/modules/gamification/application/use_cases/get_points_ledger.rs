@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::{PointsLedgerDto, PointsLedgerEntryDto};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::points_ledger_repository::PointsLedgerRepository;
+
+pub const DEFAULT_LEDGER_PAGE_SIZE: i64 = 20;
+pub const MAX_LEDGER_PAGE_SIZE: i64 = 100;
+
+pub struct GetPointsLedgerUseCase {
+    pub repository: Arc<dyn PointsLedgerRepository>,
+}
+
+impl GetPointsLedgerUseCase {
+    pub fn new(repository: Arc<dyn PointsLedgerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// A page of the user's ledger, newest first, next to the total it sums to.
+    /// `before` is the `next_before` of the previous page.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        limit: Option<i64>,
+        before: Option<i64>,
+    ) -> Result<PointsLedgerDto, GamificationError> {
+        let limit = limit.unwrap_or(DEFAULT_LEDGER_PAGE_SIZE);
+        if !(1..=MAX_LEDGER_PAGE_SIZE).contains(&limit) {
+            return Err(GamificationError::InvalidData(format!(
+                "Limit harus antara 1 dan {}",
+                MAX_LEDGER_PAGE_SIZE
+            )));
+        }
+
+        let total_score = self
+            .repository
+            .get_total_score(user_id)
+            .await?
+            .ok_or_else(|| {
+                GamificationError::UserNotFound(format!("User {} tidak ditemukan", user_id))
+            })?;
+
+        // One extra row tells whether another page follows.
+        let mut entries = self
+            .repository
+            .get_user_entries(user_id, limit + 1, before)
+            .await?;
+        let has_more = entries.len() as i64 > limit;
+        entries.truncate(limit as usize);
+
+        Ok(PointsLedgerDto {
+            user_id,
+            total_score,
+            next_before: if has_more {
+                entries.last().map(|e| e.id)
+            } else {
+                None
+            },
+            entries: entries.iter().map(PointsLedgerEntryDto::from).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::points_ledger::{
+        LedgerEntry, PointsEntry, PointsSource,
+    };
+    use crate::modules::gamification::domain::repositories::points_ledger_repository::MockPointsLedgerRepository;
+    use chrono::Utc;
+
+    fn entries(user_id: Uuid, ids: &[i64]) -> Vec<LedgerEntry> {
+        ids.iter()
+            .map(|&id| LedgerEntry {
+                id,
+                entry: PointsEntry::new(user_id, 10, PointsSource::Quiz, None, Utc::now()),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_page_reports_cursor_when_more_entries_follow() {
+        let user_id = Uuid::new_v4();
+        let mut mock_repo = MockPointsLedgerRepository::new();
+        mock_repo
+            .expect_get_total_score()
+            .returning(|_| Ok(Some(30)));
+        mock_repo
+            .expect_get_user_entries()
+            .withf(|_, limit, before| *limit == 3 && *before == Some(9))
+            .return_once(move |_, _, _| Ok(entries(user_id, &[8, 7, 6])));
+
+        let use_case = GetPointsLedgerUseCase::new(Arc::new(mock_repo));
+        let ledger = use_case.execute(user_id, Some(2), Some(9)).await.unwrap();
+
+        assert_eq!(ledger.total_score, 30);
+        assert_eq!(ledger.entries.len(), 2);
+        assert_eq!(ledger.entries[0].source_type, "quiz");
+        assert_eq!(ledger.next_before, Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_last_page_has_no_cursor() {
+        let user_id = Uuid::new_v4();
+        let mut mock_repo = MockPointsLedgerRepository::new();
+        mock_repo
+            .expect_get_total_score()
+            .returning(|_| Ok(Some(10)));
+        mock_repo
+            .expect_get_user_entries()
+            .return_once(move |_, _, _| Ok(entries(user_id, &[1])));
+
+        let use_case = GetPointsLedgerUseCase::new(Arc::new(mock_repo));
+        let ledger = use_case.execute(user_id, None, None).await.unwrap();
+
+        assert_eq!(ledger.entries.len(), 1);
+        assert_eq!(ledger.next_before, None);
+    }
+
+    #[tokio::test]
+    async fn test_limit_out_of_range_is_rejected() {
+        let mut mock_repo = MockPointsLedgerRepository::new();
+        mock_repo.expect_get_user_entries().never();
+
+        let use_case = GetPointsLedgerUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(Uuid::new_v4(), Some(MAX_LEDGER_PAGE_SIZE + 1), None)
+            .await;
+
+        assert!(matches!(result, Err(GamificationError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_is_not_found() {
+        let mut mock_repo = MockPointsLedgerRepository::new();
+        mock_repo.expect_get_total_score().returning(|_| Ok(None));
+
+        let use_case = GetPointsLedgerUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(Uuid::new_v4(), None, None).await;
+
+        assert!(matches!(result, Err(GamificationError::UserNotFound(_))));
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::ShowcaseBadgeDto;
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+
+pub struct GetShowcaseUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl GetShowcaseUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The badges the user pinned on their profile, in showcase order.
+    /// Retired achievements stay on display since the user earned them.
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<ShowcaseBadgeDto>, GamificationError> {
+        let user_achievements = self.repository.get_user_achievements(user_id).await?;
+        if !user_achievements.iter().any(|ua| ua.is_shown_on_profile()) {
+            return Ok(Vec::new());
+        }
+
+        let catalog = self.repository.get_all_achievements().await?;
+        Ok(showcase_badges(&user_achievements, &catalog))
+    }
+}
+
+/// The pinned badges among `user_achievements`, ordered by showcase position.
+pub fn showcase_badges(
+    user_achievements: &[UserAchievement],
+    catalog: &[Achievement],
+) -> Vec<ShowcaseBadgeDto> {
+    let catalog: HashMap<_, _> = catalog.iter().map(|ach| (ach.id(), ach)).collect();
+
+    let mut badges: Vec<_> = user_achievements
+        .iter()
+        .filter(|ua| ua.is_shown_on_profile())
+        .filter_map(|ua| {
+            catalog
+                .get(&ua.achievement_id())
+                .map(|ach| ShowcaseBadgeDto::new(ach, ua))
+        })
+        .collect();
+    badges.sort_by_key(|badge| badge.position);
+    badges
+}
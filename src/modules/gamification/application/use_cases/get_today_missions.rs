@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_user_missions::GetUserMissionsUseCase;
+use crate::modules::gamification::application::dto::TodayMissionsDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionRepository;
+use crate::shared::domain::user_timezone::{UserClock, UserTimezone};
+
+pub struct GetTodayMissionsUseCase {
+    pub mission_repo: Arc<dyn MissionRepository>,
+    pub clock: UserClock,
+}
+
+impl GetTodayMissionsUseCase {
+    pub fn new(mission_repo: Arc<dyn MissionRepository>, clock: UserClock) -> Self {
+        Self {
+            mission_repo,
+            clock,
+        }
+    }
+
+    /// Lists the missions running at the user's local time, daily and longer
+    /// ones alike, with their progress on the current period instance.
+    ///
+    /// Local time is read on `utc_offset_minutes` when given, otherwise in the
+    /// user's synced timezone (the configured default if there is none yet).
+    /// A requested offset only applies to this lookup; it is not stored.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+        utc_offset_minutes: Option<i32>,
+    ) -> Result<TodayMissionsDto, GamificationError> {
+        if !self.mission_repo.user_exists(user_id).await? {
+            return Err(GamificationError::UserNotFound(format!(
+                "User {} tidak ditemukan",
+                user_id
+            )));
+        }
+
+        let timezone = match utc_offset_minutes {
+            Some(minutes) => UserTimezone::from_offset_minutes(minutes)
+                .map_err(|e| GamificationError::InvalidData(e.to_string()))?,
+            None => self.clock.timezone(user_id, None).await?,
+        };
+        let local_now = timezone.local_time(now);
+
+        let missions = GetUserMissionsUseCase::new(self.mission_repo.clone())
+            .execute(user_id, local_now)
+            .await?;
+
+        Ok(TodayMissionsDto {
+            user_id,
+            date: local_now.date(),
+            missions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
+    use crate::shared::domain::user_timezone_repository::MockUserTimezoneRepository;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn clock_synced_to(minutes: Option<i32>) -> UserClock {
+        let mut repo = MockUserTimezoneRepository::new();
+        repo.expect_get_timezone()
+            .returning(move |_| Ok(minutes.map(|m| UserTimezone::from_offset_minutes(m).unwrap())));
+        repo.expect_set_timezone().never();
+        UserClock::new(Arc::new(repo), UserTimezone::UTC)
+    }
+
+    fn mission_repo_for(user_exists: bool, expected_date: NaiveDate) -> MockMissionRepository {
+        let mut repo = MockMissionRepository::new();
+        repo.expect_user_exists()
+            .returning(move |_| Ok(user_exists));
+        repo.expect_get_user_missions_at()
+            .withf(move |_, at| at.date() == expected_date)
+            .returning(|_, _| Ok(vec![]));
+        repo
+    }
+
+    #[tokio::test]
+    async fn test_execute_uses_the_synced_offset_for_the_local_day() {
+        let user_id = Uuid::new_v4();
+        let now = Utc.with_ymd_and_hms(2026, 4, 20, 20, 0, 0).unwrap();
+        let local_day = NaiveDate::from_ymd_opt(2026, 4, 21).unwrap();
+
+        let use_case = GetTodayMissionsUseCase::new(
+            Arc::new(mission_repo_for(true, local_day)),
+            clock_synced_to(Some(7 * 60)),
+        );
+        let result = use_case.execute(user_id, now, None).await.unwrap();
+
+        assert_eq!(result.date, local_day);
+    }
+
+    #[tokio::test]
+    async fn test_execute_prefers_the_requested_offset() {
+        let now = Utc.with_ymd_and_hms(2026, 4, 20, 2, 0, 0).unwrap();
+        let local_day = NaiveDate::from_ymd_opt(2026, 4, 19).unwrap();
+
+        let use_case = GetTodayMissionsUseCase::new(
+            Arc::new(mission_repo_for(true, local_day)),
+            clock_synced_to(Some(7 * 60)),
+        );
+        let result = use_case
+            .execute(Uuid::new_v4(), now, Some(-5 * 60))
+            .await
+            .unwrap();
+
+        assert_eq!(result.date, local_day);
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_unknown_user() {
+        let now = Utc::now();
+        let use_case = GetTodayMissionsUseCase::new(
+            Arc::new(mission_repo_for(false, now.date_naive())),
+            clock_synced_to(None),
+        );
+
+        let result = use_case.execute(Uuid::new_v4(), now, None).await;
+
+        assert!(matches!(result, Err(GamificationError::UserNotFound(_))));
+    }
+}
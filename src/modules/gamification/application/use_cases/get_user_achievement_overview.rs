@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::{
+    AchievementChainDto, UserAchievementDto, UserAchievementOverviewDto,
+};
+use crate::modules::gamification::domain::entities::achievement_chain::AchievementChain;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+
+pub struct GetUserAchievementOverviewUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl GetUserAchievementOverviewUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists each achievement chain once, at the user's current tier and next
+    /// target, next to the achievements that stand alone.
+    ///
+    /// Retired achievements follow the same rule as the flat list: they only show
+    /// up for users who completed them.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+    ) -> Result<UserAchievementOverviewDto, GamificationError> {
+        let catalog = self.repository.get_all_achievements().await?;
+
+        let progress: HashMap<_, _> = self
+            .repository
+            .get_user_achievements(user_id)
+            .await?
+            .into_iter()
+            .map(|ua| (ua.achievement_id(), ua))
+            .collect();
+
+        let chains = AchievementChain::from_catalog(&catalog)
+            .iter()
+            .filter_map(|chain| chain_dto(chain, &progress))
+            .collect();
+
+        let achievements = catalog
+            .iter()
+            .filter(|ach| ach.chain().is_none())
+            .filter_map(|ach| {
+                let user_progress = progress.get(&ach.id());
+                let earned = user_progress.is_some_and(|p| p.is_completed());
+                (!ach.is_retired() || earned).then(|| UserAchievementDto::new(ach, user_progress))
+            })
+            .collect();
+
+        Ok(UserAchievementOverviewDto {
+            chains,
+            achievements,
+        })
+    }
+}
+
+/// `None` when the chain has nothing to show: every tier is retired and the user
+/// earned none of them.
+fn chain_dto(
+    chain: &AchievementChain,
+    progress: &HashMap<Uuid, UserAchievement>,
+) -> Option<AchievementChainDto> {
+    let completed = |id: Uuid| progress.get(&id).is_some_and(|p| p.is_completed());
+
+    let current = chain.tiers().iter().rev().find(|ach| completed(ach.id()));
+    let next = chain
+        .next_tier_index(completed)
+        .map(|index| &chain.tiers()[index]);
+    if current.is_none() && next.is_none() {
+        return None;
+    }
+
+    // tier berikutnya belum punya baris progres -> progres terbawa dari tier sebelumnya
+    let current_progress = next
+        .and_then(|ach| progress.get(&ach.id()))
+        .or_else(|| current.and_then(|ach| progress.get(&ach.id())))
+        .map_or(0, |p| p.current_progress());
+
+    Some(AchievementChainDto {
+        chain: chain.name().to_string(),
+        total_tiers: chain.tiers().len() as i32,
+        current_tier: current.map(|ach| UserAchievementDto::new(ach, progress.get(&ach.id()))),
+        next_tier: next.map(|ach| UserAchievementDto::new(ach, progress.get(&ach.id()))),
+        current_progress,
+        next_target: next.map(|ach| ach.milestone_target()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::achievement_chain::ChainLink;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+    use chrono::Utc;
+
+    fn reader_tier(tier: i32, target: i32) -> Achievement {
+        Achievement::new(
+            Uuid::new_v4(),
+            format!("Pembaca {}", tier),
+            target,
+            AchievementType::Rare,
+            50,
+        )
+        .unwrap()
+        .with_chain(Some(ChainLink::new("Pembaca".to_string(), tier).unwrap()))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_chain_reports_current_tier_and_next_target() {
+        let user_id = Uuid::new_v4();
+        let first = reader_tier(1, 10);
+        let second = reader_tier(2, 25);
+        let third = reader_tier(3, 50);
+        let standalone = Achievement::new(
+            Uuid::new_v4(),
+            "Kutu Buku".to_string(),
+            5,
+            AchievementType::Epic,
+            100,
+        )
+        .unwrap();
+
+        let mut done = UserAchievement::new(user_id, first.id());
+        done.add_progress(10, 10, Utc::now());
+        let mut working = UserAchievement::new(user_id, second.id());
+        working.add_progress(14, 25, Utc::now());
+
+        let first_id = first.id();
+        let second_id = second.id();
+        let catalog = vec![third, standalone, second, first];
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(catalog));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![done, working]));
+
+        let use_case = GetUserAchievementOverviewUseCase::new(Arc::new(mock_repo));
+        let overview = use_case.execute(user_id).await.unwrap();
+
+        assert_eq!(overview.achievements.len(), 1);
+        assert_eq!(overview.achievements[0].name, "Kutu Buku");
+        assert_eq!(overview.chains.len(), 1);
+        let chain = &overview.chains[0];
+        assert_eq!(chain.chain, "Pembaca");
+        assert_eq!(chain.total_tiers, 3);
+        assert_eq!(
+            chain.current_tier.as_ref().map(|t| t.achievement_id),
+            Some(first_id)
+        );
+        assert_eq!(
+            chain.next_tier.as_ref().map(|t| t.achievement_id),
+            Some(second_id)
+        );
+        assert_eq!(chain.current_progress, 14);
+        assert_eq!(chain.next_target, Some(25));
+    }
+
+    #[tokio::test]
+    async fn test_finished_chain_has_no_next_target() {
+        let user_id = Uuid::new_v4();
+        let only = reader_tier(1, 3);
+        let mut done = UserAchievement::new(user_id, only.id());
+        done.add_progress(3, 3, Utc::now());
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![only]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![done]));
+
+        let use_case = GetUserAchievementOverviewUseCase::new(Arc::new(mock_repo));
+        let overview = use_case.execute(user_id).await.unwrap();
+
+        let chain = &overview.chains[0];
+        assert!(chain.next_tier.is_none());
+        assert_eq!(chain.next_target, None);
+        assert_eq!(chain.current_progress, 3);
+    }
+}
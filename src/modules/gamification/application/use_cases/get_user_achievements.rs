@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::UserAchievementDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+
+pub struct GetUserAchievementsUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+}
+
+impl GetUserAchievementsUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists every achievement in the catalog with the user's progress on it.
+    ///
+    /// Achievements the user has not started yet are reported with zero progress.
+    /// Retired achievements are only listed for users who completed them.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<UserAchievementDto>, GamificationError> {
+        let achievements = self.repository.get_all_achievements().await?;
+
+        let progress: HashMap<_, _> = self
+            .repository
+            .get_user_achievements(user_id)
+            .await?
+            .into_iter()
+            .map(|ua| (ua.achievement_id(), ua))
+            .collect();
+
+        Ok(achievements
+            .iter()
+            .filter_map(|ach| {
+                let user_progress = progress.get(&ach.id());
+                let earned = user_progress.is_some_and(|p| p.is_completed());
+                (!ach.is_retired() || earned).then(|| UserAchievementDto::new(ach, user_progress))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_execute_merges_catalog_with_progress() {
+        let user_id = Uuid::new_v4();
+        let started = Achievement::new(
+            Uuid::new_v4(),
+            "Pembaca Setia".to_string(),
+            5,
+            AchievementType::Rare,
+            100,
+        )
+        .unwrap();
+        let untouched = Achievement::new(
+            Uuid::new_v4(),
+            "Kutu Buku".to_string(),
+            50,
+            AchievementType::Epic,
+            500,
+        )
+        .unwrap();
+
+        let mut progress = UserAchievement::new(user_id, started.id());
+        progress.add_progress(3, started.milestone_target(), Utc::now());
+
+        let catalog = vec![started.clone(), untouched.clone()];
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .returning(move || Ok(catalog.clone()));
+        mock_repo
+            .expect_get_user_achievements()
+            .with(mockall::predicate::eq(user_id))
+            .returning(move |_| Ok(vec![progress.clone()]));
+
+        let use_case = GetUserAchievementsUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(user_id).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].achievement_id, started.id());
+        assert_eq!(result[0].current_progress, 3);
+        assert!(!result[0].is_completed);
+        assert_eq!(result[1].achievement_id, untouched.id());
+        assert_eq!(result[1].current_progress, 0);
+        assert!(result[1].completed_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_execute_shows_retired_achievement_only_when_earned() {
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut earned = Achievement::new(
+            Uuid::new_v4(),
+            "Musim Pertama".to_string(),
+            1,
+            AchievementType::Legendary,
+            1000,
+        )
+        .unwrap();
+        earned.retire(now);
+        let mut missed = Achievement::new(
+            Uuid::new_v4(),
+            "Musim Pertama Kedua".to_string(),
+            1,
+            AchievementType::Epic,
+            500,
+        )
+        .unwrap();
+        missed.retire(now);
+
+        let mut progress = UserAchievement::new(user_id, earned.id());
+        progress.add_progress(1, 1, now);
+
+        let catalog = vec![earned.clone(), missed];
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .returning(move || Ok(catalog.clone()));
+        mock_repo
+            .expect_get_user_achievements()
+            .returning(move |_| Ok(vec![progress.clone()]));
+
+        let use_case = GetUserAchievementsUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(user_id).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].achievement_id, earned.id());
+        assert!(result[0].is_retired);
+        assert!(result[0].is_completed);
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::UserLevelDto;
+use crate::modules::gamification::domain::entities::level::LevelCurve;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::level_repository::LevelRepository;
+
+pub struct GetUserLevelUseCase {
+    pub repository: Arc<dyn LevelRepository>,
+}
+
+impl GetUserLevelUseCase {
+    pub fn new(repository: Arc<dyn LevelRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The user's level and progress to the next one, derived from their total
+    /// score, plus the level-ups they have not seen yet.
+    pub async fn execute(&self, user_id: Uuid) -> Result<UserLevelDto, GamificationError> {
+        let total_score = self
+            .repository
+            .get_total_score(user_id)
+            .await?
+            .ok_or_else(|| {
+                GamificationError::UserNotFound(format!("User {} tidak ditemukan", user_id))
+            })?;
+
+        let curve = LevelCurve::new(self.repository.get_levels().await?).map_err(|e| {
+            GamificationError::DatabaseError(format!("Kurva level tidak valid: {}", e))
+        })?;
+        let pending = self.repository.get_pending_level_ups(user_id).await?;
+
+        Ok(UserLevelDto::new(
+            user_id,
+            curve.progress(total_score),
+            &pending,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::level::{Level, LevelUp};
+    use crate::modules::gamification::domain::repositories::level_repository::MockLevelRepository;
+    use chrono::Utc;
+
+    fn levels() -> Vec<Level> {
+        [(1, "Pemula", 0), (2, "Tekun", 100), (3, "Kutu Buku", 300)]
+            .into_iter()
+            .map(|(level, title, min_score)| Level {
+                level,
+                title: title.to_string(),
+                min_score,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_level_and_pending_level_ups_are_reported() {
+        let user_id = Uuid::new_v4();
+        let level_up = LevelUp {
+            user_id,
+            level: 2,
+            title: "Tekun".to_string(),
+            total_score: 120,
+            reached_at: Utc::now(),
+            seen_at: None,
+        };
+
+        let mut mock_repo = MockLevelRepository::new();
+        mock_repo
+            .expect_get_total_score()
+            .returning(|_| Ok(Some(200)));
+        mock_repo.expect_get_levels().returning(|| Ok(levels()));
+        mock_repo
+            .expect_get_pending_level_ups()
+            .return_once(move |_| Ok(vec![level_up]));
+
+        let use_case = GetUserLevelUseCase::new(Arc::new(mock_repo));
+        let dto = use_case.execute(user_id).await.unwrap();
+
+        assert_eq!(dto.level, 2);
+        assert_eq!(dto.title, "Tekun");
+        assert_eq!(dto.next_level_min_score, Some(300));
+        assert_eq!(dto.progress_percent, 50.0);
+        assert_eq!(dto.pending_level_ups.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_is_not_found() {
+        let mut mock_repo = MockLevelRepository::new();
+        mock_repo.expect_get_total_score().returning(|_| Ok(None));
+        mock_repo.expect_get_levels().never();
+
+        let use_case = GetUserLevelUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(GamificationError::UserNotFound(_))));
+    }
+}
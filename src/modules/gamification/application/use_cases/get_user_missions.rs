@@ -0,0 +1,84 @@
+use chrono::NaiveDateTime;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::UserMissionDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::mission_repository::MissionRepository;
+
+pub struct GetUserMissionsUseCase {
+    pub repository: Arc<dyn MissionRepository>,
+}
+
+impl GetUserMissionsUseCase {
+    pub fn new(repository: Arc<dyn MissionRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists the missions active at `at` with the user's progress on the
+    /// instance of each that is running then.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        at: NaiveDateTime,
+    ) -> Result<Vec<UserMissionDto>, GamificationError> {
+        let missions = self.repository.get_user_missions_at(user_id, at).await?;
+
+        Ok(missions
+            .iter()
+            .filter_map(|(mission, progress)| {
+                let instance = mission.instance_at(at)?;
+                Some(UserMissionDto::new(mission, &instance, progress.as_ref()))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::daily_mission::{
+        DailyMission, MissionType,
+    };
+    use crate::modules::gamification::domain::entities::user_mission::UserMission;
+    use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    async fn test_execute_reports_progress_per_mission() {
+        let user_id = Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2026, 4, 20).unwrap();
+        let at = date.and_hms_opt(8, 30, 0).unwrap();
+        let mission = DailyMission::new(
+            Uuid::new_v4(),
+            "Baca 2 Artikel".to_string(),
+            2,
+            date,
+            40,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+
+        let mut progress = UserMission::new(user_id, mission.id(), mission.window().starts_at);
+        progress.add_progress(2, 2);
+
+        let missions = vec![(mission.clone(), Some(progress))];
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_user_missions_at()
+            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(at))
+            .returning(move |_, _| Ok(missions.clone()));
+
+        let use_case = GetUserMissionsUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(user_id, at).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mission_id, mission.id());
+        assert_eq!(result[0].current_progress, 2);
+        assert!(result[0].is_completed);
+        assert!(!result[0].is_claimed);
+        assert!(result[0].is_claimable);
+        assert_eq!(result[0].period, "Daily");
+        assert_eq!(result[0].period_start, date.and_hms_opt(0, 0, 0).unwrap());
+    }
+}
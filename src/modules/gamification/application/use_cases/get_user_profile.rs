@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_showcase::showcase_badges;
+use super::get_user_missions::GetUserMissionsUseCase;
+use crate::modules::gamification::application::dto::{
+    UserAchievementDto, UserLevelDto, UserProfileDto, UserStreakDto,
+};
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::level::LevelCurve;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::entities::user_streak::{StreakPolicy, UserStreak};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::{
+    AchievementRepository, LevelRepository, MissionRepository, StreakRepository,
+};
+use crate::modules::league::application::use_cases::GetUserTierUseCase;
+use crate::modules::league::domain::repositories::{ClanRepository, LeaderboardCache};
+use crate::shared::domain::user_timezone::UserTimezone;
+
+/// How many of the latest completed achievements the profile lists.
+pub const RECENT_UNLOCKS_LIMIT: usize = 5;
+
+pub struct GetUserProfileUseCase<R: ClanRepository, L: LeaderboardCache> {
+    pub mission_repo: Arc<dyn MissionRepository>,
+    pub achievement_repo: Arc<dyn AchievementRepository>,
+    pub streak_repo: Arc<dyn StreakRepository>,
+    pub level_repo: Arc<dyn LevelRepository>,
+    pub streak_policy: StreakPolicy,
+    pub user_tier: GetUserTierUseCase<R, L>,
+}
+
+impl<R: ClanRepository, L: LeaderboardCache> GetUserProfileUseCase<R, L> {
+    pub fn new(
+        mission_repo: Arc<dyn MissionRepository>,
+        achievement_repo: Arc<dyn AchievementRepository>,
+        streak_repo: Arc<dyn StreakRepository>,
+        level_repo: Arc<dyn LevelRepository>,
+        streak_policy: StreakPolicy,
+        user_tier: GetUserTierUseCase<R, L>,
+    ) -> Self {
+        Self {
+            mission_repo,
+            achievement_repo,
+            streak_repo,
+            level_repo,
+            streak_policy,
+            user_tier,
+        }
+    }
+
+    /// Gathers the user's score, level, streak, clan standing, today's
+    /// missions, recent unlocks and showcase, "today" being read in the user's
+    /// `timezone`. The lookups are independent, so they all run concurrently.
+    ///
+    /// League data is best-effort: when it fails the profile is still served
+    /// without the clan section.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+        timezone: UserTimezone,
+    ) -> Result<UserProfileDto, GamificationError> {
+        let missions = GetUserMissionsUseCase::new(self.mission_repo.clone());
+        let (
+            total_score,
+            levels,
+            pending_level_ups,
+            streak,
+            clan,
+            missions_today,
+            user_achievements,
+            catalog,
+        ) = tokio::join!(
+            self.level_repo.get_total_score(user_id),
+            self.level_repo.get_levels(),
+            self.level_repo.get_pending_level_ups(user_id),
+            self.streak_repo.get_streak(user_id),
+            self.user_tier.execute(user_id),
+            missions.execute(user_id, timezone.local_time(now)),
+            self.achievement_repo.get_user_achievements(user_id),
+            self.achievement_repo.get_all_achievements(),
+        );
+
+        let total_score = total_score?.ok_or_else(|| {
+            GamificationError::UserNotFound(format!("User {} tidak ditemukan", user_id))
+        })?;
+        let curve = LevelCurve::new(levels?).map_err(|e| {
+            GamificationError::DatabaseError(format!("Kurva level tidak valid: {}", e))
+        })?;
+        let streak =
+            streak?.unwrap_or_else(|| UserStreak::new(user_id, self.streak_policy.initial_freezes));
+        let clan = match clan {
+            Ok(tier) => tier.clan_id.is_some().then_some(tier),
+            Err(e) => {
+                tracing::warn!("Clan summary for user {} is unavailable: {}", user_id, e);
+                None
+            }
+        };
+        let user_achievements = user_achievements?;
+        let catalog = catalog?;
+
+        Ok(UserProfileDto {
+            user_id,
+            total_score,
+            level: UserLevelDto::new(user_id, curve.progress(total_score), &pending_level_ups?),
+            streak: UserStreakDto::new(&streak, &self.streak_policy, timezone.local_date(now)),
+            clan,
+            missions_today: missions_today?,
+            recent_unlocks: recent_unlocks(&user_achievements, &catalog),
+            showcase: showcase_badges(&user_achievements, &catalog),
+        })
+    }
+}
+
+/// Completed achievements, newest first, capped at `RECENT_UNLOCKS_LIMIT`.
+fn recent_unlocks(
+    user_achievements: &[UserAchievement],
+    catalog: &[Achievement],
+) -> Vec<UserAchievementDto> {
+    let catalog: HashMap<_, _> = catalog.iter().map(|ach| (ach.id(), ach)).collect();
+
+    let mut completed: Vec<_> = user_achievements
+        .iter()
+        .filter(|ua| ua.is_completed())
+        .collect();
+    completed.sort_by_key(|ua| std::cmp::Reverse(ua.completed_at()));
+
+    completed
+        .into_iter()
+        .filter_map(|ua| {
+            catalog
+                .get(&ua.achievement_id())
+                .map(|ach| UserAchievementDto::new(ach, Some(ua)))
+        })
+        .take(RECENT_UNLOCKS_LIMIT)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::AchievementType;
+    use chrono::Duration;
+
+    #[test]
+    fn test_recent_unlocks_are_newest_first_and_capped() {
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut catalog = Vec::new();
+        let mut progress = Vec::new();
+        for i in 0..7 {
+            let achievement = Achievement::new(
+                Uuid::new_v4(),
+                format!("Badge {}", i),
+                1,
+                AchievementType::Common,
+                10,
+            )
+            .unwrap();
+            let mut user_ach = UserAchievement::new(user_id, achievement.id());
+            if i != 3 {
+                user_ach.add_progress(1, 1, now - Duration::days(i));
+            }
+            catalog.push(achievement);
+            progress.push(user_ach);
+        }
+
+        let unlocks = recent_unlocks(&progress, &catalog);
+
+        let names: Vec<_> = unlocks.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Badge 0", "Badge 1", "Badge 2", "Badge 4", "Badge 5"]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::UserStreakDto;
+use crate::modules::gamification::domain::entities::user_streak::{StreakPolicy, UserStreak};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::streak_repository::StreakRepository;
+use crate::shared::domain::user_timezone::UserTimezone;
+
+pub struct GetUserStreakUseCase {
+    pub repository: Arc<dyn StreakRepository>,
+    pub policy: StreakPolicy,
+}
+
+impl GetUserStreakUseCase {
+    pub fn new(repository: Arc<dyn StreakRepository>, policy: StreakPolicy) -> Self {
+        Self { repository, policy }
+    }
+
+    /// The user's streak as of `now` on their local day in `timezone`. Users
+    /// without any activity yet get an empty streak with the starting freezes.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+        timezone: UserTimezone,
+    ) -> Result<UserStreakDto, GamificationError> {
+        let streak = self
+            .repository
+            .get_streak(user_id)
+            .await?
+            .unwrap_or_else(|| UserStreak::new(user_id, self.policy.initial_freezes));
+
+        Ok(UserStreakDto::new(
+            &streak,
+            &self.policy,
+            timezone.local_date(now),
+        ))
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::{
+    MissionTemplateDto, MissionTemplateRequestDto,
+};
+use crate::modules::gamification::domain::entities::daily_mission::{MissionType, QuizConditions};
+use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+pub struct CreateMissionTemplateUseCase {
+    pub repository: Arc<dyn MissionTemplateRepository>,
+}
+
+impl CreateMissionTemplateUseCase {
+    pub fn new(repository: Arc<dyn MissionTemplateRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: MissionTemplateRequestDto,
+    ) -> Result<MissionTemplateDto, GamificationError> {
+        let mission_type: MissionType = dto
+            .mission_type
+            .parse()
+            .map_err(|e: &'static str| GamificationError::InvalidData(e.to_string()))?;
+        let conditions = QuizConditions::new(dto.min_score, dto.min_accuracy)
+            .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+        if !conditions.is_empty() && mission_type != MissionType::Quiz {
+            return Err(GamificationError::InvalidData(
+                "Syarat skor dan akurasi hanya berlaku untuk template Quiz.".to_string(),
+            ));
+        }
+
+        let template = MissionTemplate::new(
+            Uuid::new_v4(),
+            dto.description,
+            mission_type,
+            (dto.min_target, dto.max_target),
+            (dto.min_reward, dto.max_reward),
+            dto.weight,
+        )
+        .map_err(|e| GamificationError::InvalidData(e.to_string()))?
+        .with_quiz_conditions(conditions);
+
+        self.repository.create_template(&template).await?;
+
+        Ok(MissionTemplateDto::from(&template))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::repositories::mission_template_repository::MockMissionTemplateRepository;
+
+    fn request(mission_type: &str, weight: i32) -> MissionTemplateRequestDto {
+        MissionTemplateRequestDto {
+            description: "Baca {target} artikel".to_string(),
+            mission_type: mission_type.to_string(),
+            min_target: 2,
+            max_target: 5,
+            min_reward: 20,
+            max_reward: 60,
+            weight,
+            min_score: None,
+            min_accuracy: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_template_persists_valid_entry() {
+        let mut mock_repo = MockMissionTemplateRepository::new();
+        mock_repo
+            .expect_create_template()
+            .withf(|t| t.mission_type == MissionType::ReadArticle && t.weight == 4)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateMissionTemplateUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(request("readarticle", 4)).await.unwrap();
+
+        assert_eq!(result.mission_type, "ReadArticle");
+        assert!(result.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_create_template_rejects_invalid_input() {
+        let mut mock_repo = MockMissionTemplateRepository::new();
+        mock_repo.expect_create_template().never();
+        let use_case = CreateMissionTemplateUseCase::new(Arc::new(mock_repo));
+
+        assert!(matches!(
+            use_case.execute(request("Streak", 1)).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+        assert!(matches!(
+            use_case.execute(request("Quiz", 0)).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+
+        let mut conditions_on_reading = request("ReadArticle", 1);
+        conditions_on_reading.min_accuracy = Some(80.0);
+        assert!(matches!(
+            use_case.execute(conditions_on_reading).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+    }
+}
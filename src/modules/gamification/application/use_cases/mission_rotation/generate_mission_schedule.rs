@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+use super::plan_schedule;
+use crate::modules::gamification::domain::entities::mission_rotation::MissionRotation;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+pub struct GenerateMissionScheduleUseCase {
+    pub repository: Arc<dyn MissionTemplateRepository>,
+    pub rotation: MissionRotation,
+}
+
+impl GenerateMissionScheduleUseCase {
+    pub fn new(repository: Arc<dyn MissionTemplateRepository>, rotation: MissionRotation) -> Self {
+        Self {
+            repository,
+            rotation,
+        }
+    }
+
+    /// Fills every day in `start..start + days` that has no missions yet.
+    /// Returns the number of missions written.
+    pub async fn execute(&self, start: NaiveDate, days: u32) -> Result<u64, GamificationError> {
+        let plan = plan_schedule(self.repository.as_ref(), self.rotation, start, days).await?;
+        Ok(self.repository.insert_daily_missions(&plan.planned).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::daily_mission::{
+        DailyMission, MissionType,
+    };
+    use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+    use crate::modules::gamification::domain::repositories::mission_template_repository::MockMissionTemplateRepository;
+    use uuid::Uuid;
+
+    fn template() -> MissionTemplate {
+        MissionTemplate::new(
+            Uuid::new_v4(),
+            "Baca {target} artikel".to_string(),
+            MissionType::ReadArticle,
+            (1, 3),
+            (10, 30),
+            1,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_generate_fills_only_unscheduled_days() {
+        let start = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        let templates = vec![template(), template(), template()];
+        let stored = DailyMission::new(
+            Uuid::new_v4(),
+            "Misi manual".to_string(),
+            1,
+            start,
+            10,
+            MissionType::Quiz,
+        )
+        .unwrap();
+
+        let mut mock_repo = MockMissionTemplateRepository::new();
+        mock_repo
+            .expect_get_templates()
+            .returning(move || Ok(templates.clone()));
+        mock_repo
+            .expect_get_missions_between()
+            .withf(move |from, to| {
+                *from == NaiveDate::from_ymd_opt(2026, 6, 29).unwrap()
+                    && *to == NaiveDate::from_ymd_opt(2026, 7, 3).unwrap()
+            })
+            .returning(move |_, _| Ok(vec![stored.clone()]));
+        mock_repo
+            .expect_insert_daily_missions()
+            .withf(move |missions| missions.len() == 4 && missions.iter().all(|m| m.date() > start))
+            .times(1)
+            .returning(|missions| Ok(missions.len() as u64));
+
+        let use_case =
+            GenerateMissionScheduleUseCase::new(Arc::new(mock_repo), MissionRotation::new(2, 2));
+
+        assert_eq!(use_case.execute(start, 3).await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_generate_rejects_out_of_range_horizon() {
+        let mut mock_repo = MockMissionTemplateRepository::new();
+        mock_repo.expect_insert_daily_missions().never();
+        let use_case =
+            GenerateMissionScheduleUseCase::new(Arc::new(mock_repo), MissionRotation::new(3, 3));
+        let start = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+
+        assert!(matches!(
+            use_case.execute(start, 0).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+        assert!(matches!(
+            use_case.execute(start, 90).await,
+            Err(GamificationError::InvalidData(_))
+        ));
+    }
+}
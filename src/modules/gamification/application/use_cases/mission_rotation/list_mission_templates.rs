@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::MissionTemplateDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+pub struct ListMissionTemplatesUseCase {
+    pub repository: Arc<dyn MissionTemplateRepository>,
+}
+
+impl ListMissionTemplatesUseCase {
+    pub fn new(repository: Arc<dyn MissionTemplateRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<MissionTemplateDto>, GamificationError> {
+        let templates = self.repository.get_templates().await?;
+        Ok(templates.iter().map(MissionTemplateDto::from).collect())
+    }
+}
@@ -0,0 +1,51 @@
+// Admin use cases for mission templates and the daily mission rotation
+pub mod create_mission_template;
+pub mod generate_mission_schedule;
+pub mod list_mission_templates;
+pub mod preview_mission_schedule;
+
+pub use create_mission_template::CreateMissionTemplateUseCase;
+pub use generate_mission_schedule::GenerateMissionScheduleUseCase;
+pub use list_mission_templates::ListMissionTemplatesUseCase;
+pub use preview_mission_schedule::PreviewMissionScheduleUseCase;
+
+use chrono::{Days, NaiveDate};
+
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::mission_rotation::MissionRotation;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+/// Longest horizon the rotation will plan or preview in one go.
+pub const MAX_SCHEDULE_DAYS: u32 = 31;
+
+/// Missions already stored for `start..start + days` and the ones the
+/// rotation would add on top of them.
+struct SchedulePlan {
+    existing: Vec<DailyMission>,
+    planned: Vec<DailyMission>,
+}
+
+async fn plan_schedule(
+    repository: &dyn MissionTemplateRepository,
+    rotation: MissionRotation,
+    start: NaiveDate,
+    days: u32,
+) -> Result<SchedulePlan, GamificationError> {
+    if days == 0 || days > MAX_SCHEDULE_DAYS {
+        return Err(GamificationError::InvalidData(format!(
+            "Jumlah hari jadwal harus antara 1 dan {}.",
+            MAX_SCHEDULE_DAYS
+        )));
+    }
+
+    let end = start + Days::new(days as u64 - 1);
+    let window_start = start - Days::new(rotation.repeat_window_days as u64);
+
+    let templates = repository.get_templates().await?;
+    let stored = repository.get_missions_between(window_start, end).await?;
+    let planned = rotation.plan_range(start, days, &templates, &stored);
+    let existing = stored.into_iter().filter(|m| m.date >= start).collect();
+
+    Ok(SchedulePlan { existing, planned })
+}
@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+use super::plan_schedule;
+use crate::modules::gamification::application::dto::ScheduledMissionDto;
+use crate::modules::gamification::domain::entities::mission_rotation::MissionRotation;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+pub struct PreviewMissionScheduleUseCase {
+    pub repository: Arc<dyn MissionTemplateRepository>,
+    pub rotation: MissionRotation,
+}
+
+impl PreviewMissionScheduleUseCase {
+    pub fn new(repository: Arc<dyn MissionTemplateRepository>, rotation: MissionRotation) -> Self {
+        Self {
+            repository,
+            rotation,
+        }
+    }
+
+    /// Upcoming missions for `start..start + days`: the stored ones plus what
+    /// the rotation would generate for empty days. Nothing is written.
+    pub async fn execute(
+        &self,
+        start: NaiveDate,
+        days: u32,
+    ) -> Result<Vec<ScheduledMissionDto>, GamificationError> {
+        let plan = plan_schedule(self.repository.as_ref(), self.rotation, start, days).await?;
+
+        let mut schedule: Vec<ScheduledMissionDto> = plan
+            .existing
+            .iter()
+            .map(ScheduledMissionDto::scheduled)
+            .chain(plan.planned.iter().map(ScheduledMissionDto::planned))
+            .collect();
+        schedule.sort_by_key(|m| m.date);
+
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::daily_mission::{
+        DailyMission, MissionType,
+    };
+    use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+    use crate::modules::gamification::domain::repositories::mission_template_repository::MockMissionTemplateRepository;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_preview_merges_stored_and_planned_missions() {
+        let start = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
+        let template = MissionTemplate::new(
+            Uuid::new_v4(),
+            "Kerjakan {target} kuis".to_string(),
+            MissionType::Quiz,
+            (1, 2),
+            (20, 40),
+            1,
+        )
+        .unwrap();
+        let earlier = DailyMission::new(
+            Uuid::new_v4(),
+            "Misi kemarin".to_string(),
+            1,
+            start.pred_opt().unwrap(),
+            10,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+        let stored = DailyMission::new(
+            Uuid::new_v4(),
+            "Misi manual".to_string(),
+            1,
+            start,
+            10,
+            MissionType::ReadArticle,
+        )
+        .unwrap();
+        let stored_id = stored.id();
+
+        let mut mock_repo = MockMissionTemplateRepository::new();
+        mock_repo
+            .expect_get_templates()
+            .returning(move || Ok(vec![template.clone()]));
+        mock_repo
+            .expect_get_missions_between()
+            .returning(move |_, _| Ok(vec![earlier.clone(), stored.clone()]));
+        mock_repo.expect_insert_daily_missions().never();
+
+        let use_case =
+            PreviewMissionScheduleUseCase::new(Arc::new(mock_repo), MissionRotation::new(1, 1));
+        let schedule = use_case.execute(start, 2).await.unwrap();
+
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[0].mission_id, Some(stored_id));
+        assert!(schedule[0].is_scheduled);
+        assert_eq!(schedule[1].date, start.succ_opt().unwrap());
+        assert!(!schedule[1].is_scheduled);
+        assert!(schedule[1].mission_id.is_none());
+    }
+}
@@ -1,6 +1,47 @@
 // Gamification Use Cases - ClaimMission, CheckAchievement
+pub mod achievement_catalog;
+pub mod acknowledge_level_ups;
+pub mod backfill_achievements;
 pub mod claim_mission_reward;
+pub mod get_achievements;
+pub mod get_points_ledger;
+pub mod get_showcase;
+pub mod get_today_missions;
+pub mod get_user_achievement_overview;
+pub mod get_user_achievements;
+pub mod get_user_level;
+pub mod get_user_missions;
+pub mod get_user_profile;
+pub mod get_user_streak;
+pub mod mission_rotation;
+pub mod recompute_points_balances;
+pub mod record_daily_login;
+pub mod reward_shop;
 pub mod sync_quiz_gamification;
+pub mod track_achievement_progress;
+pub mod track_mission_progress;
+pub mod update_showcase;
+pub mod update_streak;
+pub mod verify_points_balances;
 
+pub use acknowledge_level_ups::AcknowledgeLevelUpsUseCase;
+pub use backfill_achievements::BackfillAchievementsUseCase;
 pub use claim_mission_reward::ClaimMissionRewardUseCase;
+pub use get_achievements::GetAchievementsUseCase;
+pub use get_points_ledger::GetPointsLedgerUseCase;
+pub use get_showcase::GetShowcaseUseCase;
+pub use get_today_missions::GetTodayMissionsUseCase;
+pub use get_user_achievement_overview::GetUserAchievementOverviewUseCase;
+pub use get_user_achievements::GetUserAchievementsUseCase;
+pub use get_user_level::GetUserLevelUseCase;
+pub use get_user_missions::GetUserMissionsUseCase;
+pub use get_user_profile::GetUserProfileUseCase;
+pub use get_user_streak::GetUserStreakUseCase;
+pub use recompute_points_balances::RecomputePointsBalancesUseCase;
+pub use record_daily_login::RecordDailyLoginUseCase;
 pub use sync_quiz_gamification::SyncQuizGamificationUseCase;
+pub use track_achievement_progress::TrackAchievementProgressUseCase;
+pub use track_mission_progress::TrackMissionProgressUseCase;
+pub use update_showcase::UpdateShowcaseUseCase;
+pub use update_streak::UpdateStreakUseCase;
+pub use verify_points_balances::VerifyPointsBalancesUseCase;
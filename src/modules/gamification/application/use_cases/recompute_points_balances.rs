@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::PointsBalanceReportDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::points_ledger_repository::PointsLedgerRepository;
+
+pub struct RecomputePointsBalancesUseCase {
+    pub repository: Arc<dyn PointsLedgerRepository>,
+}
+
+impl RecomputePointsBalancesUseCase {
+    pub fn new(repository: Arc<dyn PointsLedgerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Rewrites every drifted total score from the ledger, which is the source
+    /// of truth. Reports the balances that were corrected.
+    pub async fn execute(&self) -> Result<PointsBalanceReportDto, GamificationError> {
+        let corrected = self.repository.rebuild_balances().await?;
+        for mismatch in &corrected {
+            tracing::warn!(
+                user_id = %mismatch.user_id,
+                total_score = mismatch.total_score,
+                ledger_total = mismatch.ledger_total,
+                "Skor user tidak cocok dengan ledger, dihitung ulang"
+            );
+        }
+        Ok(PointsBalanceReportDto::new(&corrected, true))
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::NaiveDateTime;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::track_mission_progress::TrackMissionProgressUseCase;
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::mission_repository::MissionRepository;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+
+pub struct RecordDailyLoginUseCase {
+    pub repository: Arc<dyn MissionRepository>,
+    pub outbox: Option<Arc<dyn OutboxRepository>>,
+}
+
+impl RecordDailyLoginUseCase {
+    pub fn new(repository: Arc<dyn MissionRepository>) -> Self {
+        Self {
+            repository,
+            outbox: None,
+        }
+    }
+
+    /// Announces the missions a login completes through `outbox`.
+    pub fn with_outbox(mut self, outbox: Arc<dyn OutboxRepository>) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
+
+    /// Applies a login at `at` to the user's `DailyLogin` missions.
+    /// Logging in again the same day is harmless: progress stops at the target.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        at: NaiveDateTime,
+    ) -> Result<i32, GamificationError> {
+        if !self.repository.user_exists(user_id).await? {
+            return Err(GamificationError::UserNotFound(format!(
+                "User {} tidak ditemukan",
+                user_id
+            )));
+        }
+
+        let mut tracker = TrackMissionProgressUseCase::new(self.repository.clone());
+        if let Some(outbox) = &self.outbox {
+            tracker = tracker.with_outbox(outbox.clone());
+        }
+        tracker
+            .execute(user_id, at, &[GamificationEvent::DailyLogin])
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
+
+    #[tokio::test]
+    async fn test_login_for_unknown_user_is_rejected() {
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo.expect_user_exists().returning(|_| Ok(false));
+        mock_repo.expect_get_active_missions_at().never();
+
+        let use_case = RecordDailyLoginUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                Uuid::new_v4(),
+                chrono::NaiveDate::from_ymd_opt(2026, 8, 1)
+                    .unwrap()
+                    .and_hms_opt(9, 0, 0)
+                    .unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(GamificationError::UserNotFound(_))));
+    }
+}
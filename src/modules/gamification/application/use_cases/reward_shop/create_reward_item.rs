@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::{RewardItemDto, RewardItemRequestDto};
+use crate::modules::gamification::domain::entities::reward_item::{
+    RewardItem, RewardKind, ScoreBoost,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+pub struct CreateRewardItemUseCase {
+    pub repository: Arc<dyn RewardShopRepository>,
+}
+
+impl CreateRewardItemUseCase {
+    pub fn new(repository: Arc<dyn RewardShopRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        dto: RewardItemRequestDto,
+    ) -> Result<RewardItemDto, GamificationError> {
+        let kind: RewardKind = dto
+            .kind
+            .parse()
+            .map_err(|e: &'static str| GamificationError::InvalidData(e.to_string()))?;
+        let boost = match (dto.boost_percent, dto.boost_hours) {
+            (None, None) => None,
+            (Some(percent), Some(hours)) => Some(ScoreBoost { percent, hours }),
+            _ => {
+                return Err(GamificationError::InvalidData(
+                    "boost_percent dan boost_hours harus diisi bersamaan.".to_string(),
+                ));
+            }
+        };
+
+        let item = RewardItem::new(Uuid::new_v4(), dto.name, dto.description, kind, dto.price)
+            .and_then(|item| item.with_stock(dto.stock))
+            .and_then(|item| item.with_per_user_limit(dto.per_user_limit))
+            .and_then(|item| item.with_boost(boost))
+            .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+
+        self.repository.create_item(&item).await?;
+
+        Ok(RewardItemDto::from(&item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::repositories::reward_shop_repository::MockRewardShopRepository;
+
+    fn request(kind: &str, boost_percent: Option<i32>) -> RewardItemRequestDto {
+        RewardItemRequestDto {
+            name: "Boost Akhir Pekan".to_string(),
+            description: String::new(),
+            kind: kind.to_string(),
+            price: 200,
+            stock: Some(10),
+            per_user_limit: Some(2),
+            boost_percent,
+            boost_hours: boost_percent.map(|_| 48),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_score_boost_persists_item() {
+        let mut mock_repo = MockRewardShopRepository::new();
+        mock_repo
+            .expect_create_item()
+            .withf(|item| item.kind() == RewardKind::ScoreBoost && item.stock() == Some(10))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateRewardItemUseCase::new(Arc::new(mock_repo));
+        let item = use_case
+            .execute(request("scoreboost", Some(25)))
+            .await
+            .unwrap();
+
+        assert_eq!(item.kind, "ScoreBoost");
+        assert_eq!(item.boost_hours, Some(48));
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_unknown_kind_and_half_boost() {
+        let mut mock_repo = MockRewardShopRepository::new();
+        mock_repo.expect_create_item().never();
+        let use_case = CreateRewardItemUseCase::new(Arc::new(mock_repo));
+
+        let unknown = use_case.execute(request("Badge", None)).await;
+        let mut half = request("ScoreBoost", Some(25));
+        half.boost_hours = None;
+        let half = use_case.execute(half).await;
+
+        assert!(matches!(unknown, Err(GamificationError::InvalidData(_))));
+        assert!(matches!(half, Err(GamificationError::InvalidData(_))));
+    }
+}
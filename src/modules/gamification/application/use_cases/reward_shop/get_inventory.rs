@@ -0,0 +1,21 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::InventoryItemDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+pub struct GetInventoryUseCase {
+    pub repository: Arc<dyn RewardShopRepository>,
+}
+
+impl GetInventoryUseCase {
+    pub fn new(repository: Arc<dyn RewardShopRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<InventoryItemDto>, GamificationError> {
+        let inventory = self.repository.get_inventory(user_id).await?;
+        Ok(inventory.iter().map(InventoryItemDto::from).collect())
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::RewardPurchaseDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+pub struct GetPurchaseHistoryUseCase {
+    pub repository: Arc<dyn RewardShopRepository>,
+}
+
+impl GetPurchaseHistoryUseCase {
+    pub fn new(repository: Arc<dyn RewardShopRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The user's purchases, newest first.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<RewardPurchaseDto>, GamificationError> {
+        let purchases = self.repository.get_purchases(user_id).await?;
+        Ok(purchases.iter().map(RewardPurchaseDto::from).collect())
+    }
+}
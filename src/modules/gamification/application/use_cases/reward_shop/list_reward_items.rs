@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::RewardItemDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+pub struct ListRewardItemsUseCase {
+    pub repository: Arc<dyn RewardShopRepository>,
+}
+
+impl ListRewardItemsUseCase {
+    pub fn new(repository: Arc<dyn RewardShopRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The items on sale, cheapest first; admins also see items taken off sale.
+    pub async fn execute(
+        &self,
+        include_inactive: bool,
+    ) -> Result<Vec<RewardItemDto>, GamificationError> {
+        let items = self.repository.get_items(include_inactive).await?;
+        Ok(items.iter().map(RewardItemDto::from).collect())
+    }
+}
@@ -0,0 +1,12 @@
+// Reward shop use cases: the catalog, purchases and what users hold
+pub mod create_reward_item;
+pub mod get_inventory;
+pub mod get_purchase_history;
+pub mod list_reward_items;
+pub mod purchase_reward;
+
+pub use create_reward_item::CreateRewardItemUseCase;
+pub use get_inventory::GetInventoryUseCase;
+pub use get_purchase_history::GetPurchaseHistoryUseCase;
+pub use list_reward_items::ListRewardItemsUseCase;
+pub use purchase_reward::PurchaseRewardUseCase;
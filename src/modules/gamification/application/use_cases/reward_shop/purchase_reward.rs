@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::application::dto::RewardPurchaseDto;
+use crate::modules::gamification::domain::entities::reward_item::PurchaseRejection;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+pub struct PurchaseRewardUseCase {
+    pub repository: Arc<dyn RewardShopRepository>,
+}
+
+impl PurchaseRewardUseCase {
+    pub fn new(repository: Arc<dyn RewardShopRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Buys one item for the user, debiting its price from their points. The
+    /// shop rules are checked by the repository under the same locks that
+    /// write the purchase, so concurrent requests cannot overspend.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        item_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<RewardPurchaseDto, GamificationError> {
+        let purchase = self
+            .repository
+            .purchase(user_id, item_id, Uuid::new_v4(), now)
+            .await?
+            .map_err(|rejection| {
+                let message = rejection.to_string();
+                match rejection {
+                    PurchaseRejection::UnknownUser => GamificationError::UserNotFound(message),
+                    PurchaseRejection::UnknownItem | PurchaseRejection::Unavailable => {
+                        GamificationError::RewardNotFound(message)
+                    }
+                    PurchaseRejection::SoldOut | PurchaseRejection::LimitReached { .. } => {
+                        GamificationError::RewardUnavailable(message)
+                    }
+                    PurchaseRejection::InsufficientPoints { .. } => {
+                        GamificationError::InsufficientPoints(message)
+                    }
+                }
+            })?;
+
+        Ok(RewardPurchaseDto::from(&purchase))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::reward_item::RewardKind;
+    use crate::modules::gamification::domain::entities::reward_purchase::RewardPurchase;
+    use crate::modules::gamification::domain::repositories::reward_shop_repository::MockRewardShopRepository;
+
+    #[tokio::test]
+    async fn test_purchase_returns_the_recorded_purchase() {
+        let user_id = Uuid::new_v4();
+        let item_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut mock_repo = MockRewardShopRepository::new();
+        mock_repo
+            .expect_purchase()
+            .withf(move |user, item, _, at| *user == user_id && *item == item_id && *at == now)
+            .times(1)
+            .returning(move |user_id, item_id, purchase_id, purchased_at| {
+                Ok(Ok(RewardPurchase {
+                    id: purchase_id,
+                    user_id,
+                    item_id,
+                    item_name: "Streak Freeze".to_string(),
+                    kind: RewardKind::StreakFreeze,
+                    price: 80,
+                    purchased_at,
+                    expires_at: None,
+                }))
+            });
+
+        let use_case = PurchaseRewardUseCase::new(Arc::new(mock_repo));
+        let purchase = use_case.execute(user_id, item_id, now).await.unwrap();
+
+        assert_eq!(purchase.item_id, item_id);
+        assert_eq!(purchase.kind, "StreakFreeze");
+        assert_eq!(purchase.price, 80);
+    }
+
+    #[tokio::test]
+    async fn test_rejections_map_to_errors() {
+        let cases = [
+            (
+                PurchaseRejection::InsufficientPoints {
+                    balance: 10,
+                    price: 80,
+                },
+                "insufficient",
+            ),
+            (PurchaseRejection::SoldOut, "unavailable"),
+            (PurchaseRejection::LimitReached { limit: 1 }, "unavailable"),
+            (PurchaseRejection::Unavailable, "not_found"),
+            (PurchaseRejection::UnknownUser, "user"),
+        ];
+
+        for (rejection, expected) in cases {
+            let mut mock_repo = MockRewardShopRepository::new();
+            mock_repo
+                .expect_purchase()
+                .return_once(move |_, _, _, _| Ok(Err(rejection)));
+
+            let use_case = PurchaseRewardUseCase::new(Arc::new(mock_repo));
+            let result = use_case
+                .execute(Uuid::new_v4(), Uuid::new_v4(), Utc::now())
+                .await;
+
+            let matched = match expected {
+                "insufficient" => matches!(result, Err(GamificationError::InsufficientPoints(_))),
+                "unavailable" => matches!(result, Err(GamificationError::RewardUnavailable(_))),
+                "not_found" => matches!(result, Err(GamificationError::RewardNotFound(_))),
+                _ => matches!(result, Err(GamificationError::UserNotFound(_))),
+            };
+            assert!(matched, "unexpected result for {}", expected);
+        }
+    }
+}
@@ -1,103 +1,122 @@
-use chrono::Utc;
-use std::collections::HashMap;
 use std::sync::Arc;
 
+use super::track_achievement_progress::TrackAchievementProgressUseCase;
+use super::track_mission_progress::TrackMissionProgressUseCase;
+use super::update_streak::UpdateStreakUseCase;
 use crate::modules::gamification::application::dto::quiz_sync::SyncQuizHistoryRequestDto;
-use crate::modules::gamification::domain::entities::achievement::Achievement;
-use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
-use crate::modules::gamification::domain::entities::daily_mission::MissionType;
-use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
-use crate::modules::gamification::domain::entities::user_mission::UserMission;
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::domain::entities::user_streak::StreakPolicy;
+use crate::modules::gamification::domain::errors::GamificationError;
 use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
 use crate::modules::gamification::domain::repositories::mission_repository::MissionRepository;
+use crate::modules::gamification::domain::repositories::streak_repository::StreakRepository;
+use crate::shared::domain::outbox_repository::OutboxRepository;
 
 pub struct SyncQuizGamificationUseCase {
     pub mission_repo: Arc<dyn MissionRepository>,
     pub achievement_repo: Arc<dyn AchievementRepository>,
+    pub streak_repo: Arc<dyn StreakRepository>,
+    pub streak_policy: StreakPolicy,
+    pub outbox: Option<Arc<dyn OutboxRepository>>,
 }
 
 impl SyncQuizGamificationUseCase {
     pub fn new(
         mission_repo: Arc<dyn MissionRepository>,
         achievement_repo: Arc<dyn AchievementRepository>,
+        streak_repo: Arc<dyn StreakRepository>,
+        streak_policy: StreakPolicy,
     ) -> Self {
         Self {
             mission_repo,
             achievement_repo,
+            streak_repo,
+            streak_policy,
+            outbox: None,
         }
     }
 
-    /// Syncs quiz completion to gamification: updates mission progress and achievements.
+    /// Announces the missions completed and achievements unlocked through `outbox`.
+    pub fn with_outbox(mut self, outbox: Arc<dyn OutboxRepository>) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
+
+    /// Syncs quiz completion to gamification: updates the reading streak,
+    /// mission progress and achievements.
     ///
-    /// For each active daily mission containing "baca" (read), increments user progress.
-    /// For each achievement, adds progress and grants reward points upon completion.
-    pub async fn execute(&self, payload: SyncQuizHistoryRequestDto) -> Result<(), String> {
-        let now = Utc::now();
-        let today = now.naive_utc().date();
-
-        let active_missions = self.mission_repo.get_active_missions_by_date(today).await?;
-
-        for mission in active_missions {
-            if let MissionType::ReadArticle = mission.mission_type() {
-                let mut user_mission = match self.mission_repo.get_user_mission(payload.user_id, mission.id()).await? {
-                    Some(um) => um,
-                    None => {
-                        crate::modules::gamification::domain::entities::user_mission::UserMission::new(payload.user_id, mission.id())
-                    }
-                };
-
-                user_mission.add_progress(1, mission.target_count());
-
-                self.mission_repo.save_user_mission(&user_mission).await?;
-            }
-        }
+    /// The quiz counts as an article read and a completed quiz. Each advances
+    /// the missions of the matching type running on the user's local day, and
+    /// achievements progress according to their criteria, streak length
+    /// included. Streak milestones award their points here. Everything is dated
+    /// `payload.completed_at`, so a quiz credited late still lands on the day it
+    /// was finished. Returns how many daily missions received progress.
+    pub async fn execute(
+        &self,
+        payload: SyncQuizHistoryRequestDto,
+    ) -> Result<i32, GamificationError> {
+        let now = payload.completed_at;
+
+        // The quiz is recorded before gamification runs, so a first read counts one row.
+        let reads = self
+            .achievement_repo
+            .count_article_reads(payload.user_id, payload.article_id)
+            .await?;
+        let mut events = payload.events(reads <= 1).to_vec();
 
-        let all_achievements = self.achievement_repo.get_all_achievements().await?;
+        let streak = UpdateStreakUseCase::new(self.streak_repo.clone(), self.streak_policy.clone())
+            .execute(payload.user_id, now, payload.timezone)
+            .await?;
+        events.extend(streak.event());
 
-        let achievement_map: HashMap<_, _> = all_achievements
-            .into_iter()
-            .map(|ach| (ach.id(), ach))
-            .collect();
+        let mut missions = TrackMissionProgressUseCase::new(self.mission_repo.clone());
+        let mut achievements = TrackAchievementProgressUseCase::new(self.achievement_repo.clone());
+        if let Some(outbox) = &self.outbox {
+            missions = missions.with_outbox(outbox.clone());
+            achievements = achievements.with_outbox(outbox.clone());
+        }
 
-        let user_achievements = self
-            .achievement_repo
-            .get_user_achievements(payload.user_id)
+        let missions_updated = missions
+            .execute(payload.user_id, payload.timezone.local_time(now), &events)
             .await?;
-
-        for mut user_ach in user_achievements {
-            if user_ach.is_completed() {
-                continue;
-            }
-
-            if let Some(achievement_master) = achievement_map.get(&user_ach.achievement_id()) {
-                user_ach.add_progress(1, achievement_master.milestone_target(), now);
-
-                // reward otomatis dapat habis selesaikan achievement
-                if user_ach.is_completed() {
-                    self.achievement_repo
-                        .add_user_score(payload.user_id, achievement_master.reward_points())
-                        .await?;
-                }
-
-                self.achievement_repo
-                    .save_user_achievement(&user_ach)
-                    .await?;
-            }
+        achievements.execute(payload.user_id, &events, now).await?;
+
+        if streak.reward_points() > 0 {
+            self.achievement_repo
+                .award_points(&[PointsEntry::new(
+                    payload.user_id,
+                    streak.reward_points(),
+                    PointsSource::StreakMilestone,
+                    None,
+                    now,
+                )])
+                .await?;
         }
 
-        Ok(())
+        Ok(missions_updated)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::gamification::domain::entities::achievement::AchievementType;
-    use chrono::NaiveDate;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+    use crate::modules::gamification::domain::entities::daily_mission::{
+        DailyMission, MissionType, QuizConditions,
+    };
+    use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+    use crate::modules::gamification::domain::entities::user_mission::UserMission;
+    use crate::modules::gamification::domain::entities::user_streak::UserStreak;
+    use chrono::{NaiveDate, Utc};
     use uuid::Uuid;
 
     use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
     use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
+    use crate::modules::gamification::domain::repositories::streak_repository::MockStreakRepository;
+    use crate::shared::domain::user_timezone::UserTimezone;
 
     fn create_test_mission(
         id: Uuid,
@@ -126,9 +145,26 @@ mod tests {
             article_id: Uuid::new_v4(),
             score: 80,
             accuracy: 0.85,
+            timezone: UserTimezone::UTC,
+            completed_at: Utc::now(),
         }
     }
 
+    fn create_use_case(
+        mission_repo: MockMissionRepository,
+        achievement_repo: MockAchievementRepository,
+    ) -> SyncQuizGamificationUseCase {
+        let mut streak_repo = MockStreakRepository::new();
+        streak_repo.expect_get_streak().returning(|_| Ok(None));
+        streak_repo.expect_save_streak().returning(|_| Ok(()));
+        SyncQuizGamificationUseCase::new(
+            Arc::new(mission_repo),
+            Arc::new(achievement_repo),
+            Arc::new(streak_repo),
+            StreakPolicy::default(),
+        )
+    }
+
     #[tokio::test]
     async fn sync_quiz_creates_new_mission_progress() {
         let user_id = Uuid::new_v4();
@@ -141,18 +177,21 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(move |_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(None));
+            .return_once(|_, _, _| Ok(None));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -161,8 +200,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -177,22 +215,26 @@ mod tests {
         let mission_type = MissionType::ReadArticle;
 
         let mission = create_test_mission(mission_id, "Baca 3 Berita", 3, today, mission_type);
-        let existing_user_mission = UserMission::new(user_id, mission_id);
+        let existing_user_mission =
+            UserMission::new(user_id, mission_id, mission.window().starts_at);
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(Some(existing_user_mission)));
+            .return_once(|_, _, _| Ok(Some(existing_user_mission)));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -201,8 +243,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -217,23 +258,27 @@ mod tests {
         let mission_type = MissionType::ReadArticle;
 
         let mission = create_test_mission(mission_id, "Baca 3 Berita", 3, today, mission_type);
-        let mut existing_user_mission = UserMission::new(user_id, mission_id);
+        let mut existing_user_mission =
+            UserMission::new(user_id, mission_id, mission.window().starts_at);
         existing_user_mission.add_progress(3, 3);
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(Some(existing_user_mission)));
+            .return_once(|_, _, _| Ok(Some(existing_user_mission)));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -242,8 +287,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -257,10 +301,13 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![]));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -269,8 +316,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -284,13 +330,16 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![]));
 
         let achievement = create_test_achievement(achievement_id, "Quiz Starter", 5, 50);
         let user_achievement = UserAchievement::new(user_id, achievement_id);
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![user_achievement]));
@@ -300,11 +349,10 @@ mod tests {
             .return_once(move || Ok(vec![achievement.clone()]));
 
         achievement_repo
-            .expect_save_user_achievement()
+            .expect_save_user_achievements()
             .returning(|_| Ok(()));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -318,7 +366,7 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![]));
 
         let achievement = create_test_achievement(achievement_id, "First Quiz", 1, 100);
@@ -326,6 +374,9 @@ mod tests {
         user_achievement.add_progress(1, 1, Utc::now());
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![user_achievement]));
@@ -335,15 +386,14 @@ mod tests {
             .return_once(move || Ok(vec![achievement.clone()]));
 
         achievement_repo
-            .expect_add_user_score()
-            .return_once(|_, _| Ok(()));
+            .expect_award_points()
+            .return_once(|_| Ok(()));
 
         achievement_repo
-            .expect_save_user_achievement()
+            .expect_save_user_achievements()
             .returning(|_| Ok(()));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -357,7 +407,7 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![]));
 
         let achievement = create_test_achievement(achievement_id, "Quiz Master", 2, 250);
@@ -365,6 +415,9 @@ mod tests {
         user_achievement.add_progress(1, 2, Utc::now());
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![user_achievement]));
@@ -374,15 +427,14 @@ mod tests {
             .return_once(move || Ok(vec![achievement.clone()]));
 
         achievement_repo
-            .expect_add_user_score()
-            .return_once(|_, _| Ok(()));
+            .expect_award_points()
+            .return_once(|_| Ok(()));
 
         achievement_repo
-            .expect_save_user_achievement()
+            .expect_save_user_achievements()
             .returning(|_| Ok(()));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -399,18 +451,21 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(None));
+            .return_once(|_, _, _| Ok(None));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -419,8 +474,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -434,14 +488,19 @@ mod tests {
             article_id: Uuid::nil(),
             score: 100,
             accuracy: 85.0,
+            timezone: UserTimezone::UTC,
+            completed_at: Utc::now(),
         };
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![]));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -450,8 +509,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -469,18 +527,21 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(None));
+            .return_once(|_, _, _| Ok(None));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -489,8 +550,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -505,23 +565,27 @@ mod tests {
         let mission_type = MissionType::ReadArticle;
 
         let mission = create_test_mission(mission_id, "Baca 3 Berita", 3, today, mission_type);
-        let mut existing_user_mission = UserMission::new(user_id, mission_id);
+        let mut existing_user_mission =
+            UserMission::new(user_id, mission_id, mission.window().starts_at);
         existing_user_mission.add_progress(3, 3);
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission]));
 
         mission_repo
             .expect_get_user_mission()
-            .return_once(|_, _| Ok(Some(existing_user_mission)));
+            .return_once(|_, _, _| Ok(Some(existing_user_mission)));
 
         mission_repo
             .expect_save_user_mission()
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -530,8 +594,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
         assert!(result.is_ok());
@@ -551,13 +614,13 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .return_once(|_| Ok(vec![mission1, mission2]));
 
         mission_repo
             .expect_get_user_mission()
             .times(2)
-            .returning(|_, _| Ok(None));
+            .returning(|_, _, _| Ok(None));
 
         mission_repo
             .expect_save_user_mission()
@@ -565,6 +628,9 @@ mod tests {
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .return_once(|_| Ok(vec![]));
@@ -573,11 +639,10 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let result = use_case.execute(payload).await;
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 2);
     }
 
     #[tokio::test]
@@ -591,14 +656,14 @@ mod tests {
 
         let mut mission_repo = MockMissionRepository::new();
         mission_repo
-            .expect_get_active_missions_by_date()
+            .expect_get_active_missions_at()
             .times(2)
             .returning(move |_| Ok(vec![mission.clone()]));
 
         mission_repo
             .expect_get_user_mission()
             .times(2)
-            .returning(|_, _| Ok(None));
+            .returning(|_, _, _| Ok(None));
 
         mission_repo
             .expect_save_user_mission()
@@ -606,6 +671,9 @@ mod tests {
             .returning(|_| Ok(()));
 
         let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
         achievement_repo
             .expect_get_user_achievements()
             .times(2)
@@ -615,8 +683,7 @@ mod tests {
             .expect_get_all_achievements()
             .returning(|| Ok(vec![]));
 
-        let use_case =
-            SyncQuizGamificationUseCase::new(Arc::new(mission_repo), Arc::new(achievement_repo));
+        let use_case = create_use_case(mission_repo, achievement_repo);
 
         let payload1 = create_payload(user_id);
         let payload2 = create_payload(user_id);
@@ -627,4 +694,167 @@ mod tests {
         assert!(result1.is_ok());
         assert!(result2.is_ok());
     }
+
+    #[tokio::test]
+    async fn sync_quiz_skips_retired_achievements() {
+        let user_id = Uuid::new_v4();
+        let achievement_id = Uuid::new_v4();
+        let payload = create_payload(user_id);
+
+        let mut mission_repo = MockMissionRepository::new();
+        mission_repo
+            .expect_get_active_missions_at()
+            .return_once(|_| Ok(vec![]));
+
+        let mut achievement = create_test_achievement(achievement_id, "Musim Lalu", 1, 100);
+        achievement.retire(Utc::now());
+        let user_achievement = UserAchievement::new(user_id, achievement_id);
+
+        let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
+        achievement_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![user_achievement]));
+        achievement_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![achievement]));
+        achievement_repo.expect_save_user_achievements().never();
+        achievement_repo.expect_award_points().never();
+
+        let use_case = create_use_case(mission_repo, achievement_repo);
+
+        let result = use_case.execute(payload).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn sync_quiz_advances_read_and_matching_quiz_missions() {
+        let user_id = Uuid::new_v4();
+        let today = Utc::now().naive_utc().date();
+        let payload = create_payload(user_id);
+
+        let read = create_test_mission(
+            Uuid::new_v4(),
+            "Baca 3 Berita",
+            3,
+            today,
+            MissionType::ReadArticle,
+        );
+        let quiz = create_test_mission(
+            Uuid::new_v4(),
+            "Kerjakan 2 Kuis",
+            2,
+            today,
+            MissionType::Quiz,
+        );
+        let perfect =
+            create_test_mission(Uuid::new_v4(), "Kuis sempurna", 1, today, MissionType::Quiz)
+                .with_quiz_conditions(QuizConditions::new(None, Some(100.0)).unwrap());
+        let login = create_test_mission(Uuid::new_v4(), "Login", 1, today, MissionType::DailyLogin);
+        let advanced = [read.id(), quiz.id()];
+
+        let mut mission_repo = MockMissionRepository::new();
+        mission_repo
+            .expect_get_active_missions_at()
+            .return_once(move |_| Ok(vec![read, quiz, perfect, login]));
+        mission_repo
+            .expect_get_user_mission()
+            .times(2)
+            .returning(|_, _, _| Ok(None));
+        mission_repo
+            .expect_save_user_mission()
+            .withf(move |um| advanced.contains(&um.mission_id()))
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
+        achievement_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![]));
+        achievement_repo
+            .expect_get_all_achievements()
+            .returning(|| Ok(vec![]));
+
+        let use_case = create_use_case(mission_repo, achievement_repo);
+
+        let result = use_case.execute(payload).await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn sync_quiz_streak_milestone_awards_points_and_feeds_achievements() {
+        let user_id = Uuid::new_v4();
+        let streak_achievement = create_test_achievement(Uuid::new_v4(), "Seminggu", 7, 70)
+            .with_criteria(AchievementCriteria::StreakLength)
+            .unwrap();
+        let yesterday = Utc::now().date_naive() - chrono::Days::new(1);
+        let existing = UserStreak {
+            current_streak: 6,
+            longest_streak: 6,
+            last_active_date: Some(yesterday),
+            ..UserStreak::new(user_id, 0)
+        };
+
+        let mut mission_repo = MockMissionRepository::new();
+        mission_repo
+            .expect_get_active_missions_at()
+            .return_once(|_| Ok(vec![]));
+
+        let mut achievement_repo = MockAchievementRepository::new();
+        achievement_repo
+            .expect_count_article_reads()
+            .returning(|_, _| Ok(1));
+        achievement_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![streak_achievement]));
+        achievement_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![]));
+        achievement_repo
+            .expect_save_user_achievements()
+            .withf(|saved| saved.len() == 1 && saved[0].is_completed())
+            .times(1)
+            .returning(|_| Ok(()));
+        // 70 from the streak achievement, then 50 from the 7-day milestone.
+        achievement_repo
+            .expect_award_points()
+            .withf(|entries| {
+                matches!(entries, [entry] if entry.delta == 70
+                    && entry.source == PointsSource::Achievement)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        achievement_repo
+            .expect_award_points()
+            .withf(|entries| {
+                matches!(entries, [entry] if entry.delta == 50
+                    && entry.source == PointsSource::StreakMilestone)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut streak_repo = MockStreakRepository::new();
+        streak_repo
+            .expect_get_streak()
+            .return_once(move |_| Ok(Some(existing)));
+        streak_repo
+            .expect_save_streak()
+            .withf(|s| s.current_streak == 7 && s.freezes_available == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = SyncQuizGamificationUseCase::new(
+            Arc::new(mission_repo),
+            Arc::new(achievement_repo),
+            Arc::new(streak_repo),
+            StreakPolicy::default(),
+        );
+
+        assert!(use_case.execute(create_payload(user_id)).await.is_ok());
+    }
 }
@@ -0,0 +1,476 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::achievement_chain::AchievementChain;
+use crate::modules::gamification::domain::entities::criteria_evaluator::CriteriaEvaluator;
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+use crate::shared::domain::outbox::EngineEvent;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+
+pub struct TrackAchievementProgressUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+    pub outbox: Option<Arc<dyn OutboxRepository>>,
+}
+
+impl TrackAchievementProgressUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>) -> Self {
+        Self {
+            repository,
+            outbox: None,
+        }
+    }
+
+    /// Announces every unlocked achievement through `outbox`.
+    pub fn with_outbox(mut self, outbox: Arc<dyn OutboxRepository>) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
+
+    /// Applies `events` to every active achievement in the catalog according to
+    /// its criteria, enrolling the user on first progress and granting reward
+    /// points on completion. In a chain only the lowest unfinished tier accrues
+    /// progress, and whatever it reaches carries into the tiers above it, so one
+    /// batch of events can complete several tiers. Returns how many achievements
+    /// received progress.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        events: &[GamificationEvent],
+        now: DateTime<Utc>,
+    ) -> Result<i32, GamificationError> {
+        let catalog = self.repository.get_all_achievements().await?;
+        let mut existing: HashMap<_, _> = self
+            .repository
+            .get_user_achievements(user_id)
+            .await?
+            .into_iter()
+            .map(|ua| (ua.achievement_id(), ua))
+            .collect();
+
+        let mut changed = Vec::new();
+        let mut awards = Vec::new();
+        let mut unlocked = Vec::new();
+
+        let standalone = catalog
+            .iter()
+            .filter(|ach| !ach.is_retired() && ach.chain().is_none());
+        for achievement in standalone {
+            // user belum punya baris progres -> enroll otomatis saat progres pertama
+            let mut user_ach = existing
+                .remove(&achievement.id())
+                .unwrap_or_else(|| UserAchievement::new(user_id, achievement.id()));
+            if user_ach.is_completed() {
+                continue;
+            }
+
+            let delta = CriteriaEvaluator::total_delta(
+                achievement.criteria(),
+                events,
+                user_ach.current_progress(),
+            );
+            if delta == 0 {
+                continue;
+            }
+
+            Self::advance(
+                achievement,
+                &mut user_ach,
+                delta,
+                now,
+                &mut awards,
+                &mut unlocked,
+            );
+            changed.push(user_ach);
+        }
+
+        for chain in AchievementChain::from_catalog(&catalog) {
+            let Some(start) =
+                chain.next_tier_index(|id| existing.get(&id).is_some_and(|ua| ua.is_completed()))
+            else {
+                continue;
+            };
+
+            let current = &chain.tiers()[start];
+            let current_progress = existing
+                .get(&current.id())
+                .map_or(0, |ua| ua.current_progress());
+            let delta =
+                CriteriaEvaluator::total_delta(current.criteria(), events, current_progress);
+            if delta == 0 {
+                continue;
+            }
+
+            // progres tier yang selesai ikut terbawa ke tier berikutnya
+            let reached = current_progress + delta;
+            for tier in chain.tiers()[start..]
+                .iter()
+                .filter(|ach| !ach.is_retired())
+            {
+                let mut user_ach = existing
+                    .remove(&tier.id())
+                    .unwrap_or_else(|| UserAchievement::new(user_id, tier.id()));
+                if user_ach.is_completed() {
+                    continue;
+                }
+
+                let amount = reached - user_ach.current_progress();
+                if amount <= 0 {
+                    break;
+                }
+                Self::advance(tier, &mut user_ach, amount, now, &mut awards, &mut unlocked);
+                let completed = user_ach.is_completed();
+                changed.push(user_ach);
+                if !completed {
+                    break;
+                }
+            }
+        }
+
+        if changed.is_empty() {
+            return Ok(0);
+        }
+
+        if !awards.is_empty() {
+            self.repository.award_points(&awards).await?;
+        }
+        self.repository.save_user_achievements(&changed).await?;
+        if let Some(outbox) = &self.outbox {
+            outbox.enqueue(&unlocked, now).await?;
+        }
+
+        Ok(changed.len() as i32)
+    }
+
+    fn advance(
+        achievement: &Achievement,
+        user_ach: &mut UserAchievement,
+        amount: i32,
+        now: DateTime<Utc>,
+        awards: &mut Vec<PointsEntry>,
+        unlocked: &mut Vec<EngineEvent>,
+    ) {
+        user_ach.add_progress(amount, achievement.milestone_target(), now);
+        if user_ach.is_completed() {
+            unlocked.push(EngineEvent::AchievementUnlocked {
+                user_id: user_ach.user_id(),
+                achievement_id: achievement.id(),
+                name: achievement.name().to_string(),
+            });
+        }
+
+        // reward otomatis dapat habis selesaikan achievement
+        if user_ach.is_completed() && achievement.reward_points() > 0 {
+            awards.push(PointsEntry::new(
+                user_ach.user_id(),
+                achievement.reward_points(),
+                PointsSource::Achievement,
+                Some(achievement.id()),
+                now,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::{
+        Achievement, AchievementType,
+    };
+    use crate::modules::gamification::domain::entities::achievement_chain::ChainLink;
+    use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+    use crate::shared::domain::outbox_repository::MockOutboxRepository;
+
+    fn achievement(criteria: AchievementCriteria, target: i32) -> Achievement {
+        Achievement::new(
+            Uuid::new_v4(),
+            format!("{:?}", criteria),
+            target,
+            AchievementType::Rare,
+            40,
+        )
+        .unwrap()
+        .with_criteria(criteria)
+        .unwrap()
+    }
+
+    fn score_tier(tier: i32, target: i32) -> Achievement {
+        achievement(AchievementCriteria::CumulativeScore, target)
+            .with_chain(Some(ChainLink::new("Skor".to_string(), tier).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_progress_follows_each_achievements_criteria() {
+        let user_id = Uuid::new_v4();
+        let score = achievement(AchievementCriteria::CumulativeScore, 500);
+        let perfect = achievement(AchievementCriteria::PerfectQuizzes, 3);
+        let score_id = score.id();
+        let score_progress = UserAchievement::new(user_id, score.id());
+        let perfect_progress = UserAchievement::new(user_id, perfect.id());
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![score, perfect]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![score_progress, perfect_progress]));
+        mock_repo
+            .expect_save_user_achievements()
+            .withf(move |saved| {
+                saved.len() == 1
+                    && saved[0].achievement_id() == score_id
+                    && saved[0].current_progress() == 85
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo.expect_award_points().never();
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::QuizCompleted {
+            score: 85,
+            accuracy: 90.0,
+        };
+        let updated = use_case
+            .execute(user_id, &[event], Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(updated, 1);
+    }
+
+    #[tokio::test]
+    async fn test_completion_grants_reward_once() {
+        let user_id = Uuid::new_v4();
+        let claims = achievement(AchievementCriteria::MissionsClaimed, 2);
+        let mut progress = UserAchievement::new(user_id, claims.id());
+        progress.add_progress(1, 2, Utc::now());
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![claims]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![progress]));
+        mock_repo
+            .expect_award_points()
+            .withf(|entries| {
+                matches!(entries, [entry] if entry.delta == 40
+                    && entry.source == PointsSource::Achievement)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo
+            .expect_save_user_achievements()
+            .withf(|saved| saved.len() == 1 && saved[0].is_completed())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::MissionClaimed {
+            mission_id: Uuid::new_v4(),
+        };
+
+        assert_eq!(
+            use_case
+                .execute(user_id, &[event], Utc::now())
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unlocks_are_enqueued_with_the_progress() {
+        let user_id = Uuid::new_v4();
+        let perfect = achievement(AchievementCriteria::PerfectQuizzes, 1);
+        let pending = achievement(AchievementCriteria::PerfectQuizzes, 2);
+        let perfect_id = perfect.id();
+        let perfect_name = perfect.name().to_string();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![perfect, pending]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![]));
+        mock_repo.expect_award_points().returning(|_| Ok(()));
+        mock_repo
+            .expect_save_user_achievements()
+            .returning(|_| Ok(()));
+
+        let mut mock_outbox = MockOutboxRepository::new();
+        mock_outbox
+            .expect_enqueue()
+            .withf(move |events, _| {
+                events
+                    == [EngineEvent::AchievementUnlocked {
+                        user_id,
+                        achievement_id: perfect_id,
+                        name: perfect_name.clone(),
+                    }]
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo))
+            .with_outbox(Arc::new(mock_outbox));
+        let event = GamificationEvent::QuizCompleted {
+            score: 100,
+            accuracy: 100.0,
+        };
+
+        assert_eq!(
+            use_case
+                .execute(user_id, &[event], Utc::now())
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enrolls_user_on_first_progress_in_one_bulk_save() {
+        let user_id = Uuid::new_v4();
+        let quizzes = achievement(
+            AchievementCriteria::QuizzesCompleted { min_accuracy: None },
+            5,
+        );
+        let perfect = achievement(AchievementCriteria::PerfectQuizzes, 1);
+        let claims = achievement(AchievementCriteria::MissionsClaimed, 3);
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![quizzes, perfect, claims]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![]));
+        mock_repo
+            .expect_award_points()
+            .withf(|entries| {
+                matches!(entries, [entry] if entry.delta == 40
+                    && entry.source == PointsSource::Achievement)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo
+            .expect_save_user_achievements()
+            .withf(move |saved| saved.len() == 2 && saved.iter().all(|ua| ua.user_id() == user_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::QuizCompleted {
+            score: 100,
+            accuracy: 100.0,
+        };
+
+        assert_eq!(
+            use_case
+                .execute(user_id, &[event], Utc::now())
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chain_only_advances_its_lowest_unfinished_tier() {
+        let user_id = Uuid::new_v4();
+        let first = score_tier(1, 100);
+        let second = score_tier(2, 300);
+        let first_id = first.id();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(vec![second, first]));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(|_| Ok(vec![]));
+        mock_repo.expect_award_points().never();
+        mock_repo
+            .expect_save_user_achievements()
+            .withf(move |saved| {
+                saved.len() == 1
+                    && saved[0].achievement_id() == first_id
+                    && saved[0].current_progress() == 60
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::QuizCompleted {
+            score: 60,
+            accuracy: 70.0,
+        };
+
+        assert_eq!(
+            use_case
+                .execute(user_id, &[event], Utc::now())
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chain_progress_carries_over_into_higher_tiers() {
+        let user_id = Uuid::new_v4();
+        let tiers = vec![score_tier(1, 50), score_tier(2, 100), score_tier(3, 200)];
+        let third_id = tiers[2].id();
+        let mut progress = UserAchievement::new(user_id, tiers[0].id());
+        progress.add_progress(40, 50, Utc::now());
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_all_achievements()
+            .return_once(move || Ok(tiers));
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![progress]));
+        mock_repo
+            .expect_award_points()
+            .withf(|entries| entries.len() == 2)
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo
+            .expect_save_user_achievements()
+            .withf(move |saved| {
+                saved.len() == 3
+                    && saved[0].is_completed()
+                    && saved[1].is_completed()
+                    && saved[2].achievement_id() == third_id
+                    && saved[2].current_progress() == 120
+                    && !saved[2].is_completed()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackAchievementProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::QuizCompleted {
+            score: 80,
+            accuracy: 70.0,
+        };
+
+        assert_eq!(
+            use_case
+                .execute(user_id, &[event], Utc::now())
+                .await
+                .unwrap(),
+            3
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use chrono::{NaiveDateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::modules::gamification::domain::entities::user_mission::UserMission;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::mission_repository::MissionRepository;
+use crate::shared::domain::outbox::EngineEvent;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+
+pub struct TrackMissionProgressUseCase {
+    pub repository: Arc<dyn MissionRepository>,
+    pub outbox: Option<Arc<dyn OutboxRepository>>,
+}
+
+impl TrackMissionProgressUseCase {
+    pub fn new(repository: Arc<dyn MissionRepository>) -> Self {
+        Self {
+            repository,
+            outbox: None,
+        }
+    }
+
+    /// Announces every mission instance this progress completes through `outbox`.
+    pub fn with_outbox(mut self, outbox: Arc<dyn OutboxRepository>) -> Self {
+        self.outbox = Some(outbox);
+        self
+    }
+
+    /// Advances the user's missions active at `at` by one step for every event
+    /// that matches the mission's type and conditions. Progress goes to the
+    /// period instance running at `at`.
+    /// Returns how many missions received progress.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        at: NaiveDateTime,
+        events: &[GamificationEvent],
+    ) -> Result<i32, GamificationError> {
+        let missions = self.repository.get_active_missions_at(at).await?;
+        let mut missions_updated = 0;
+        let mut completed = Vec::new();
+
+        for mission in missions {
+            let steps = events.iter().filter(|e| mission.is_advanced_by(e)).count() as i32;
+            let Some(instance) = mission.instance_at(at) else {
+                continue;
+            };
+            if steps == 0 {
+                continue;
+            }
+
+            let mut user_mission = self
+                .repository
+                .get_user_mission(user_id, mission.id(), instance.starts_at)
+                .await?
+                .unwrap_or_else(|| UserMission::new(user_id, mission.id(), instance.starts_at));
+
+            let was_completed = user_mission.current_progress() >= mission.target_count();
+            user_mission.add_progress(steps, mission.target_count());
+            if !was_completed && user_mission.current_progress() >= mission.target_count() {
+                completed.push(EngineEvent::MissionCompleted {
+                    user_id,
+                    mission_id: mission.id(),
+                    period_start: instance.starts_at,
+                });
+            }
+
+            self.repository.save_user_mission(&user_mission).await?;
+            missions_updated += 1;
+        }
+
+        if let Some(outbox) = &self.outbox {
+            outbox.enqueue(&completed, Utc::now()).await?;
+        }
+
+        Ok(missions_updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::daily_mission::{
+        DailyMission, MissionType, QuizConditions,
+    };
+    use crate::modules::gamification::domain::entities::mission_period::{
+        MissionPeriod, MissionWindow,
+    };
+    use crate::modules::gamification::domain::repositories::mission_repository::MockMissionRepository;
+    use crate::shared::domain::outbox_repository::MockOutboxRepository;
+    use chrono::NaiveDate;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 8, 1).unwrap()
+    }
+
+    fn at() -> NaiveDateTime {
+        date().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn mission(mission_type: MissionType) -> DailyMission {
+        DailyMission::new(
+            Uuid::new_v4(),
+            format!("Misi {:?}", mission_type),
+            3,
+            date(),
+            50,
+            mission_type,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_events_only_advance_missions_of_their_type() {
+        let read = mission(MissionType::ReadArticle);
+        let quiz = mission(MissionType::Quiz);
+        let login = mission(MissionType::DailyLogin);
+        let login_id = login.id();
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_active_missions_at()
+            .return_once(move |_| Ok(vec![read, quiz, login]));
+        mock_repo
+            .expect_get_user_mission()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        mock_repo
+            .expect_save_user_mission()
+            .withf(move |um| um.mission_id() == login_id && um.current_progress() == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackMissionProgressUseCase::new(Arc::new(mock_repo));
+        let updated = use_case
+            .execute(Uuid::new_v4(), at(), &[GamificationEvent::DailyLogin])
+            .await
+            .unwrap();
+
+        assert_eq!(updated, 1);
+    }
+
+    #[tokio::test]
+    async fn test_completing_a_mission_enqueues_one_event() {
+        let login = mission(MissionType::DailyLogin);
+        let login_id = login.id();
+        let period_start = date().and_hms_opt(0, 0, 0).unwrap();
+        let user_id = Uuid::new_v4();
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_active_missions_at()
+            .return_once(move |_| Ok(vec![login]));
+        mock_repo
+            .expect_get_user_mission()
+            .returning(move |user_id, mission_id, period_start| {
+                let mut progress = UserMission::new(user_id, mission_id, period_start);
+                progress.add_progress(2, 3);
+                Ok(Some(progress))
+            });
+        mock_repo.expect_save_user_mission().returning(|_| Ok(()));
+
+        let mut mock_outbox = MockOutboxRepository::new();
+        mock_outbox
+            .expect_enqueue()
+            .withf(move |events, _| {
+                events
+                    == [EngineEvent::MissionCompleted {
+                        user_id,
+                        mission_id: login_id,
+                        period_start,
+                    }]
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = TrackMissionProgressUseCase::new(Arc::new(mock_repo))
+            .with_outbox(Arc::new(mock_outbox));
+        use_case
+            .execute(user_id, at(), &[GamificationEvent::DailyLogin])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_quiz_event_below_threshold_is_ignored() {
+        let strict = mission(MissionType::Quiz)
+            .with_quiz_conditions(QuizConditions::new(None, Some(90.0)).unwrap());
+        let open = mission(MissionType::Quiz);
+        let open_id = open.id();
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_active_missions_at()
+            .return_once(move |_| Ok(vec![strict, open]));
+        mock_repo
+            .expect_get_user_mission()
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        mock_repo
+            .expect_save_user_mission()
+            .withf(move |um| um.mission_id() == open_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackMissionProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::QuizCompleted {
+            score: 80,
+            accuracy: 75.0,
+        };
+        let updated = use_case
+            .execute(Uuid::new_v4(), at(), &[event])
+            .await
+            .unwrap();
+
+        assert_eq!(updated, 1);
+    }
+
+    #[tokio::test]
+    async fn test_weekly_mission_progress_goes_to_the_running_week() {
+        // Saturday 1 August 2026 falls in the week starting Monday 27 July.
+        let week_start = NaiveDate::from_ymd_opt(2026, 7, 27)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let season = MissionWindow::new(
+            week_start - chrono::TimeDelta::weeks(2),
+            week_start + chrono::TimeDelta::weeks(4),
+        )
+        .unwrap();
+        let weekly = mission(MissionType::ReadArticle).with_period(MissionPeriod::Weekly, season);
+
+        let mut mock_repo = MockMissionRepository::new();
+        mock_repo
+            .expect_get_active_missions_at()
+            .return_once(move |_| Ok(vec![weekly]));
+        mock_repo
+            .expect_get_user_mission()
+            .withf(move |_, _, period_start| *period_start == week_start)
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        mock_repo
+            .expect_save_user_mission()
+            .withf(move |um| um.period_start() == week_start && um.current_progress() == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = TrackMissionProgressUseCase::new(Arc::new(mock_repo));
+        let event = GamificationEvent::ArticleRead {
+            article_id: Uuid::new_v4(),
+            first_read: true,
+        };
+        let updated = use_case
+            .execute(Uuid::new_v4(), at(), &[event])
+            .await
+            .unwrap();
+
+        assert_eq!(updated, 1);
+    }
+}
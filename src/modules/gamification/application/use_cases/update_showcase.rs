@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_showcase::GetShowcaseUseCase;
+use crate::modules::gamification::application::dto::ShowcaseBadgeDto;
+use crate::modules::gamification::domain::entities::profile_showcase::ProfileShowcase;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::achievement_repository::AchievementRepository;
+
+pub struct UpdateShowcaseUseCase {
+    pub repository: Arc<dyn AchievementRepository>,
+    pub max_badges: usize,
+}
+
+impl UpdateShowcaseUseCase {
+    pub fn new(repository: Arc<dyn AchievementRepository>, max_badges: usize) -> Self {
+        Self {
+            repository,
+            max_badges,
+        }
+    }
+
+    /// Replaces the user's showcase with `achievement_ids`, in that order,
+    /// and returns the resulting badges.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        achievement_ids: &[Uuid],
+    ) -> Result<Vec<ShowcaseBadgeDto>, GamificationError> {
+        let user_achievements = self.repository.get_user_achievements(user_id).await?;
+        let changed = ProfileShowcase::arrange(user_achievements, achievement_ids, self.max_badges)
+            .map_err(|e| GamificationError::InvalidData(e.to_string()))?;
+
+        if !changed.is_empty() {
+            self.repository.save_showcase(&changed).await?;
+        }
+
+        GetShowcaseUseCase::new(self.repository.clone())
+            .execute(user_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+    use crate::modules::gamification::domain::repositories::achievement_repository::MockAchievementRepository;
+
+    #[tokio::test]
+    async fn test_unfinished_achievement_cannot_be_pinned() {
+        let user_id = Uuid::new_v4();
+        let unfinished = UserAchievement::new(user_id, Uuid::new_v4());
+        let id = unfinished.achievement_id();
+
+        let mut mock_repo = MockAchievementRepository::new();
+        mock_repo
+            .expect_get_user_achievements()
+            .return_once(move |_| Ok(vec![unfinished]));
+        mock_repo.expect_save_showcase().never();
+
+        let use_case = UpdateShowcaseUseCase::new(Arc::new(mock_repo), 3);
+        let result = use_case.execute(user_id, &[id]).await;
+
+        assert!(matches!(result, Err(GamificationError::InvalidData(_))));
+    }
+}
@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::gamification_event::GamificationEvent;
+use crate::modules::gamification::domain::entities::user_streak::{
+    StreakChange, StreakMilestone, StreakPolicy, UserStreak,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::streak_repository::StreakRepository;
+use crate::shared::domain::user_timezone::UserTimezone;
+
+/// Result of counting one activity towards a user's streak.
+#[derive(Debug, Clone)]
+pub struct StreakUpdate {
+    pub streak: UserStreak,
+    pub change: StreakChange,
+    /// Milestones this activity crossed, for the caller's milestone hooks.
+    pub milestones: Vec<StreakMilestone>,
+}
+
+impl StreakUpdate {
+    pub fn reward_points(&self) -> i32 {
+        self.milestones.iter().map(|m| m.reward_points).sum()
+    }
+
+    /// The event achievements see, or `None` when the streak did not move.
+    pub fn event(&self) -> Option<GamificationEvent> {
+        (self.change != StreakChange::Unchanged).then_some(GamificationEvent::StreakUpdated {
+            current_streak: self.streak.current_streak,
+        })
+    }
+}
+
+pub struct UpdateStreakUseCase {
+    pub repository: Arc<dyn StreakRepository>,
+    pub policy: StreakPolicy,
+}
+
+impl UpdateStreakUseCase {
+    pub fn new(repository: Arc<dyn StreakRepository>, policy: StreakPolicy) -> Self {
+        Self { repository, policy }
+    }
+
+    /// Counts activity at `at` on the user's local day in `timezone`.
+    ///
+    /// Missed days are covered by freezes automatically; each milestone
+    /// crossed earns a freeze, up to the policy's maximum.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        at: DateTime<Utc>,
+        timezone: UserTimezone,
+    ) -> Result<StreakUpdate, GamificationError> {
+        let mut streak = self
+            .repository
+            .get_streak(user_id)
+            .await?
+            .unwrap_or_else(|| UserStreak::new(user_id, self.policy.initial_freezes));
+
+        let previous = streak.current_streak;
+        let change = streak.record_activity(timezone.local_date(at));
+        let milestones = match change {
+            StreakChange::Unchanged => Vec::new(),
+            StreakChange::Started | StreakChange::Reset => {
+                self.policy.milestones_reached(0, streak.current_streak)
+            }
+            StreakChange::Extended | StreakChange::Frozen { .. } => self
+                .policy
+                .milestones_reached(previous, streak.current_streak),
+        };
+        streak.add_freezes(milestones.len() as i32, self.policy.max_freezes);
+
+        if change != StreakChange::Unchanged {
+            self.repository.save_streak(&streak).await?;
+        }
+
+        Ok(StreakUpdate {
+            streak,
+            change,
+            milestones,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::repositories::streak_repository::MockStreakRepository;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn policy() -> StreakPolicy {
+        StreakPolicy::new(
+            1,
+            2,
+            vec![StreakMilestone {
+                days: 3,
+                reward_points: 30,
+            }],
+        )
+    }
+
+    fn streak_on(user_id: Uuid, last: NaiveDate, current: i32, freezes: i32) -> UserStreak {
+        UserStreak {
+            current_streak: current,
+            longest_streak: current,
+            last_active_date: Some(last),
+            freezes_available: freezes,
+            ..UserStreak::new(user_id, 0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_activity_starts_streak_with_initial_freezes() {
+        let mut mock_repo = MockStreakRepository::new();
+        mock_repo.expect_get_streak().returning(|_| Ok(None));
+        mock_repo
+            .expect_save_streak()
+            .withf(|s| s.current_streak == 1 && s.freezes_available == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateStreakUseCase::new(Arc::new(mock_repo), policy());
+        let update = use_case
+            .execute(Uuid::new_v4(), Utc::now(), UserTimezone::UTC)
+            .await
+            .unwrap();
+
+        assert_eq!(update.change, StreakChange::Started);
+        assert!(update.milestones.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reaching_milestone_rewards_points_and_a_freeze() {
+        let user_id = Uuid::new_v4();
+        let existing = streak_on(user_id, NaiveDate::from_ymd_opt(2026, 5, 2).unwrap(), 2, 0);
+
+        let mut mock_repo = MockStreakRepository::new();
+        mock_repo
+            .expect_get_streak()
+            .return_once(move |_| Ok(Some(existing)));
+        mock_repo
+            .expect_save_streak()
+            .withf(|s| s.current_streak == 3 && s.freezes_available == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateStreakUseCase::new(Arc::new(mock_repo), policy());
+        let at = Utc.with_ymd_and_hms(2026, 5, 3, 12, 0, 0).unwrap();
+        let update = use_case
+            .execute(user_id, at, UserTimezone::UTC)
+            .await
+            .unwrap();
+
+        assert_eq!(update.reward_points(), 30);
+        assert!(matches!(
+            update.event(),
+            Some(GamificationEvent::StreakUpdated { current_streak: 3 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_day_is_resolved_in_the_users_timezone() {
+        let user_id = Uuid::new_v4();
+        let existing = streak_on(user_id, NaiveDate::from_ymd_opt(2026, 5, 2).unwrap(), 1, 0);
+
+        let mut mock_repo = MockStreakRepository::new();
+        mock_repo
+            .expect_get_streak()
+            .return_once(move |_| Ok(Some(existing)));
+        mock_repo
+            .expect_save_streak()
+            .withf(|s| s.current_streak == 2)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = UpdateStreakUseCase::new(Arc::new(mock_repo), policy());
+        // 18:00 UTC on May 2 is already May 3 in UTC+7.
+        let at = Utc.with_ymd_and_hms(2026, 5, 2, 18, 0, 0).unwrap();
+        let wib = UserTimezone::from_offset_minutes(420).unwrap();
+        let update = use_case.execute(user_id, at, wib).await.unwrap();
+
+        assert_eq!(update.change, StreakChange::Extended);
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::modules::gamification::application::dto::PointsBalanceReportDto;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::domain::repositories::points_ledger_repository::PointsLedgerRepository;
+
+pub struct VerifyPointsBalancesUseCase {
+    pub repository: Arc<dyn PointsLedgerRepository>,
+}
+
+impl VerifyPointsBalancesUseCase {
+    pub fn new(repository: Arc<dyn PointsLedgerRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Lists users whose total score no longer matches their ledger, without
+    /// changing anything.
+    pub async fn execute(&self) -> Result<PointsBalanceReportDto, GamificationError> {
+        let mismatches = self.repository.find_balance_mismatches().await?;
+        Ok(PointsBalanceReportDto::new(&mismatches, false))
+    }
+}
@@ -1,6 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use uuid::Uuid;
 
+use super::achievement_chain::ChainLink;
+use super::achievement_criteria::AchievementCriteria;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum AchievementType {
     #[default]
@@ -21,6 +26,20 @@ impl std::fmt::Display for AchievementType {
     }
 }
 
+impl FromStr for AchievementType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "common" => Ok(AchievementType::Common),
+            "rare" => Ok(AchievementType::Rare),
+            "epic" => Ok(AchievementType::Epic),
+            "legendary" => Ok(AchievementType::Legendary),
+            _ => Err("Tipe achievement harus Common, Rare, Epic, atau Legendary."),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Achievement {
     pub id: Uuid,
@@ -28,6 +47,11 @@ pub struct Achievement {
     pub milestone_target: i32,
     pub achievement_type: AchievementType,
     pub reward_points: i32,
+    pub is_retired: bool,
+    pub retired_at: Option<DateTime<Utc>>,
+    pub criteria: AchievementCriteria,
+    /// Set for tiers of a chain such as "Reader I/II/III".
+    pub chain: Option<ChainLink>,
 }
 
 impl Achievement {
@@ -44,6 +68,10 @@ impl Achievement {
             milestone_target: 1,
             achievement_type,
             reward_points: 0,
+            is_retired: false,
+            retired_at: None,
+            criteria: AchievementCriteria::default(),
+            chain: None,
         };
 
         achievement.update_details(name, target, reward)?;
@@ -73,6 +101,44 @@ impl Achievement {
         Ok(())
     }
 
+    pub fn with_criteria(mut self, criteria: AchievementCriteria) -> Result<Self, &'static str> {
+        self.set_criteria(criteria)?;
+        Ok(self)
+    }
+
+    pub fn set_criteria(&mut self, criteria: AchievementCriteria) -> Result<(), &'static str> {
+        criteria.validate()?;
+        self.criteria = criteria;
+        Ok(())
+    }
+
+    pub fn with_chain(mut self, chain: Option<ChainLink>) -> Result<Self, &'static str> {
+        self.set_chain(chain)?;
+        Ok(self)
+    }
+
+    pub fn set_chain(&mut self, chain: Option<ChainLink>) -> Result<(), &'static str> {
+        if let Some(link) = &chain {
+            ChainLink::new(link.chain.clone(), link.tier)?;
+        }
+        self.chain = chain;
+        Ok(())
+    }
+
+    pub fn set_achievement_type(&mut self, achievement_type: AchievementType) {
+        self.achievement_type = achievement_type;
+    }
+
+    /// Retires the achievement: it stays in the catalog for users who earned it
+    /// but no longer accrues progress. Retiring twice keeps the original timestamp.
+    pub fn retire(&mut self, now: DateTime<Utc>) {
+        if self.is_retired {
+            return;
+        }
+        self.is_retired = true;
+        self.retired_at = Some(now);
+    }
+
     pub fn id(&self) -> Uuid {
         self.id
     }
@@ -88,6 +154,18 @@ impl Achievement {
     pub fn reward_points(&self) -> i32 {
         self.reward_points
     }
+    pub fn is_retired(&self) -> bool {
+        self.is_retired
+    }
+    pub fn retired_at(&self) -> Option<DateTime<Utc>> {
+        self.retired_at
+    }
+    pub fn criteria(&self) -> &AchievementCriteria {
+        &self.criteria
+    }
+    pub fn chain(&self) -> Option<&ChainLink> {
+        self.chain.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -126,4 +204,34 @@ mod tests {
             "Target milestone harus lebih dari 0."
         );
     }
+
+    #[test]
+    fn test_achievement_retire_keeps_first_timestamp() {
+        let mut ach = Achievement::new(
+            Uuid::new_v4(),
+            "Valid".to_string(),
+            1,
+            AchievementType::Common,
+            0,
+        )
+        .unwrap();
+        assert!(!ach.is_retired());
+
+        let first = Utc::now();
+        ach.retire(first);
+        ach.retire(first + chrono::Duration::days(1));
+
+        assert!(ach.is_retired());
+        assert_eq!(ach.retired_at(), Some(first));
+    }
+
+    #[test]
+    fn test_achievement_type_from_str_is_case_insensitive() {
+        assert_eq!(
+            "legendary".parse::<AchievementType>(),
+            Ok(AchievementType::Legendary)
+        );
+        assert_eq!("Rare".parse::<AchievementType>(), Ok(AchievementType::Rare));
+        assert!("Mythic".parse::<AchievementType>().is_err());
+    }
 }
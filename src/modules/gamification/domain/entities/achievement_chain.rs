@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use super::achievement::Achievement;
+
+/// Where an achievement sits in a tiered chain such as "Reader I/II/III".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainLink {
+    pub chain: String,
+    /// 1-based; higher tiers have higher milestone targets.
+    pub tier: i32,
+}
+
+impl ChainLink {
+    pub fn new(chain: String, tier: i32) -> Result<Self, &'static str> {
+        if chain.trim().is_empty() {
+            return Err("Nama rantai achievement tidak boleh kosong.");
+        }
+        if tier <= 0 {
+            return Err("Tier rantai achievement harus lebih dari 0.");
+        }
+        Ok(Self { chain, tier })
+    }
+}
+
+/// The tiers of one achievement chain, lowest first. A tier only accrues progress
+/// once every active tier below it is completed, and its progress starts from where
+/// the previous tier ended.
+#[derive(Debug, Clone)]
+pub struct AchievementChain {
+    name: String,
+    tiers: Vec<Achievement>,
+}
+
+impl AchievementChain {
+    /// Groups the chained achievements of `catalog`, sorted by chain name.
+    pub fn from_catalog(catalog: &[Achievement]) -> Vec<AchievementChain> {
+        let mut chains: BTreeMap<&str, Vec<Achievement>> = BTreeMap::new();
+        for achievement in catalog {
+            if let Some(link) = achievement.chain() {
+                chains
+                    .entry(link.chain.as_str())
+                    .or_default()
+                    .push(achievement.clone());
+            }
+        }
+
+        chains
+            .into_iter()
+            .map(|(name, mut tiers)| {
+                tiers.sort_by_key(|ach| ach.chain().map_or(0, |link| link.tier));
+                AchievementChain {
+                    name: name.to_string(),
+                    tiers,
+                }
+            })
+            .collect()
+    }
+
+    /// Index of the tier the user is working on: the lowest tier that is neither
+    /// completed nor retired. `None` once nothing is left to earn.
+    pub fn next_tier_index(&self, is_completed: impl Fn(Uuid) -> bool) -> Option<usize> {
+        self.tiers
+            .iter()
+            .position(|ach| !ach.is_retired() && !is_completed(ach.id()))
+    }
+
+    /// Checks that `achievement` fits the chain it names among the rest of `catalog`:
+    /// its tier is free, it counts the same thing as the other tiers, and targets
+    /// rise with the tier.
+    pub fn check_tier(
+        catalog: &[Achievement],
+        achievement: &Achievement,
+    ) -> Result<(), &'static str> {
+        let Some(link) = achievement.chain() else {
+            return Ok(());
+        };
+
+        let siblings = catalog.iter().filter(|other| {
+            other.id() != achievement.id() && other.chain().is_some_and(|l| l.chain == link.chain)
+        });
+        for other in siblings {
+            let other_tier = other.chain().map_or(0, |l| l.tier);
+            if other_tier == link.tier {
+                return Err("Tier ini sudah dipakai achievement lain di rantai yang sama.");
+            }
+            if other.criteria() != achievement.criteria() {
+                return Err("Semua tier dalam satu rantai harus memakai kriteria yang sama.");
+            }
+            let rising = if other_tier < link.tier {
+                other.milestone_target() < achievement.milestone_target()
+            } else {
+                other.milestone_target() > achievement.milestone_target()
+            };
+            if !rising {
+                return Err("Target milestone harus naik di setiap tier rantai.");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn tiers(&self) -> &[Achievement] {
+        &self.tiers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::AchievementType;
+    use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+
+    fn tier(chain: &str, tier: i32, target: i32) -> Achievement {
+        Achievement::new(
+            Uuid::new_v4(),
+            format!("{} {}", chain, tier),
+            target,
+            AchievementType::Common,
+            10,
+        )
+        .unwrap()
+        .with_chain(Some(ChainLink::new(chain.to_string(), tier).unwrap()))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_chain_link_validates_name_and_tier() {
+        assert!(ChainLink::new(" ".to_string(), 1).is_err());
+        assert!(ChainLink::new("Reader".to_string(), 0).is_err());
+        assert!(ChainLink::new("Reader".to_string(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_from_catalog_groups_tiers_in_order() {
+        let standalone = Achievement::new(
+            Uuid::new_v4(),
+            "Sendiri".to_string(),
+            1,
+            AchievementType::Rare,
+            0,
+        )
+        .unwrap();
+        let catalog = vec![
+            tier("Reader", 3, 50),
+            standalone,
+            tier("Reader", 1, 10),
+            tier("Perfect", 1, 1),
+            tier("Reader", 2, 25),
+        ];
+
+        let chains = AchievementChain::from_catalog(&catalog);
+
+        assert_eq!(chains.len(), 2);
+        assert_eq!(chains[0].name(), "Perfect");
+        assert_eq!(chains[1].name(), "Reader");
+        let targets: Vec<_> = chains[1]
+            .tiers()
+            .iter()
+            .map(|ach| ach.milestone_target())
+            .collect();
+        assert_eq!(targets, vec![10, 25, 50]);
+    }
+
+    #[test]
+    fn test_next_tier_skips_completed_and_retired_tiers() {
+        let mut catalog = vec![
+            tier("Reader", 1, 10),
+            tier("Reader", 2, 25),
+            tier("Reader", 3, 50),
+        ];
+        catalog[1].retire(chrono::Utc::now());
+        let first = catalog[0].id();
+        let chain = AchievementChain::from_catalog(&catalog).remove(0);
+
+        assert_eq!(chain.next_tier_index(|_| false), Some(0));
+        assert_eq!(chain.next_tier_index(|id| id == first), Some(2));
+        assert_eq!(chain.next_tier_index(|_| true), None);
+    }
+
+    #[test]
+    fn test_check_tier_rejects_duplicates_mixed_criteria_and_falling_targets() {
+        let catalog = vec![tier("Reader", 1, 10), tier("Reader", 3, 50)];
+
+        assert!(AchievementChain::check_tier(&catalog, &tier("Reader", 2, 25)).is_ok());
+        assert!(AchievementChain::check_tier(&catalog, &tier("Reader", 1, 5)).is_err());
+        assert!(AchievementChain::check_tier(&catalog, &tier("Reader", 2, 60)).is_err());
+        assert!(AchievementChain::check_tier(&catalog, &tier("Reader", 2, 10)).is_err());
+        assert!(AchievementChain::check_tier(&catalog, &tier("Other", 1, 1)).is_ok());
+
+        let scored = tier("Reader", 2, 25)
+            .with_criteria(AchievementCriteria::CumulativeScore)
+            .unwrap();
+        assert!(AchievementChain::check_tier(&catalog, &scored).is_err());
+
+        // an achievement may keep its own tier when it is updated
+        assert!(AchievementChain::check_tier(&catalog, &catalog[0]).is_ok());
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What an achievement counts towards its `milestone_target`.
+///
+/// Stored as tagged JSON in `achievements.criteria`, e.g.
+/// `{"type": "quizzes_completed", "min_accuracy": 80.0}`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AchievementCriteria {
+    /// One step per completed quiz, optionally only those at or above `min_accuracy` (0-100).
+    QuizzesCompleted {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_accuracy: Option<f64>,
+    },
+    /// One step per quiz finished with 100% accuracy.
+    PerfectQuizzes,
+    /// The quiz score is added to the progress.
+    CumulativeScore,
+    /// One step per article the user reads for the first time.
+    DistinctArticlesRead,
+    /// Progress is the longest reading streak reached, in days.
+    StreakLength,
+    /// One step per daily mission reward claimed.
+    MissionsClaimed,
+}
+
+impl Default for AchievementCriteria {
+    fn default() -> Self {
+        AchievementCriteria::QuizzesCompleted { min_accuracy: None }
+    }
+}
+
+impl AchievementCriteria {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            AchievementCriteria::QuizzesCompleted {
+                min_accuracy: Some(accuracy),
+            } if !(0.0..=100.0).contains(accuracy) => {
+                Err("Akurasi minimum kriteria harus antara 0 dan 100.")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_criteria_round_trips_as_tagged_json() {
+        let criteria = AchievementCriteria::QuizzesCompleted {
+            min_accuracy: Some(90.0),
+        };
+        let json = serde_json::to_string(&criteria).unwrap();
+        assert_eq!(json, r#"{"type":"quizzes_completed","min_accuracy":90.0}"#);
+
+        let parsed: AchievementCriteria =
+            serde_json::from_str(r#"{"type": "distinct_articles_read"}"#).unwrap();
+        assert_eq!(parsed, AchievementCriteria::DistinctArticlesRead);
+    }
+
+    #[test]
+    fn test_default_matches_legacy_rows() {
+        let legacy: AchievementCriteria =
+            serde_json::from_str(r#"{"type": "quizzes_completed"}"#).unwrap();
+        assert_eq!(legacy, AchievementCriteria::default());
+    }
+
+    #[test]
+    fn test_validate_rejects_accuracy_out_of_range() {
+        let invalid = AchievementCriteria::QuizzesCompleted {
+            min_accuracy: Some(120.0),
+        };
+        assert!(invalid.validate().is_err());
+        assert!(AchievementCriteria::CumulativeScore.validate().is_ok());
+    }
+}
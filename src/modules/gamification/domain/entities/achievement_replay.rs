@@ -0,0 +1,202 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use super::achievement::Achievement;
+use super::achievement_chain::AchievementChain;
+use super::achievement_criteria::AchievementCriteria;
+use super::criteria_evaluator::CriteriaEvaluator;
+use super::gamification_event::GamificationEvent;
+use super::user_achievement::UserAchievement;
+
+/// A recorded quiz, as far as achievements are concerned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PastQuiz {
+    pub article_id: Uuid,
+    pub score: i32,
+    pub accuracy: f64,
+    pub completed_at: DateTime<Utc>,
+}
+
+/// Rebuilds one user's achievement progress from their quiz history, as if the
+/// achievement had existed when each quiz was synced.
+pub struct AchievementReplay {
+    user_id: Uuid,
+    /// The events each quiz sync produced, oldest quiz first.
+    steps: Vec<(DateTime<Utc>, [GamificationEvent; 2])>,
+}
+
+impl AchievementReplay {
+    pub fn new(user_id: Uuid, mut quizzes: Vec<PastQuiz>) -> Self {
+        quizzes.sort_by_key(|quiz| quiz.completed_at);
+
+        let mut read = HashSet::new();
+        let steps = quizzes
+            .into_iter()
+            .map(|quiz| {
+                let first_read = read.insert(quiz.article_id);
+                (
+                    quiz.completed_at,
+                    [
+                        GamificationEvent::ArticleRead {
+                            article_id: quiz.article_id,
+                            first_read,
+                        },
+                        GamificationEvent::QuizCompleted {
+                            score: quiz.score,
+                            accuracy: quiz.accuracy,
+                        },
+                    ],
+                )
+            })
+            .collect();
+
+        Self { user_id, steps }
+    }
+
+    /// Whether quiz history holds everything `criteria` counts. Streaks and
+    /// mission claims are not recorded there.
+    pub fn supports(criteria: &AchievementCriteria) -> bool {
+        matches!(
+            criteria,
+            AchievementCriteria::QuizzesCompleted { .. }
+                | AchievementCriteria::PerfectQuizzes
+                | AchievementCriteria::CumulativeScore
+                | AchievementCriteria::DistinctArticlesRead
+        )
+    }
+
+    /// Progress on `achievement` from every quiz, completed at the quiz that
+    /// reached its target.
+    pub fn replay(&self, achievement: &Achievement) -> UserAchievement {
+        let mut progress = UserAchievement::new(self.user_id, achievement.id());
+        for (completed_at, events) in &self.steps {
+            if progress.is_completed() {
+                break;
+            }
+            let delta = CriteriaEvaluator::total_delta(
+                achievement.criteria(),
+                events,
+                progress.current_progress(),
+            );
+            progress.add_progress(delta, achievement.milestone_target(), *completed_at);
+        }
+        progress
+    }
+
+    /// Progress on the active tiers of `chain`, up to the first one left
+    /// unfinished. Progress carries over between tiers, so each tier counts
+    /// every quiz from the start.
+    pub fn replay_chain(&self, chain: &AchievementChain) -> Vec<UserAchievement> {
+        let mut tiers = Vec::new();
+        for tier in chain.tiers().iter().filter(|ach| !ach.is_retired()) {
+            let progress = self.replay(tier);
+            let completed = progress.is_completed();
+            tiers.push(progress);
+            if !completed {
+                break;
+            }
+        }
+        tiers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::achievement::AchievementType;
+    use crate::modules::gamification::domain::entities::achievement_chain::ChainLink;
+    use chrono::TimeDelta;
+
+    fn achievement(criteria: AchievementCriteria, target: i32) -> Achievement {
+        Achievement::new(
+            Uuid::new_v4(),
+            format!("{:?}", criteria),
+            target,
+            AchievementType::Common,
+            10,
+        )
+        .unwrap()
+        .with_criteria(criteria)
+        .unwrap()
+    }
+
+    fn quiz(article_id: Uuid, score: i32, accuracy: f64, days_ago: i64) -> PastQuiz {
+        PastQuiz {
+            article_id,
+            score,
+            accuracy,
+            completed_at: Utc::now() - TimeDelta::days(days_ago),
+        }
+    }
+
+    #[test]
+    fn test_completes_at_the_quiz_that_reached_the_target() {
+        let article = Uuid::new_v4();
+        let quizzes = vec![
+            quiz(article, 60, 100.0, 1),
+            quiz(article, 50, 80.0, 10),
+            quiz(article, 40, 100.0, 5),
+        ];
+        let reached_at = quizzes[2].completed_at;
+        let replay = AchievementReplay::new(Uuid::new_v4(), quizzes);
+
+        let score = replay.replay(&achievement(AchievementCriteria::CumulativeScore, 90));
+        assert!(score.is_completed());
+        assert_eq!(score.current_progress(), 90);
+        assert_eq!(score.completed_at(), Some(reached_at));
+
+        let perfect = replay.replay(&achievement(AchievementCriteria::PerfectQuizzes, 3));
+        assert_eq!(perfect.current_progress(), 2);
+        assert!(!perfect.is_completed());
+    }
+
+    #[test]
+    fn test_repeated_articles_count_once_as_read() {
+        let article = Uuid::new_v4();
+        let replay = AchievementReplay::new(
+            Uuid::new_v4(),
+            vec![
+                quiz(article, 10, 50.0, 3),
+                quiz(article, 10, 50.0, 2),
+                quiz(Uuid::new_v4(), 10, 50.0, 1),
+            ],
+        );
+
+        let articles = replay.replay(&achievement(AchievementCriteria::DistinctArticlesRead, 5));
+        assert_eq!(articles.current_progress(), 2);
+        assert!(AchievementReplay::supports(
+            &AchievementCriteria::DistinctArticlesRead
+        ));
+        assert!(!AchievementReplay::supports(
+            &AchievementCriteria::StreakLength
+        ));
+    }
+
+    #[test]
+    fn test_chain_stops_at_the_first_unfinished_tier() {
+        let tiers: Vec<_> = [(1, 50), (2, 100), (3, 200)]
+            .into_iter()
+            .map(|(tier, target)| {
+                achievement(AchievementCriteria::CumulativeScore, target)
+                    .with_chain(Some(ChainLink::new("Skor".to_string(), tier).unwrap()))
+                    .unwrap()
+            })
+            .collect();
+        let chain = AchievementChain::from_catalog(&tiers).remove(0);
+        let replay = AchievementReplay::new(
+            Uuid::new_v4(),
+            vec![
+                quiz(Uuid::new_v4(), 70, 90.0, 2),
+                quiz(Uuid::new_v4(), 50, 90.0, 1),
+            ],
+        );
+
+        let progress = replay.replay_chain(&chain);
+        assert_eq!(progress.len(), 3);
+        assert!(progress[0].is_completed() && progress[1].is_completed());
+        assert_eq!(progress[2].achievement_id(), tiers[2].id());
+        assert_eq!(progress[2].current_progress(), 120);
+        assert!(!progress[2].is_completed());
+    }
+}
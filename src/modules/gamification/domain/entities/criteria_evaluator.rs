@@ -0,0 +1,196 @@
+use super::achievement_criteria::AchievementCriteria;
+use super::gamification_event::GamificationEvent;
+
+/// Accuracy a quiz needs to count as perfect.
+const PERFECT_ACCURACY: f64 = 100.0;
+
+/// Turns incoming events into achievement progress.
+pub struct CriteriaEvaluator;
+
+impl CriteriaEvaluator {
+    /// Progress `event` adds to an achievement with `criteria`, given the
+    /// user's `current_progress` on it. Zero when the event is irrelevant.
+    pub fn progress_delta(
+        criteria: &AchievementCriteria,
+        event: &GamificationEvent,
+        current_progress: i32,
+    ) -> i32 {
+        match (criteria, event) {
+            (
+                AchievementCriteria::QuizzesCompleted { min_accuracy },
+                GamificationEvent::QuizCompleted { accuracy, .. },
+            ) => i32::from(min_accuracy.is_none_or(|min| *accuracy >= min)),
+            (
+                AchievementCriteria::PerfectQuizzes,
+                GamificationEvent::QuizCompleted { accuracy, .. },
+            ) => i32::from(*accuracy >= PERFECT_ACCURACY),
+            (
+                AchievementCriteria::CumulativeScore,
+                GamificationEvent::QuizCompleted { score, .. },
+            ) => (*score).max(0),
+            (
+                AchievementCriteria::DistinctArticlesRead,
+                GamificationEvent::ArticleRead { first_read, .. },
+            ) => i32::from(*first_read),
+            (
+                AchievementCriteria::StreakLength,
+                GamificationEvent::StreakUpdated { current_streak },
+            ) => (current_streak - current_progress).max(0),
+            (AchievementCriteria::MissionsClaimed, GamificationEvent::MissionClaimed { .. }) => 1,
+            _ => 0,
+        }
+    }
+
+    /// Sum of `progress_delta` over several events applied in order.
+    pub fn total_delta(
+        criteria: &AchievementCriteria,
+        events: &[GamificationEvent],
+        current_progress: i32,
+    ) -> i32 {
+        events.iter().fold(0, |delta, event| {
+            delta + Self::progress_delta(criteria, event, current_progress + delta)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn quiz(score: i32, accuracy: f64) -> GamificationEvent {
+        GamificationEvent::QuizCompleted { score, accuracy }
+    }
+
+    fn read(first_read: bool) -> GamificationEvent {
+        GamificationEvent::ArticleRead {
+            article_id: Uuid::new_v4(),
+            first_read,
+        }
+    }
+
+    fn claimed() -> GamificationEvent {
+        GamificationEvent::MissionClaimed {
+            mission_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_quizzes_completed_counts_each_quiz() {
+        let criteria = AchievementCriteria::default();
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(10, 20.0), 0),
+            1
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &read(true), 0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_quizzes_completed_respects_min_accuracy() {
+        let criteria = AchievementCriteria::QuizzesCompleted {
+            min_accuracy: Some(80.0),
+        };
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(90, 80.0), 0),
+            1
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(90, 79.9), 0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_perfect_quizzes_needs_full_accuracy() {
+        let criteria = AchievementCriteria::PerfectQuizzes;
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(100, 100.0), 0),
+            1
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(100, 99.5), 0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_cumulative_score_adds_quiz_score() {
+        let criteria = AchievementCriteria::CumulativeScore;
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(85, 70.0), 40),
+            85
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &claimed(), 40),
+            0
+        );
+    }
+
+    #[test]
+    fn test_distinct_articles_ignores_rereads() {
+        let criteria = AchievementCriteria::DistinctArticlesRead;
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &read(true), 0),
+            1
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &read(false), 0),
+            0
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &quiz(90, 90.0), 0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_streak_length_tracks_best_streak() {
+        let criteria = AchievementCriteria::StreakLength;
+        let streak = |current_streak| GamificationEvent::StreakUpdated { current_streak };
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &streak(5), 3),
+            2
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &streak(1), 3),
+            0
+        );
+    }
+
+    #[test]
+    fn test_missions_claimed_counts_claims() {
+        let criteria = AchievementCriteria::MissionsClaimed;
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &claimed(), 7),
+            1
+        );
+        assert_eq!(
+            CriteriaEvaluator::progress_delta(&criteria, &GamificationEvent::DailyLogin, 7),
+            0
+        );
+    }
+
+    #[test]
+    fn test_total_delta_applies_events_in_order() {
+        let events = [read(true), quiz(60, 100.0), quiz(40, 50.0)];
+        assert_eq!(
+            CriteriaEvaluator::total_delta(&AchievementCriteria::CumulativeScore, &events, 0),
+            100
+        );
+        assert_eq!(
+            CriteriaEvaluator::total_delta(&AchievementCriteria::PerfectQuizzes, &events, 0),
+            1
+        );
+        let streaks = [
+            GamificationEvent::StreakUpdated { current_streak: 4 },
+            GamificationEvent::StreakUpdated { current_streak: 6 },
+        ];
+        assert_eq!(
+            CriteriaEvaluator::total_delta(&AchievementCriteria::StreakLength, &streaks, 2),
+            4
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use uuid::Uuid;
 
+use super::gamification_event::GamificationEvent;
+use super::mission_period::{MissionPeriod, MissionWindow};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum MissionType {
     #[default]
@@ -10,14 +14,69 @@ pub enum MissionType {
     DailyLogin,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl FromStr for MissionType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "readarticle" => Ok(MissionType::ReadArticle),
+            "quiz" => Ok(MissionType::Quiz),
+            "dailylogin" => Ok(MissionType::DailyLogin),
+            _ => Err("Tipe misi harus ReadArticle, Quiz, atau DailyLogin."),
+        }
+    }
+}
+
+/// Thresholds a quiz must reach to count towards a `Quiz` mission.
+/// Unset thresholds accept any quiz.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct QuizConditions {
+    pub min_score: Option<i32>,
+    /// Percentage between 0 and 100.
+    pub min_accuracy: Option<f64>,
+}
+
+impl QuizConditions {
+    pub fn new(min_score: Option<i32>, min_accuracy: Option<f64>) -> Result<Self, &'static str> {
+        if min_score.is_some_and(|score| score < 0) {
+            return Err("Skor minimum kuis tidak boleh negatif.");
+        }
+        if min_accuracy.is_some_and(|accuracy| !(0.0..=100.0).contains(&accuracy)) {
+            return Err("Akurasi minimum kuis harus antara 0 dan 100.");
+        }
+        Ok(Self {
+            min_score,
+            min_accuracy,
+        })
+    }
+
+    pub fn is_met_by(&self, score: i32, accuracy: f64) -> bool {
+        self.min_score.is_none_or(|min| score >= min)
+            && self.min_accuracy.is_none_or(|min| accuracy >= min)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_score.is_none() && self.min_accuracy.is_none()
+    }
+}
+
+/// A mission running over `window`, split into instances by `period`.
+/// Plain daily missions cover a single day; weekly and event missions span longer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DailyMission {
     pub id: Uuid,
     pub description: String,
     pub target_count: i32,
+    /// First day of the window.
     pub date: NaiveDate,
+    pub period: MissionPeriod,
+    pub window: MissionWindow,
     pub reward_points: i32,
     pub mission_type: MissionType,
+    /// Template the rotation generated this mission from; `None` for hand-made missions.
+    pub template_id: Option<Uuid>,
+    /// Only meaningful for `Quiz` missions.
+    pub quiz_conditions: QuizConditions,
 }
 
 impl DailyMission {
@@ -34,8 +93,12 @@ impl DailyMission {
             description: String::new(),
             target_count: 1,
             date,
+            period: MissionPeriod::Daily,
+            window: MissionWindow::day(date),
             reward_points: 0,
             mission_type,
+            template_id: None,
+            quiz_conditions: QuizConditions::default(),
         };
 
         mission.update_details(description, target_count, date, reward_points, mission_type)?;
@@ -69,6 +132,54 @@ impl DailyMission {
         Ok(())
     }
 
+    pub fn with_template(mut self, template_id: Uuid) -> Self {
+        self.template_id = Some(template_id);
+        self
+    }
+
+    pub fn with_quiz_conditions(mut self, conditions: QuizConditions) -> Self {
+        self.quiz_conditions = conditions;
+        self
+    }
+
+    /// Runs the mission over `window` instead of a single day.
+    pub fn with_period(mut self, period: MissionPeriod, window: MissionWindow) -> Self {
+        self.period = period;
+        self.window = window;
+        self.date = window.starts_at.date();
+        self
+    }
+
+    pub fn is_active_at(&self, at: NaiveDateTime) -> bool {
+        self.window.contains(at)
+    }
+
+    /// The period instance running at `at`, if the mission is active then.
+    pub fn instance_at(&self, at: NaiveDateTime) -> Option<MissionWindow> {
+        self.period.instance_at(&self.window, at)
+    }
+
+    /// The instance a claim made at `at` settles: the running one, or the last
+    /// one once the window has closed. `None` before the mission starts.
+    pub fn claimable_instance_at(&self, at: NaiveDateTime) -> Option<MissionWindow> {
+        let last_moment = self.window.ends_at - TimeDelta::microseconds(1);
+        self.instance_at(at.min(last_moment))
+    }
+
+    /// Whether `event` moves this mission forward: the event has to belong to
+    /// the mission's type and, for quizzes, meet the mission's conditions.
+    pub fn is_advanced_by(&self, event: &GamificationEvent) -> bool {
+        if event.mission_type() != Some(self.mission_type) {
+            return false;
+        }
+        match event {
+            GamificationEvent::QuizCompleted { score, accuracy } => {
+                self.quiz_conditions.is_met_by(*score, *accuracy)
+            }
+            _ => true,
+        }
+    }
+
     pub fn id(&self) -> Uuid {
         self.id
     }
@@ -81,12 +192,24 @@ impl DailyMission {
     pub fn date(&self) -> chrono::NaiveDate {
         self.date
     }
+    pub fn period(&self) -> MissionPeriod {
+        self.period
+    }
+    pub fn window(&self) -> MissionWindow {
+        self.window
+    }
     pub fn reward_points(&self) -> i32 {
         self.reward_points
     }
     pub fn mission_type(&self) -> MissionType {
         self.mission_type
     }
+    pub fn template_id(&self) -> Option<Uuid> {
+        self.template_id
+    }
+    pub fn quiz_conditions(&self) -> QuizConditions {
+        self.quiz_conditions
+    }
 }
 
 #[cfg(test)]
@@ -127,4 +250,112 @@ mod tests {
             "Poin reward tidak boleh bernilai negatif."
         );
     }
+
+    #[test]
+    fn test_mission_is_only_advanced_by_events_of_its_type() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 6).unwrap();
+        let login = DailyMission::new(
+            Uuid::new_v4(),
+            "Login hari ini".to_string(),
+            1,
+            date,
+            20,
+            MissionType::DailyLogin,
+        )
+        .unwrap();
+
+        assert!(login.is_advanced_by(&GamificationEvent::DailyLogin));
+        assert!(!login.is_advanced_by(&GamificationEvent::ArticleRead {
+            article_id: Uuid::new_v4(),
+            first_read: true
+        }));
+        assert!(!login.is_advanced_by(&GamificationEvent::QuizCompleted {
+            score: 100,
+            accuracy: 100.0
+        }));
+    }
+
+    #[test]
+    fn test_quiz_mission_checks_conditions() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 6).unwrap();
+        let quiz = DailyMission::new(
+            Uuid::new_v4(),
+            "Kuis akurat".to_string(),
+            2,
+            date,
+            50,
+            MissionType::Quiz,
+        )
+        .unwrap()
+        .with_quiz_conditions(QuizConditions::new(Some(70), Some(80.0)).unwrap());
+
+        let passing = GamificationEvent::QuizCompleted {
+            score: 70,
+            accuracy: 80.0,
+        };
+        let low_score = GamificationEvent::QuizCompleted {
+            score: 69,
+            accuracy: 95.0,
+        };
+        let low_accuracy = GamificationEvent::QuizCompleted {
+            score: 90,
+            accuracy: 79.5,
+        };
+
+        assert!(quiz.is_advanced_by(&passing));
+        assert!(!quiz.is_advanced_by(&low_score));
+        assert!(!quiz.is_advanced_by(&low_accuracy));
+    }
+
+    #[test]
+    fn test_weekly_mission_claims_its_last_instance_after_closing() {
+        let monday = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        let window = MissionWindow::new(
+            MissionWindow::day(monday).starts_at,
+            MissionWindow::day(monday + chrono::Days::new(14)).starts_at,
+        )
+        .unwrap();
+        let mission = DailyMission::new(
+            Uuid::new_v4(),
+            "Baca 20 artikel minggu ini".to_string(),
+            20,
+            monday,
+            300,
+            MissionType::ReadArticle,
+        )
+        .unwrap()
+        .with_period(MissionPeriod::Weekly, window);
+
+        let second_week = MissionWindow::day(monday + chrono::Days::new(7)).starts_at;
+        let after_close = window.ends_at + TimeDelta::hours(3);
+        assert!(!mission.is_active_at(after_close));
+        assert_eq!(mission.instance_at(after_close), None);
+        assert_eq!(
+            mission
+                .claimable_instance_at(after_close)
+                .map(|i| i.starts_at),
+            Some(second_week)
+        );
+        assert_eq!(
+            mission.claimable_instance_at(window.starts_at - TimeDelta::hours(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quiz_conditions_reject_out_of_range_thresholds() {
+        assert!(QuizConditions::new(Some(-1), None).is_err());
+        assert!(QuizConditions::new(None, Some(100.5)).is_err());
+        assert!(QuizConditions::new(None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_mission_type_parses_case_insensitively() {
+        assert_eq!("quiz".parse::<MissionType>(), Ok(MissionType::Quiz));
+        assert_eq!(
+            "DailyLogin".parse::<MissionType>(),
+            Ok(MissionType::DailyLogin)
+        );
+        assert!("Streak".parse::<MissionType>().is_err());
+    }
 }
@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use super::daily_mission::MissionType;
+
+/// Something a user did that can move their missions and achievements forward.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamificationEvent {
+    /// `first_read` is false when the user had already read the article before.
+    ArticleRead {
+        article_id: Uuid,
+        first_read: bool,
+    },
+    /// `accuracy` is a percentage between 0 and 100.
+    QuizCompleted {
+        score: i32,
+        accuracy: f64,
+    },
+    DailyLogin,
+    MissionClaimed {
+        mission_id: Uuid,
+    },
+    StreakUpdated {
+        current_streak: i32,
+    },
+}
+
+impl GamificationEvent {
+    /// The daily mission type this event advances, if any.
+    pub fn mission_type(&self) -> Option<MissionType> {
+        match self {
+            GamificationEvent::ArticleRead { .. } => Some(MissionType::ReadArticle),
+            GamificationEvent::QuizCompleted { .. } => Some(MissionType::Quiz),
+            GamificationEvent::DailyLogin => Some(MissionType::DailyLogin),
+            GamificationEvent::MissionClaimed { .. } | GamificationEvent::StreakUpdated { .. } => {
+                None
+            }
+        }
+    }
+}
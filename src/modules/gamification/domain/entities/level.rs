@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Level {
+    pub level: i32,
+    pub title: String,
+    /// Total score needed to reach this level.
+    pub min_score: i32,
+}
+
+/// Where a total score sits on the level curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelProgress {
+    pub level: i32,
+    pub title: String,
+    pub total_score: i32,
+    pub level_min_score: i32,
+    /// `None` at the top level.
+    pub next_level_min_score: Option<i32>,
+    /// 0-100 towards the next level; 100 at the top level.
+    pub progress_percent: f64,
+}
+
+/// Maps total score to levels. Loaded from the `levels` table, so the curve
+/// can be retuned without a deploy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelCurve {
+    levels: Vec<Level>,
+}
+
+impl LevelCurve {
+    /// Levels are sorted by `min_score`; the first must start at 0 and both
+    /// the level numbers and the thresholds must strictly increase.
+    pub fn new(mut levels: Vec<Level>) -> Result<Self, &'static str> {
+        levels.sort_by_key(|l| l.min_score);
+        match levels.first() {
+            None => return Err("Kurva level tidak boleh kosong"),
+            Some(first) if first.min_score != 0 => {
+                return Err("Level pertama harus dimulai dari skor 0");
+            }
+            Some(_) => {}
+        }
+        if levels
+            .windows(2)
+            .any(|pair| pair[1].level <= pair[0].level || pair[1].min_score <= pair[0].min_score)
+        {
+            return Err("Nomor level dan skor minimum harus terus naik");
+        }
+        Ok(Self { levels })
+    }
+
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    pub fn level_for(&self, total_score: i32) -> &Level {
+        self.levels
+            .iter()
+            .rev()
+            .find(|l| l.min_score <= total_score)
+            .unwrap_or(&self.levels[0])
+    }
+
+    pub fn next_level(&self, total_score: i32) -> Option<&Level> {
+        // A negative total still sits on the first level, which starts at 0.
+        self.levels
+            .iter()
+            .find(|l| l.min_score > total_score.max(0))
+    }
+
+    pub fn progress(&self, total_score: i32) -> LevelProgress {
+        let current = self.level_for(total_score);
+        let next = self.next_level(total_score);
+        let progress_percent = match next {
+            Some(next) => {
+                let span = (next.min_score - current.min_score) as f64;
+                let earned = (total_score - current.min_score).max(0) as f64;
+                (earned / span * 100.0).clamp(0.0, 100.0)
+            }
+            None => 100.0,
+        };
+
+        LevelProgress {
+            level: current.level,
+            title: current.title.clone(),
+            total_score,
+            level_min_score: current.min_score,
+            next_level_min_score: next.map(|l| l.min_score),
+            progress_percent,
+        }
+    }
+}
+
+/// A level a user reached, kept until the client has shown it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelUp {
+    pub user_id: Uuid,
+    pub level: i32,
+    pub title: String,
+    /// The user's total score right after the change that crossed the level.
+    pub total_score: i32,
+    pub reached_at: DateTime<Utc>,
+    pub seen_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(level: i32, min_score: i32) -> Level {
+        Level {
+            level,
+            title: format!("Level {}", level),
+            min_score,
+        }
+    }
+
+    fn curve() -> LevelCurve {
+        LevelCurve::new(vec![level(3, 300), level(1, 0), level(2, 100)]).unwrap()
+    }
+
+    #[test]
+    fn test_curve_must_start_at_zero_and_increase() {
+        assert!(LevelCurve::new(vec![]).is_err());
+        assert!(LevelCurve::new(vec![level(1, 10)]).is_err());
+        assert!(LevelCurve::new(vec![level(1, 0), level(1, 100)]).is_err());
+        assert_eq!(curve().levels()[0].level, 1);
+    }
+
+    #[test]
+    fn test_progress_within_a_level() {
+        let progress = curve().progress(150);
+
+        assert_eq!(progress.level, 2);
+        assert_eq!(progress.level_min_score, 100);
+        assert_eq!(progress.next_level_min_score, Some(300));
+        assert_eq!(progress.progress_percent, 25.0);
+    }
+
+    #[test]
+    fn test_top_level_is_complete() {
+        let progress = curve().progress(5000);
+
+        assert_eq!(progress.level, 3);
+        assert_eq!(progress.next_level_min_score, None);
+        assert_eq!(progress.progress_percent, 100.0);
+    }
+
+    #[test]
+    fn test_negative_score_stays_on_first_level() {
+        assert_eq!(curve().level_for(-20).level, 1);
+        assert_eq!(curve().progress(-20).progress_percent, 0.0);
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, TimeDelta};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a mission's window is split into instances. Each instance keeps its own
+/// progress and can be claimed once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MissionPeriod {
+    /// A fresh instance every calendar day.
+    #[default]
+    Daily,
+    /// A fresh instance every week, starting on Monday.
+    Weekly,
+    /// A single instance spanning the whole window, e.g. an event.
+    Custom,
+}
+
+impl FromStr for MissionPeriod {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "daily" => Ok(MissionPeriod::Daily),
+            "weekly" => Ok(MissionPeriod::Weekly),
+            "custom" => Ok(MissionPeriod::Custom),
+            _ => Err("Periode misi harus Daily, Weekly, atau Custom."),
+        }
+    }
+}
+
+/// A half-open span of wall-clock time, `[starts_at, ends_at)`, read on the
+/// user's clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MissionWindow {
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
+
+impl MissionWindow {
+    pub fn new(starts_at: NaiveDateTime, ends_at: NaiveDateTime) -> Result<Self, &'static str> {
+        if ends_at <= starts_at {
+            return Err("Waktu selesai misi harus setelah waktu mulai.");
+        }
+        Ok(Self { starts_at, ends_at })
+    }
+
+    /// The whole of `date`.
+    pub fn day(date: NaiveDate) -> Self {
+        let starts_at = date.and_time(chrono::NaiveTime::MIN);
+        Self {
+            starts_at,
+            ends_at: starts_at + TimeDelta::days(1),
+        }
+    }
+
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        self.starts_at <= at && at < self.ends_at
+    }
+
+    fn clip(&self, other: MissionWindow) -> Self {
+        Self {
+            starts_at: self.starts_at.max(other.starts_at),
+            ends_at: self.ends_at.min(other.ends_at),
+        }
+    }
+}
+
+impl MissionPeriod {
+    /// The instance of this period that contains `at`, cut to `window`.
+    /// `None` when `at` falls outside the window.
+    pub fn instance_at(&self, window: &MissionWindow, at: NaiveDateTime) -> Option<MissionWindow> {
+        if !window.contains(at) {
+            return None;
+        }
+        let date = at.date();
+        let span = match self {
+            MissionPeriod::Daily => MissionWindow::day(date),
+            MissionPeriod::Weekly => {
+                let monday = date - Days::new(u64::from(date.weekday().num_days_from_monday()));
+                let starts_at = MissionWindow::day(monday).starts_at;
+                MissionWindow {
+                    starts_at,
+                    ends_at: starts_at + TimeDelta::weeks(1),
+                }
+            }
+            MissionPeriod::Custom => return Some(*window),
+        };
+        Some(window.clip(span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 6, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn season() -> MissionWindow {
+        // Wednesday 3 June 12:00 to Wednesday 17 June 00:00.
+        MissionWindow::new(at(3, 12), at(17, 0)).unwrap()
+    }
+
+    #[test]
+    fn test_window_must_end_after_it_starts() {
+        assert!(MissionWindow::new(at(3, 0), at(3, 0)).is_err());
+        assert!(MissionWindow::day(at(3, 0).date()).contains(at(3, 23)));
+        assert!(!MissionWindow::day(at(3, 0).date()).contains(at(4, 0)));
+    }
+
+    #[test]
+    fn test_daily_instances_follow_the_calendar_day() {
+        let instance = MissionPeriod::Daily
+            .instance_at(&season(), at(5, 9))
+            .unwrap();
+        assert_eq!(instance, MissionWindow::new(at(5, 0), at(6, 0)).unwrap());
+
+        let first = MissionPeriod::Daily
+            .instance_at(&season(), at(3, 18))
+            .unwrap();
+        assert_eq!(first.starts_at, at(3, 12));
+    }
+
+    #[test]
+    fn test_weekly_instances_start_on_monday_and_are_cut_to_the_window() {
+        let first = MissionPeriod::Weekly
+            .instance_at(&season(), at(4, 10))
+            .unwrap();
+        assert_eq!(first, MissionWindow::new(at(3, 12), at(8, 0)).unwrap());
+
+        let second = MissionPeriod::Weekly
+            .instance_at(&season(), at(14, 22))
+            .unwrap();
+        assert_eq!(second, MissionWindow::new(at(8, 0), at(15, 0)).unwrap());
+
+        let last = MissionPeriod::Weekly
+            .instance_at(&season(), at(16, 8))
+            .unwrap();
+        assert_eq!(last, MissionWindow::new(at(15, 0), at(17, 0)).unwrap());
+    }
+
+    #[test]
+    fn test_custom_period_is_one_instance_and_nothing_outside_the_window() {
+        assert_eq!(
+            MissionPeriod::Custom.instance_at(&season(), at(10, 0)),
+            Some(season())
+        );
+        assert_eq!(MissionPeriod::Daily.instance_at(&season(), at(3, 11)), None);
+        assert_eq!(
+            MissionPeriod::Weekly.instance_at(&season(), at(17, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mission_period_parses_case_insensitively() {
+        assert_eq!("weekly".parse::<MissionPeriod>(), Ok(MissionPeriod::Weekly));
+        assert!("monthly".parse::<MissionPeriod>().is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Days, NaiveDate};
+use uuid::Uuid;
+
+use super::daily_mission::DailyMission;
+use super::mission_template::MissionTemplate;
+
+/// Small deterministic PRNG (SplitMix64). Seeding it with the date makes the
+/// rotation reproducible: every instance plans the same missions for a day.
+struct DateRng(u64);
+
+impl DateRng {
+    fn for_date(date: NaiveDate) -> Self {
+        Self(date.num_days_from_ce() as u64)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound`; `bound` must be positive.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    fn in_range(&mut self, (min, max): (i32, i32)) -> i32 {
+        let span = (max as i64 - min as i64 + 1) as u64;
+        (min as i64 + self.below(span) as i64) as i32
+    }
+}
+
+/// Policy that turns mission templates into concrete daily missions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissionRotation {
+    pub missions_per_day: usize,
+    /// A template used on one of the previous `repeat_window_days` days is
+    /// only picked again when there are not enough fresh templates.
+    pub repeat_window_days: u32,
+}
+
+impl MissionRotation {
+    pub fn new(missions_per_day: usize, repeat_window_days: u32) -> Self {
+        Self {
+            missions_per_day,
+            repeat_window_days,
+        }
+    }
+
+    /// Plans `days` days starting at `start`. Dates that already have missions
+    /// in `existing` are left alone; `existing` should also cover the repeat
+    /// window before `start` so the first planned days respect it.
+    pub fn plan_range(
+        &self,
+        start: NaiveDate,
+        days: u32,
+        templates: &[MissionTemplate],
+        existing: &[DailyMission],
+    ) -> Vec<DailyMission> {
+        let mut active: Vec<&MissionTemplate> = templates.iter().filter(|t| t.is_active).collect();
+        active.sort_by_key(|t| t.id);
+
+        let mut scheduled: HashMap<NaiveDate, Vec<Option<Uuid>>> = HashMap::new();
+        for mission in existing {
+            scheduled
+                .entry(mission.date)
+                .or_default()
+                .push(mission.template_id);
+        }
+
+        let mut planned = Vec::new();
+        for offset in 0..days {
+            let Some(date) = start.checked_add_days(Days::new(offset as u64)) else {
+                break;
+            };
+            if scheduled.contains_key(&date) {
+                continue;
+            }
+
+            let recent = self.recently_used(date, &scheduled);
+            let missions = self.plan_day(date, &active, &recent);
+            scheduled.insert(date, missions.iter().map(|m| m.template_id).collect());
+            planned.extend(missions);
+        }
+        planned
+    }
+
+    fn recently_used(
+        &self,
+        date: NaiveDate,
+        scheduled: &HashMap<NaiveDate, Vec<Option<Uuid>>>,
+    ) -> HashSet<Uuid> {
+        (1..=self.repeat_window_days)
+            .filter_map(|back| date.checked_sub_days(Days::new(back as u64)))
+            .filter_map(|day| scheduled.get(&day))
+            .flatten()
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    fn plan_day(
+        &self,
+        date: NaiveDate,
+        templates: &[&MissionTemplate],
+        recent: &HashSet<Uuid>,
+    ) -> Vec<DailyMission> {
+        let mut rng = DateRng::for_date(date);
+        let (mut fresh, mut repeated): (Vec<&MissionTemplate>, Vec<&MissionTemplate>) =
+            templates.iter().partition(|t| !recent.contains(&t.id));
+
+        let mut picked = Vec::with_capacity(self.missions_per_day);
+        while picked.len() < self.missions_per_day {
+            let pool = if fresh.is_empty() {
+                &mut repeated
+            } else {
+                &mut fresh
+            };
+            let Some(index) = Self::weighted_index(&mut rng, pool) else {
+                break;
+            };
+            picked.push(pool.remove(index));
+        }
+
+        picked
+            .into_iter()
+            .filter_map(|template| {
+                let target = rng.in_range(template.target_range());
+                let reward = rng.in_range(template.reward_range());
+                template.instantiate(date, target, reward).ok()
+            })
+            .collect()
+    }
+
+    fn weighted_index(rng: &mut DateRng, pool: &[&MissionTemplate]) -> Option<usize> {
+        let total: u64 = pool.iter().map(|t| t.weight.max(0) as u64).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.below(total);
+        pool.iter().position(|t| {
+            let weight = t.weight.max(0) as u64;
+            if roll < weight {
+                true
+            } else {
+                roll -= weight;
+                false
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::gamification::domain::entities::daily_mission::MissionType;
+
+    fn template(weight: i32) -> MissionTemplate {
+        MissionTemplate::new(
+            Uuid::new_v4(),
+            "Baca {target} artikel".to_string(),
+            MissionType::ReadArticle,
+            (1, 5),
+            (10, 50),
+            weight,
+        )
+        .unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, day).unwrap()
+    }
+
+    fn fingerprint(missions: &[DailyMission]) -> Vec<(NaiveDate, Option<Uuid>, i32, i32)> {
+        missions
+            .iter()
+            .map(|m| (m.date, m.template_id, m.target_count, m.reward_points))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_is_deterministic_per_date() {
+        let templates: Vec<_> = (0..6).map(|i| template(i + 1)).collect();
+        let mut shuffled = templates.clone();
+        shuffled.reverse();
+        let rotation = MissionRotation::new(3, 2);
+
+        let first = rotation.plan_range(date(1), 7, &templates, &[]);
+        let second = rotation.plan_range(date(1), 7, &shuffled, &[]);
+
+        assert_eq!(first.len(), 21);
+        assert_eq!(fingerprint(&first), fingerprint(&second));
+    }
+
+    #[test]
+    fn test_plan_avoids_repeats_within_window() {
+        let templates: Vec<_> = (0..6).map(|_| template(1)).collect();
+        let rotation = MissionRotation::new(2, 2);
+
+        let planned = rotation.plan_range(date(1), 10, &templates, &[]);
+
+        for day in 2..=10 {
+            let today: HashSet<_> = planned
+                .iter()
+                .filter(|m| m.date == date(day))
+                .map(|m| m.template_id)
+                .collect();
+            let window: HashSet<_> = planned
+                .iter()
+                .filter(|m| m.date < date(day) && m.date + Days::new(2) >= date(day))
+                .map(|m| m.template_id)
+                .collect();
+            assert!(
+                today.is_disjoint(&window),
+                "hari {} mengulang template",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn test_plan_falls_back_to_recent_templates_when_pool_is_small() {
+        let templates: Vec<_> = (0..2).map(|_| template(1)).collect();
+        let rotation = MissionRotation::new(2, 3);
+
+        let planned = rotation.plan_range(date(1), 3, &templates, &[]);
+
+        assert_eq!(planned.len(), 6);
+        for day in 1..=3 {
+            let today: HashSet<_> = planned
+                .iter()
+                .filter(|m| m.date == date(day))
+                .map(|m| m.template_id)
+                .collect();
+            assert_eq!(today.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_plan_skips_dates_already_scheduled() {
+        let templates: Vec<_> = (0..4).map(|_| template(1)).collect();
+        let manual = DailyMission::new(
+            Uuid::new_v4(),
+            "Misi manual".to_string(),
+            1,
+            date(2),
+            10,
+            MissionType::Quiz,
+        )
+        .unwrap();
+        let rotation = MissionRotation::new(1, 0);
+
+        let planned = rotation.plan_range(date(1), 3, &templates, &[manual]);
+
+        let dates: Vec<_> = planned.iter().map(|m| m.date).collect();
+        assert_eq!(dates, vec![date(1), date(3)]);
+    }
+
+    #[test]
+    fn test_heavier_templates_are_picked_more_often() {
+        let heavy = template(50);
+        let light = template(1);
+        let rotation = MissionRotation::new(1, 0);
+
+        let planned = rotation.plan_range(date(1), 30, &[heavy.clone(), light], &[]);
+
+        let heavy_days = planned
+            .iter()
+            .filter(|m| m.template_id == Some(heavy.id))
+            .count();
+        assert!(
+            heavy_days >= 25,
+            "template berat hanya terpilih {} kali",
+            heavy_days
+        );
+    }
+
+    #[test]
+    fn test_rolled_values_stay_within_template_ranges() {
+        let templates: Vec<_> = (0..3).map(|_| template(1)).collect();
+        let rotation = MissionRotation::new(3, 0);
+
+        for mission in rotation.plan_range(date(1), 20, &templates, &[]) {
+            assert!((1..=5).contains(&mission.target_count));
+            assert!((10..=50).contains(&mission.reward_points));
+            assert_eq!(
+                mission.description,
+                format!("Baca {} artikel", mission.target_count)
+            );
+        }
+    }
+
+    #[test]
+    fn test_inactive_templates_are_ignored() {
+        let mut retired = template(100);
+        retired.is_active = false;
+        let active = template(1);
+        let rotation = MissionRotation::new(2, 0);
+
+        let planned = rotation.plan_range(date(1), 5, &[retired, active.clone()], &[]);
+
+        assert_eq!(planned.len(), 5);
+        assert!(planned.iter().all(|m| m.template_id == Some(active.id)));
+    }
+}
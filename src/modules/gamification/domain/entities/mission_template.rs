@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::daily_mission::{DailyMission, MissionType, QuizConditions};
+
+/// Placeholder in a template description that is replaced with the rolled target.
+pub const TARGET_PLACEHOLDER: &str = "{target}";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissionTemplate {
+    pub id: Uuid,
+    pub description: String,
+    pub mission_type: MissionType,
+    pub min_target: i32,
+    pub max_target: i32,
+    pub min_reward: i32,
+    pub max_reward: i32,
+    pub weight: i32,
+    pub is_active: bool,
+    /// Copied onto every mission generated from a `Quiz` template.
+    pub quiz_conditions: QuizConditions,
+}
+
+impl MissionTemplate {
+    pub fn new(
+        id: Uuid,
+        description: String,
+        mission_type: MissionType,
+        target_range: (i32, i32),
+        reward_range: (i32, i32),
+        weight: i32,
+    ) -> Result<Self, &'static str> {
+        if description.trim().is_empty() {
+            return Err("Deskripsi template misi tidak boleh kosong.");
+        }
+        let (min_target, max_target) = target_range;
+        if min_target <= 0 || max_target < min_target {
+            return Err("Rentang target harus positif dan minimum tidak melebihi maksimum.");
+        }
+        let (min_reward, max_reward) = reward_range;
+        if min_reward < 0 || max_reward < min_reward {
+            return Err("Rentang reward tidak boleh negatif dan minimum tidak melebihi maksimum.");
+        }
+        if weight <= 0 {
+            return Err("Bobot template harus lebih dari 0.");
+        }
+
+        Ok(Self {
+            id,
+            description,
+            mission_type,
+            min_target,
+            max_target,
+            min_reward,
+            max_reward,
+            weight,
+            is_active: true,
+            quiz_conditions: QuizConditions::default(),
+        })
+    }
+
+    pub fn with_quiz_conditions(mut self, conditions: QuizConditions) -> Self {
+        self.quiz_conditions = conditions;
+        self
+    }
+
+    /// Builds the concrete mission for `date` with already rolled target and reward.
+    pub fn instantiate(
+        &self,
+        date: NaiveDate,
+        target_count: i32,
+        reward_points: i32,
+    ) -> Result<DailyMission, &'static str> {
+        let description = self
+            .description
+            .replace(TARGET_PLACEHOLDER, &target_count.to_string());
+        Ok(DailyMission::new(
+            Uuid::new_v4(),
+            description,
+            target_count,
+            date,
+            reward_points,
+            self.mission_type,
+        )?
+        .with_template(self.id)
+        .with_quiz_conditions(self.quiz_conditions))
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn weight(&self) -> i32 {
+        self.weight
+    }
+    pub fn target_range(&self) -> (i32, i32) {
+        (self.min_target, self.max_target)
+    }
+    pub fn reward_range(&self) -> (i32, i32) {
+        (self.min_reward, self.max_reward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_rejects_inverted_ranges() {
+        let inverted_target = MissionTemplate::new(
+            Uuid::new_v4(),
+            "Baca {target} artikel".to_string(),
+            MissionType::ReadArticle,
+            (5, 2),
+            (10, 20),
+            1,
+        );
+        assert!(inverted_target.is_err());
+
+        let zero_weight = MissionTemplate::new(
+            Uuid::new_v4(),
+            "Baca {target} artikel".to_string(),
+            MissionType::ReadArticle,
+            (1, 2),
+            (10, 20),
+            0,
+        );
+        assert_eq!(
+            zero_weight.unwrap_err(),
+            "Bobot template harus lebih dari 0."
+        );
+    }
+
+    #[test]
+    fn test_instantiate_fills_target_placeholder() {
+        let template = MissionTemplate::new(
+            Uuid::new_v4(),
+            "Kerjakan {target} kuis".to_string(),
+            MissionType::Quiz,
+            (2, 4),
+            (30, 60),
+            3,
+        )
+        .unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 5, 1).unwrap();
+
+        let mission = template.instantiate(date, 3, 45).unwrap();
+
+        assert_eq!(mission.description(), "Kerjakan 3 kuis");
+        assert_eq!(mission.template_id(), Some(template.id()));
+        assert_eq!(mission.mission_type(), MissionType::Quiz);
+        assert_eq!(mission.date(), date);
+        assert!(mission.quiz_conditions().is_empty());
+
+        let strict = template
+            .with_quiz_conditions(QuizConditions::new(Some(80), None).unwrap())
+            .instantiate(date, 2, 30)
+            .unwrap();
+        assert_eq!(strict.quiz_conditions().min_score, Some(80));
+    }
+}
@@ -1,5 +1,19 @@
 // Gamification entities - Achievement, Mission, Reward entities
 pub mod achievement;
+pub mod achievement_chain;
+pub mod achievement_criteria;
+pub mod achievement_replay;
+pub mod criteria_evaluator;
 pub mod daily_mission;
+pub mod gamification_event;
+pub mod level;
+pub mod mission_period;
+pub mod mission_rotation;
+pub mod mission_template;
+pub mod points_ledger;
+pub mod profile_showcase;
+pub mod reward_item;
+pub mod reward_purchase;
 pub mod user_achievement;
 pub mod user_mission;
+pub mod user_streak;
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// What a ledger entry was awarded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PointsSource {
+    /// The score a user already had when the ledger was introduced.
+    OpeningBalance,
+    Quiz,
+    MissionClaim,
+    Achievement,
+    StreakMilestone,
+    /// A reward shop purchase; its entry carries a negative delta.
+    Purchase,
+    /// The extra a bought score boost adds on top of a quiz score.
+    ScoreBoost,
+}
+
+impl PointsSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PointsSource::OpeningBalance => "opening_balance",
+            PointsSource::Quiz => "quiz",
+            PointsSource::MissionClaim => "mission_claim",
+            PointsSource::Achievement => "achievement",
+            PointsSource::StreakMilestone => "streak_milestone",
+            PointsSource::Purchase => "purchase",
+            PointsSource::ScoreBoost => "score_boost",
+        }
+    }
+}
+
+impl fmt::Display for PointsSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PointsSource {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "opening_balance" => Ok(PointsSource::OpeningBalance),
+            "quiz" => Ok(PointsSource::Quiz),
+            "mission_claim" => Ok(PointsSource::MissionClaim),
+            "achievement" => Ok(PointsSource::Achievement),
+            "streak_milestone" => Ok(PointsSource::StreakMilestone),
+            "purchase" => Ok(PointsSource::Purchase),
+            "score_boost" => Ok(PointsSource::ScoreBoost),
+            _ => Err("Sumber poin tidak dikenal"),
+        }
+    }
+}
+
+/// One award or spend in the append-only points ledger. A user's `total_score` is the
+/// sum of their entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointsEntry {
+    pub user_id: Uuid,
+    pub delta: i32,
+    pub source: PointsSource,
+    /// The quiz, mission, achievement or purchase behind the entry, when there is one.
+    pub source_id: Option<Uuid>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl PointsEntry {
+    pub fn new(
+        user_id: Uuid,
+        delta: i32,
+        source: PointsSource,
+        source_id: Option<Uuid>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            delta,
+            source,
+            source_id,
+            occurred_at,
+        }
+    }
+}
+
+/// A ledger entry as stored, with its position in the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub id: i64,
+    pub entry: PointsEntry,
+}
+
+/// A user whose `total_score` projection disagrees with their ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceMismatch {
+    pub user_id: Uuid,
+    pub total_score: i32,
+    pub ledger_total: i64,
+}
+
+impl BalanceMismatch {
+    pub fn difference(&self) -> i64 {
+        i64::from(self.total_score) - self.ledger_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_round_trips_through_its_stored_name() {
+        for source in [
+            PointsSource::OpeningBalance,
+            PointsSource::Quiz,
+            PointsSource::MissionClaim,
+            PointsSource::Achievement,
+            PointsSource::StreakMilestone,
+            PointsSource::Purchase,
+        ] {
+            assert_eq!(source.as_str().parse::<PointsSource>(), Ok(source));
+        }
+        assert!("bonus".parse::<PointsSource>().is_err());
+    }
+
+    #[test]
+    fn test_mismatch_difference_is_projection_minus_ledger() {
+        let mismatch = BalanceMismatch {
+            user_id: Uuid::new_v4(),
+            total_score: 120,
+            ledger_total: 150,
+        };
+
+        assert_eq!(mismatch.difference(), -30);
+    }
+}
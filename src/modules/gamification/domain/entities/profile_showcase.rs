@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use super::user_achievement::UserAchievement;
+
+/// The ordered badges a user pins on their public profile.
+pub struct ProfileShowcase;
+
+impl ProfileShowcase {
+    /// Pins `ordered_ids` at positions 1, 2, ... and unpins every other badge.
+    /// All ids must be distinct, completed achievements of the user, at most
+    /// `max_badges` of them. Returns only the rows whose showcase state changed.
+    pub fn arrange(
+        user_achievements: Vec<UserAchievement>,
+        ordered_ids: &[Uuid],
+        max_badges: usize,
+    ) -> Result<Vec<UserAchievement>, &'static str> {
+        if ordered_ids.len() > max_badges {
+            return Err("Terlalu banyak badge untuk dipajang");
+        }
+        let mut seen = HashSet::new();
+        if !ordered_ids.iter().all(|id| seen.insert(*id)) {
+            return Err("Badge yang sama tidak boleh dipajang dua kali");
+        }
+
+        let mut changed = Vec::new();
+        let mut pinned = 0;
+        for mut user_ach in user_achievements {
+            let before = (user_ach.is_shown_on_profile(), user_ach.showcase_position());
+            match ordered_ids
+                .iter()
+                .position(|id| *id == user_ach.achievement_id())
+            {
+                Some(index) => {
+                    user_ach.pin(index as i32 + 1)?;
+                    pinned += 1;
+                }
+                None => user_ach.unpin(),
+            }
+            if (user_ach.is_shown_on_profile(), user_ach.showcase_position()) != before {
+                changed.push(user_ach);
+            }
+        }
+
+        if pinned != ordered_ids.len() {
+            return Err("Hanya achievement yang sudah selesai yang bisa dipajang");
+        }
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn earned(user_id: Uuid) -> UserAchievement {
+        let mut user_achievement = UserAchievement::new(user_id, Uuid::new_v4());
+        user_achievement.add_progress(1, 1, Utc::now());
+        user_achievement
+    }
+
+    #[test]
+    fn test_arrange_pins_in_order_and_unpins_the_rest() {
+        let user_id = Uuid::new_v4();
+        let (first, second) = (earned(user_id), earned(user_id));
+        let mut previously_shown = earned(user_id);
+        previously_shown.pin(1).unwrap();
+        let ids = [second.achievement_id(), first.achievement_id()];
+
+        let changed = ProfileShowcase::arrange(
+            vec![first.clone(), second.clone(), previously_shown.clone()],
+            &ids,
+            3,
+        )
+        .unwrap();
+
+        let position_of = |id: Uuid| {
+            changed
+                .iter()
+                .find(|ua| ua.achievement_id() == id)
+                .map(|ua| ua.showcase_position())
+        };
+        assert_eq!(position_of(second.achievement_id()), Some(Some(1)));
+        assert_eq!(position_of(first.achievement_id()), Some(Some(2)));
+        assert_eq!(position_of(previously_shown.achievement_id()), Some(None));
+    }
+
+    #[test]
+    fn test_arrange_rejects_unfinished_unknown_duplicate_or_too_many() {
+        let user_id = Uuid::new_v4();
+        let done = earned(user_id);
+        let unfinished = UserAchievement::new(user_id, Uuid::new_v4());
+
+        let arrange = |ids: &[Uuid], max: usize| {
+            ProfileShowcase::arrange(vec![done.clone(), unfinished.clone()], ids, max)
+        };
+
+        assert!(arrange(&[unfinished.achievement_id()], 3).is_err());
+        assert!(arrange(&[Uuid::new_v4()], 3).is_err());
+        assert!(arrange(&[done.achievement_id(), done.achievement_id()], 3).is_err());
+        assert!(arrange(&[done.achievement_id(), Uuid::new_v4()], 1).is_err());
+        assert!(arrange(&[done.achievement_id()], 1).is_ok());
+    }
+
+    #[test]
+    fn test_empty_list_clears_the_showcase() {
+        let mut shown = earned(Uuid::new_v4());
+        shown.pin(1).unwrap();
+
+        let changed = ProfileShowcase::arrange(vec![shown], &[], 3).unwrap();
+
+        assert_eq!(changed.len(), 1);
+        assert!(!changed[0].is_shown_on_profile());
+    }
+}
@@ -0,0 +1,366 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use super::reward_purchase::RewardPurchase;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RewardKind {
+    StreakFreeze,
+    Cosmetic,
+    /// Raises the user's own score gains for a limited time.
+    ScoreBoost,
+}
+
+impl fmt::Display for RewardKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RewardKind::StreakFreeze => write!(f, "StreakFreeze"),
+            RewardKind::Cosmetic => write!(f, "Cosmetic"),
+            RewardKind::ScoreBoost => write!(f, "ScoreBoost"),
+        }
+    }
+}
+
+impl FromStr for RewardKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "streakfreeze" => Ok(RewardKind::StreakFreeze),
+            "cosmetic" => Ok(RewardKind::Cosmetic),
+            "scoreboost" => Ok(RewardKind::ScoreBoost),
+            _ => Err("Jenis hadiah harus StreakFreeze, Cosmetic, atau ScoreBoost."),
+        }
+    }
+}
+
+/// How much and how long a `ScoreBoost` raises score gains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreBoost {
+    pub percent: i32,
+    pub hours: i32,
+}
+
+/// Why a purchase was turned down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PurchaseRejection {
+    UnknownUser,
+    UnknownItem,
+    /// The item was taken off the shop.
+    Unavailable,
+    SoldOut,
+    LimitReached {
+        limit: i32,
+    },
+    InsufficientPoints {
+        balance: i32,
+        price: i32,
+    },
+}
+
+impl fmt::Display for PurchaseRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PurchaseRejection::UnknownUser => write!(f, "User tidak ditemukan."),
+            PurchaseRejection::UnknownItem => write!(f, "Hadiah tidak ditemukan."),
+            PurchaseRejection::Unavailable => write!(f, "Hadiah ini sudah tidak dijual."),
+            PurchaseRejection::SoldOut => write!(f, "Stok hadiah sudah habis."),
+            PurchaseRejection::LimitReached { limit } => write!(
+                f,
+                "Batas pembelian hadiah ini ({} kali per user) sudah tercapai.",
+                limit
+            ),
+            PurchaseRejection::InsufficientPoints { balance, price } => write!(
+                f,
+                "Poin tidak cukup: harga {} poin, saldo {} poin.",
+                price, balance
+            ),
+        }
+    }
+}
+
+/// An item in the reward shop, bought with earned points.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardItem {
+    id: Uuid,
+    name: String,
+    description: String,
+    kind: RewardKind,
+    price: i32,
+    /// `None` means unlimited.
+    stock: Option<i32>,
+    /// How many times one user may buy the item; `None` means no limit.
+    per_user_limit: Option<i32>,
+    boost: Option<ScoreBoost>,
+    is_active: bool,
+}
+
+impl RewardItem {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        description: String,
+        kind: RewardKind,
+        price: i32,
+    ) -> Result<Self, &'static str> {
+        if name.trim().is_empty() {
+            return Err("Nama hadiah tidak boleh kosong.");
+        }
+        if price <= 0 {
+            return Err("Harga hadiah harus lebih dari 0.");
+        }
+
+        Ok(Self {
+            id,
+            name,
+            description,
+            kind,
+            price,
+            stock: None,
+            per_user_limit: None,
+            boost: None,
+            is_active: true,
+        })
+    }
+
+    pub fn with_stock(mut self, stock: Option<i32>) -> Result<Self, &'static str> {
+        if stock.is_some_and(|s| s < 0) {
+            return Err("Stok hadiah tidak boleh negatif.");
+        }
+        self.stock = stock;
+        Ok(self)
+    }
+
+    pub fn with_per_user_limit(mut self, limit: Option<i32>) -> Result<Self, &'static str> {
+        if limit.is_some_and(|l| l <= 0) {
+            return Err("Batas pembelian per user harus lebih dari 0.");
+        }
+        self.per_user_limit = limit;
+        Ok(self)
+    }
+
+    /// Required for `ScoreBoost` items and rejected on every other kind.
+    pub fn with_boost(mut self, boost: Option<ScoreBoost>) -> Result<Self, &'static str> {
+        match (self.kind, boost) {
+            (RewardKind::ScoreBoost, None) => {
+                return Err("Hadiah ScoreBoost wajib punya persentase dan durasi boost.");
+            }
+            (RewardKind::ScoreBoost, Some(b)) if !(1..=100).contains(&b.percent) => {
+                return Err("Persentase boost harus antara 1 dan 100.");
+            }
+            (RewardKind::ScoreBoost, Some(b)) if b.hours <= 0 => {
+                return Err("Durasi boost harus lebih dari 0 jam.");
+            }
+            (RewardKind::ScoreBoost, _) => {}
+            (_, Some(_)) => return Err("Hanya hadiah ScoreBoost yang boleh punya boost."),
+            (_, None) => {}
+        }
+        self.boost = boost;
+        Ok(self)
+    }
+
+    pub fn set_active(&mut self, is_active: bool) {
+        self.is_active = is_active;
+    }
+
+    /// Checks the shop rules for one more purchase by a user who holds
+    /// `balance` points and has bought the item `bought` times before.
+    pub fn check_purchase(&self, balance: i32, bought: i64) -> Result<(), PurchaseRejection> {
+        if !self.is_active {
+            return Err(PurchaseRejection::Unavailable);
+        }
+        if self.stock.is_some_and(|s| s <= 0) {
+            return Err(PurchaseRejection::SoldOut);
+        }
+        if let Some(limit) = self.per_user_limit
+            && bought >= i64::from(limit)
+        {
+            return Err(PurchaseRejection::LimitReached { limit });
+        }
+        if balance < self.price {
+            return Err(PurchaseRejection::InsufficientPoints {
+                balance,
+                price: self.price,
+            });
+        }
+        Ok(())
+    }
+
+    /// The purchase record for a sale that passed `check_purchase`.
+    pub fn purchase(&self, id: Uuid, user_id: Uuid, purchased_at: DateTime<Utc>) -> RewardPurchase {
+        RewardPurchase {
+            id,
+            user_id,
+            item_id: self.id,
+            item_name: self.name.clone(),
+            kind: self.kind,
+            price: self.price,
+            purchased_at,
+            expires_at: self
+                .boost
+                .map(|b| purchased_at + TimeDelta::hours(i64::from(b.hours))),
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn kind(&self) -> RewardKind {
+        self.kind
+    }
+    pub fn price(&self) -> i32 {
+        self.price
+    }
+    pub fn stock(&self) -> Option<i32> {
+        self.stock
+    }
+    pub fn per_user_limit(&self) -> Option<i32> {
+        self.per_user_limit
+    }
+    pub fn boost(&self) -> Option<ScoreBoost> {
+        self.boost
+    }
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: RewardKind, price: i32) -> RewardItem {
+        RewardItem::new(
+            Uuid::new_v4(),
+            "Bingkai Emas".to_string(),
+            String::new(),
+            kind,
+            price,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_item_validates_price_stock_and_limit() {
+        assert!(
+            RewardItem::new(
+                Uuid::new_v4(),
+                " ".to_string(),
+                String::new(),
+                RewardKind::Cosmetic,
+                10
+            )
+            .is_err()
+        );
+        assert!(
+            RewardItem::new(
+                Uuid::new_v4(),
+                "A".to_string(),
+                String::new(),
+                RewardKind::Cosmetic,
+                0
+            )
+            .is_err()
+        );
+        assert!(item(RewardKind::Cosmetic, 10).with_stock(Some(-1)).is_err());
+        assert!(
+            item(RewardKind::Cosmetic, 10)
+                .with_per_user_limit(Some(0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_boost_is_required_only_for_score_boosts() {
+        let boost = ScoreBoost {
+            percent: 20,
+            hours: 24,
+        };
+
+        assert!(item(RewardKind::ScoreBoost, 10).with_boost(None).is_err());
+        assert!(
+            item(RewardKind::ScoreBoost, 10)
+                .with_boost(Some(ScoreBoost {
+                    percent: 0,
+                    ..boost
+                }))
+                .is_err()
+        );
+        assert!(
+            item(RewardKind::Cosmetic, 10)
+                .with_boost(Some(boost))
+                .is_err()
+        );
+        assert!(
+            item(RewardKind::ScoreBoost, 10)
+                .with_boost(Some(boost))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_check_purchase_applies_shop_rules_in_order() {
+        let limited = item(RewardKind::Cosmetic, 50)
+            .with_stock(Some(3))
+            .unwrap()
+            .with_per_user_limit(Some(1))
+            .unwrap();
+
+        assert_eq!(limited.check_purchase(50, 0), Ok(()));
+        assert_eq!(
+            limited.check_purchase(50, 1),
+            Err(PurchaseRejection::LimitReached { limit: 1 })
+        );
+        assert_eq!(
+            limited.check_purchase(49, 0),
+            Err(PurchaseRejection::InsufficientPoints {
+                balance: 49,
+                price: 50
+            })
+        );
+
+        let sold_out = item(RewardKind::Cosmetic, 50).with_stock(Some(0)).unwrap();
+        assert_eq!(
+            sold_out.check_purchase(500, 0),
+            Err(PurchaseRejection::SoldOut)
+        );
+
+        let mut retired = item(RewardKind::Cosmetic, 50);
+        retired.set_active(false);
+        assert_eq!(
+            retired.check_purchase(500, 0),
+            Err(PurchaseRejection::Unavailable)
+        );
+    }
+
+    #[test]
+    fn test_boost_purchase_expires_after_its_duration() {
+        let now = Utc::now();
+        let boost = item(RewardKind::ScoreBoost, 10)
+            .with_boost(Some(ScoreBoost {
+                percent: 10,
+                hours: 2,
+            }))
+            .unwrap();
+
+        let purchase = boost.purchase(Uuid::new_v4(), Uuid::new_v4(), now);
+
+        assert_eq!(purchase.price, 10);
+        assert_eq!(purchase.expires_at, Some(now + TimeDelta::hours(2)));
+        assert_eq!(
+            item(RewardKind::Cosmetic, 10)
+                .purchase(Uuid::new_v4(), Uuid::new_v4(), now)
+                .expires_at,
+            None
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::reward_item::RewardKind;
+
+/// One completed purchase from the reward shop. Its price is debited from the
+/// points ledger under the purchase id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardPurchase {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub item_id: Uuid,
+    pub item_name: String,
+    pub kind: RewardKind,
+    pub price: i32,
+    pub purchased_at: DateTime<Utc>,
+    /// When a score boost bought here runs out.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// What a user holds of one shop item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InventoryItem {
+    pub item_id: Uuid,
+    pub name: String,
+    pub kind: RewardKind,
+    pub quantity: i32,
+    /// For score boosts, the end of the latest boost bought.
+    pub active_until: Option<DateTime<Utc>>,
+}
@@ -10,6 +10,8 @@ pub struct UserAchievement {
     pub is_completed: bool,
     pub is_shown_on_profile: bool,
     pub completed_at: Option<DateTime<Utc>>,
+    /// 1-based slot on the profile showcase while `is_shown_on_profile`.
+    pub showcase_position: Option<i32>,
 }
 
 impl UserAchievement {
@@ -21,6 +23,7 @@ impl UserAchievement {
             is_completed: false,
             is_shown_on_profile: false,
             completed_at: None,
+            showcase_position: None,
         }
     }
 
@@ -39,6 +42,34 @@ impl UserAchievement {
         }
     }
 
+    /// Raises progress to `replayed`, rebuilt from past activity, along with its
+    /// completion time. Never lowers progress or reopens a completed
+    /// achievement. Returns whether anything changed.
+    pub fn catch_up(&mut self, replayed: &UserAchievement) -> bool {
+        if self.is_completed || replayed.current_progress <= self.current_progress {
+            return false;
+        }
+        self.current_progress = replayed.current_progress;
+        self.is_completed = replayed.is_completed;
+        self.completed_at = replayed.completed_at;
+        true
+    }
+
+    /// Shows the badge on the profile at `position`. Only earned badges can be pinned.
+    pub fn pin(&mut self, position: i32) -> Result<(), &'static str> {
+        if !self.is_completed {
+            return Err("Hanya achievement yang sudah selesai yang bisa dipajang");
+        }
+        self.is_shown_on_profile = true;
+        self.showcase_position = Some(position);
+        Ok(())
+    }
+
+    pub fn unpin(&mut self) {
+        self.is_shown_on_profile = false;
+        self.showcase_position = None;
+    }
+
     pub fn user_id(&self) -> Uuid {
         self.user_id
     }
@@ -62,6 +93,10 @@ impl UserAchievement {
     pub fn completed_at(&self) -> Option<DateTime<Utc>> {
         self.completed_at
     }
+
+    pub fn showcase_position(&self) -> Option<i32> {
+        self.showcase_position
+    }
 }
 
 #[cfg(test)]
@@ -97,4 +132,39 @@ mod test {
         user_achievement.add_progress(-5, milestone_target, test_time);
         assert_eq!(user_achievement.current_progress(), 0);
     }
+
+    #[test]
+    fn test_catch_up_only_moves_progress_forward() {
+        let user_id = Uuid::new_v4();
+        let achievement_id = Uuid::new_v4();
+        let reached_at = Utc::now() - chrono::TimeDelta::days(30);
+        let mut replayed = UserAchievement::new(user_id, achievement_id);
+        replayed.add_progress(10, 10, reached_at);
+
+        let mut ahead = UserAchievement::new(user_id, achievement_id);
+        ahead.add_progress(4, 10, Utc::now());
+        assert!(ahead.catch_up(&replayed));
+        assert!(ahead.is_completed());
+        assert_eq!(ahead.completed_at(), Some(reached_at));
+
+        let mut done = UserAchievement::new(user_id, achievement_id);
+        done.add_progress(10, 10, Utc::now());
+        assert!(!done.catch_up(&replayed));
+        assert_ne!(done.completed_at(), Some(reached_at));
+    }
+
+    #[test]
+    fn test_only_completed_achievement_can_be_pinned() {
+        let mut user_achievement = UserAchievement::new(Uuid::new_v4(), Uuid::new_v4());
+        assert!(user_achievement.pin(1).is_err());
+        assert!(!user_achievement.is_shown_on_profile());
+
+        user_achievement.add_progress(1, 1, Utc::now());
+        user_achievement.pin(2).unwrap();
+        assert!(user_achievement.is_shown_on_profile());
+        assert_eq!(user_achievement.showcase_position(), Some(2));
+
+        user_achievement.unpin();
+        assert_eq!(user_achievement.showcase_position(), None);
+    }
 }
@@ -1,19 +1,24 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// A user's progress on one period instance of a mission.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserMission {
     pub user_id: Uuid,
     pub mission_id: Uuid,
+    /// Start of the period instance this progress belongs to.
+    pub period_start: NaiveDateTime,
     pub current_progress: i32,
     pub is_claimed: bool,
 }
 
 impl UserMission {
-    pub fn new(user_id: Uuid, mission_id: Uuid) -> Self {
+    pub fn new(user_id: Uuid, mission_id: Uuid, period_start: NaiveDateTime) -> Self {
         Self {
             user_id,
             mission_id,
+            period_start,
             current_progress: 0,
             is_claimed: false,
         }
@@ -49,6 +54,9 @@ impl UserMission {
     pub fn mission_id(&self) -> Uuid {
         self.mission_id
     }
+    pub fn period_start(&self) -> NaiveDateTime {
+        self.period_start
+    }
     pub fn current_progress(&self) -> i32 {
         self.current_progress
     }
@@ -63,7 +71,7 @@ mod test {
 
     #[test]
     fn test_claim_reward_success_when_target_met() {
-        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4());
+        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4(), NaiveDateTime::MIN);
         let target = 3;
 
         user_mission.add_progress(3, target);
@@ -75,7 +83,7 @@ mod test {
 
     #[test]
     fn test_claim_reward_fails_if_already_claimed() {
-        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4());
+        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4(), NaiveDateTime::MIN);
         let target = 3;
 
         user_mission.add_progress(3, target);
@@ -91,7 +99,7 @@ mod test {
 
     #[test]
     fn test_add_progress_should_ignore_negative_or_zero_amount() {
-        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4());
+        let mut user_mission = UserMission::new(Uuid::new_v4(), Uuid::new_v4(), NaiveDateTime::MIN);
         let target = 3;
 
         user_mission.add_progress(0, target);
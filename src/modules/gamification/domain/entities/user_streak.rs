@@ -0,0 +1,231 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// A day the streak reached, with what it pays out. Every milestone also
+/// earns one streak freeze, up to the policy's maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreakMilestone {
+    pub days: i32,
+    pub reward_points: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreakPolicy {
+    /// Freezes a user starts with.
+    pub initial_freezes: i32,
+    pub max_freezes: i32,
+    pub milestones: Vec<StreakMilestone>,
+}
+
+impl StreakPolicy {
+    pub fn new(initial_freezes: i32, max_freezes: i32, milestones: Vec<StreakMilestone>) -> Self {
+        let mut milestones = milestones;
+        milestones.sort_by_key(|m| m.days);
+        Self {
+            initial_freezes: initial_freezes.clamp(0, max_freezes.max(0)),
+            max_freezes: max_freezes.max(0),
+            milestones,
+        }
+    }
+
+    /// Milestones crossed when the streak went from `previous` to `current` days.
+    pub fn milestones_reached(&self, previous: i32, current: i32) -> Vec<StreakMilestone> {
+        self.milestones
+            .iter()
+            .filter(|m| m.days > previous && m.days <= current)
+            .copied()
+            .collect()
+    }
+
+    /// The next milestone still ahead of a `current`-day streak.
+    pub fn next_milestone(&self, current: i32) -> Option<StreakMilestone> {
+        self.milestones.iter().find(|m| m.days > current).copied()
+    }
+}
+
+impl Default for StreakPolicy {
+    fn default() -> Self {
+        Self::new(
+            1,
+            2,
+            vec![
+                StreakMilestone {
+                    days: 7,
+                    reward_points: 50,
+                },
+                StreakMilestone {
+                    days: 30,
+                    reward_points: 200,
+                },
+                StreakMilestone {
+                    days: 100,
+                    reward_points: 500,
+                },
+            ],
+        )
+    }
+}
+
+/// How a day of activity changed the streak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakChange {
+    /// The day was already counted (or is older than the last active day).
+    Unchanged,
+    Started,
+    Extended,
+    /// Missed days were covered by freezes and the streak continued.
+    Frozen {
+        freezes_used: i32,
+    },
+    /// Too many days were missed; the streak restarted at 1.
+    Reset,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserStreak {
+    pub user_id: Uuid,
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub last_active_date: Option<NaiveDate>,
+    pub freezes_available: i32,
+}
+
+impl UserStreak {
+    pub fn new(user_id: Uuid, freezes_available: i32) -> Self {
+        Self {
+            user_id,
+            current_streak: 0,
+            longest_streak: 0,
+            last_active_date: None,
+            freezes_available,
+        }
+    }
+
+    /// Counts `day` as active. A gap of missed days is bridged by spending one
+    /// freeze per missed day when enough are available; otherwise the streak resets.
+    pub fn record_activity(&mut self, day: NaiveDate) -> StreakChange {
+        let change = match self.last_active_date {
+            None => {
+                self.current_streak = 1;
+                StreakChange::Started
+            }
+            Some(last) if day <= last => return StreakChange::Unchanged,
+            Some(last) => {
+                let missed = ((day - last).num_days() - 1) as i32;
+                if missed == 0 {
+                    self.current_streak += 1;
+                    StreakChange::Extended
+                } else if missed <= self.freezes_available {
+                    self.freezes_available -= missed;
+                    self.current_streak += 1;
+                    StreakChange::Frozen {
+                        freezes_used: missed,
+                    }
+                } else {
+                    self.current_streak = 1;
+                    StreakChange::Reset
+                }
+            }
+        };
+
+        self.last_active_date = Some(day);
+        self.longest_streak = self.longest_streak.max(self.current_streak);
+        change
+    }
+
+    /// The streak as it stands on `today`: it still counts while the missed
+    /// days since the last activity can be covered by freezes.
+    pub fn current_as_of(&self, today: NaiveDate) -> i32 {
+        match self.last_active_date {
+            Some(last) if ((today - last).num_days() - 1) as i32 <= self.freezes_available => {
+                self.current_streak
+            }
+            _ => 0,
+        }
+    }
+
+    /// Earned freezes stop at `max_freezes`; freezes bought in the reward shop
+    /// may go beyond it and are never taken away here.
+    pub fn add_freezes(&mut self, count: i32, max_freezes: i32) {
+        self.freezes_available = self
+            .freezes_available
+            .max((self.freezes_available + count).min(max_freezes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, d).unwrap()
+    }
+
+    #[test]
+    fn test_consecutive_days_extend_and_same_day_is_ignored() {
+        let mut streak = UserStreak::new(Uuid::new_v4(), 0);
+
+        assert_eq!(streak.record_activity(day(1)), StreakChange::Started);
+        assert_eq!(streak.record_activity(day(2)), StreakChange::Extended);
+        assert_eq!(streak.record_activity(day(2)), StreakChange::Unchanged);
+        assert_eq!(streak.current_streak, 2);
+        assert_eq!(streak.longest_streak, 2);
+    }
+
+    #[test]
+    fn test_earned_freezes_stop_at_max_but_bought_ones_stay() {
+        let mut streak = UserStreak::new(Uuid::new_v4(), 1);
+        streak.add_freezes(3, 2);
+        assert_eq!(streak.freezes_available, 2);
+
+        streak.freezes_available = 4;
+        streak.add_freezes(1, 2);
+        assert_eq!(streak.freezes_available, 4);
+    }
+
+    #[test]
+    fn test_missed_days_consume_freezes() {
+        let mut streak = UserStreak::new(Uuid::new_v4(), 2);
+        streak.record_activity(day(1));
+
+        assert_eq!(
+            streak.record_activity(day(4)),
+            StreakChange::Frozen { freezes_used: 2 }
+        );
+        assert_eq!(streak.current_streak, 2);
+        assert_eq!(streak.freezes_available, 0);
+    }
+
+    #[test]
+    fn test_gap_larger_than_freezes_resets_but_keeps_longest() {
+        let mut streak = UserStreak::new(Uuid::new_v4(), 1);
+        for d in 1..=3 {
+            streak.record_activity(day(d));
+        }
+
+        assert_eq!(streak.record_activity(day(6)), StreakChange::Reset);
+        assert_eq!(streak.current_streak, 1);
+        assert_eq!(streak.longest_streak, 3);
+        assert_eq!(streak.freezes_available, 1);
+    }
+
+    #[test]
+    fn test_current_as_of_drops_to_zero_once_freezes_cannot_cover() {
+        let mut streak = UserStreak::new(Uuid::new_v4(), 1);
+        streak.record_activity(day(1));
+
+        assert_eq!(streak.current_as_of(day(2)), 1);
+        assert_eq!(streak.current_as_of(day(3)), 1);
+        assert_eq!(streak.current_as_of(day(4)), 0);
+    }
+
+    #[test]
+    fn test_policy_reports_crossed_and_next_milestones() {
+        let policy = StreakPolicy::default();
+
+        assert_eq!(policy.milestones_reached(6, 7)[0].days, 7);
+        assert!(policy.milestones_reached(7, 8).is_empty());
+        assert_eq!(policy.next_milestone(7).map(|m| m.days), Some(30));
+        assert_eq!(policy.next_milestone(100), None);
+    }
+}
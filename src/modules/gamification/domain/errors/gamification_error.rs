@@ -0,0 +1,122 @@
+use crate::shared::domain::base_error::AppError;
+use crate::shared::utils::response::ApiResponse;
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GamificationError {
+    #[error("Mission not found: {0}")]
+    MissionNotFound(String),
+
+    #[error("Achievement not found: {0}")]
+    AchievementNotFound(String),
+
+    #[error("Mission already claimed: {0}")]
+    MissionAlreadyClaimed(String),
+
+    #[error("Mission not completed: {0}")]
+    MissionNotCompleted(String),
+
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
+    #[error("Reward not found: {0}")]
+    RewardNotFound(String),
+
+    #[error("Reward unavailable: {0}")]
+    RewardUnavailable(String),
+
+    #[error("Insufficient points: {0}")]
+    InsufficientPoints(String),
+
+    #[error("Invalid data: {0}")]
+    InvalidData(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository ports report failures as plain strings, so anything bubbling up
+/// through `?` from a repository is treated as a storage failure.
+impl From<String> for GamificationError {
+    fn from(message: String) -> Self {
+        GamificationError::DatabaseError(message)
+    }
+}
+
+/// Shared ports (outbox, unit of work) report `AppError`; for gamification they
+/// are storage failures as well.
+impl From<AppError> for GamificationError {
+    fn from(error: AppError) -> Self {
+        GamificationError::DatabaseError(error.to_string())
+    }
+}
+
+impl IntoResponse for GamificationError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match &self {
+            GamificationError::MissionNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            GamificationError::AchievementNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            GamificationError::MissionAlreadyClaimed(msg) => (StatusCode::CONFLICT, msg.clone()),
+            GamificationError::MissionNotCompleted(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            GamificationError::UserNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            GamificationError::RewardNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            GamificationError::RewardUnavailable(msg) => (StatusCode::CONFLICT, msg.clone()),
+            GamificationError::InsufficientPoints(msg) => (StatusCode::CONFLICT, msg.clone()),
+            GamificationError::InvalidData(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            GamificationError::DatabaseError(msg) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, msg.clone())
+            }
+        };
+
+        let body = ApiResponse::<()>::error(&error_message);
+
+        tracing::error!("GamificationError: {:?}", self);
+
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gamification_error_mission_not_found_maps_to_404() {
+        let error = GamificationError::MissionNotFound("mission_123".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn gamification_error_mission_already_claimed_maps_to_409() {
+        let error = GamificationError::MissionAlreadyClaimed("mission_123".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn gamification_error_mission_not_completed_maps_to_400() {
+        let error = GamificationError::MissionNotCompleted("mission_123".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn gamification_error_insufficient_points_maps_to_409() {
+        let error = GamificationError::InsufficientPoints("reward_123".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn gamification_error_from_repository_string_maps_to_500() {
+        let error: GamificationError = "connection lost".to_string().into();
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
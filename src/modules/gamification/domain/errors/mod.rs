@@ -1 +1,4 @@
 // Gamification errors - AchievementNotFound, MissionAlreadyClaimed
+mod gamification_error;
+
+pub use gamification_error::GamificationError;
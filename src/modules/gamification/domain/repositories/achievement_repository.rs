@@ -2,15 +2,28 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::points_ledger::PointsEntry;
 use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait AchievementRepository: Send + Sync {
+    /// Returns the whole catalog, retired achievements included.
     async fn get_all_achievements(&self) -> Result<Vec<Achievement>, String>;
     async fn get_achievement_by_id(&self, id: Uuid) -> Result<Option<Achievement>, String>;
     async fn get_user_achievements(&self, user_id: Uuid) -> Result<Vec<UserAchievement>, String>;
-    async fn save_user_achievement(&self, user_achievement: &UserAchievement)
-    -> Result<(), String>;
-    async fn add_user_score(&self, user_id: Uuid, points: i32) -> Result<(), String>;
+    /// Upserts all rows in one statement, creating progress for first-time achievements.
+    /// Existing rows keep their showcase placement; see `save_showcase`.
+    async fn save_user_achievements(
+        &self,
+        user_achievements: &[UserAchievement],
+    ) -> Result<(), String>;
+    /// Writes only the showcase placement of rows that already exist.
+    async fn save_showcase(&self, user_achievements: &[UserAchievement]) -> Result<(), String>;
+    /// Appends the awards to the points ledger and moves each user's total score.
+    async fn award_points(&self, entries: &[PointsEntry]) -> Result<(), String>;
+    /// How many recorded quizzes the user has on `article_id`, the current one included.
+    async fn count_article_reads(&self, user_id: Uuid, article_id: Uuid) -> Result<i64, String>;
+    async fn create_achievement(&self, achievement: &Achievement) -> Result<(), String>;
+    async fn update_achievement(&self, achievement: &Achievement) -> Result<(), String>;
 }
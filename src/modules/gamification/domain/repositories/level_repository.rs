@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::level::{Level, LevelUp};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LevelRepository: Send + Sync {
+    async fn get_levels(&self) -> Result<Vec<Level>, String>;
+    /// `None` when the user has no shadow record yet.
+    async fn get_total_score(&self, user_id: Uuid) -> Result<Option<i32>, String>;
+    /// Level-ups the client has not shown yet, lowest level first.
+    async fn get_pending_level_ups(&self, user_id: Uuid) -> Result<Vec<LevelUp>, String>;
+    /// Marks every pending level-up as shown. Returns how many were marked.
+    async fn mark_level_ups_seen(
+        &self,
+        user_id: Uuid,
+        seen_at: DateTime<Utc>,
+    ) -> Result<u64, String>;
+}
@@ -1,23 +1,45 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use uuid::Uuid;
 
 use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::points_ledger::PointsEntry;
 use crate::modules::gamification::domain::entities::user_mission::UserMission;
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait MissionRepository: Send + Sync {
-    async fn get_active_missions_by_date(
+    /// Missions whose window contains `at`, on the user's wall clock.
+    async fn get_active_missions_at(&self, at: NaiveDateTime) -> Result<Vec<DailyMission>, String>;
+    /// Missions active at `at`, each with the user's progress on the instance
+    /// running then, if any.
+    async fn get_user_missions_at(
         &self,
-        date: NaiveDate,
-    ) -> Result<Vec<DailyMission>, String>;
+        user_id: Uuid,
+        at: NaiveDateTime,
+    ) -> Result<Vec<(DailyMission, Option<UserMission>)>, String>;
+    /// Progress on the mission instance starting at `period_start`.
     async fn get_user_mission(
         &self,
         user_id: Uuid,
         mission_id: Uuid,
+        period_start: NaiveDateTime,
     ) -> Result<Option<UserMission>, String>;
     async fn save_user_mission(&self, user_mission: &UserMission) -> Result<(), String>;
+    /// Marks the instance starting at `period_start` as claimed if its progress
+    /// reached `target_count` and it was not claimed yet. Returns whether this
+    /// call claimed it, so of concurrent claims exactly one gets `true`.
+    async fn claim_user_mission(
+        &self,
+        user_id: Uuid,
+        mission_id: Uuid,
+        period_start: NaiveDateTime,
+        target_count: i32,
+    ) -> Result<bool, String>;
     async fn get_daily_mission_by_id(&self, id: Uuid) -> Result<Option<DailyMission>, String>;
-    async fn add_user_score(&self, user_id: Uuid, points: i32) -> Result<(), String>;
+    /// Whether the user has been synced into `shadow_users` and can hold mission
+    /// progress.
+    async fn user_exists(&self, user_id: Uuid) -> Result<bool, String>;
+    /// Appends the awards to the points ledger and moves each user's total score.
+    async fn award_points(&self, entries: &[PointsEntry]) -> Result<(), String>;
 }
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait MissionTemplateRepository: Send + Sync {
+    async fn get_templates(&self) -> Result<Vec<MissionTemplate>, String>;
+    async fn create_template(&self, template: &MissionTemplate) -> Result<(), String>;
+    /// Daily missions dated between `from` and `to`, both inclusive.
+    async fn get_missions_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyMission>, String>;
+    /// Inserts generated missions, skipping any (date, template) pair that
+    /// already exists. Returns how many rows were actually written.
+    async fn insert_daily_missions(&self, missions: &[DailyMission]) -> Result<u64, String>;
+}
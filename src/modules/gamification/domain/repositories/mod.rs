@@ -1,6 +1,16 @@
 // Gamification repositories - Ports for achievement/mission persistence
 pub mod achievement_repository;
+pub mod level_repository;
 pub mod mission_repository;
+pub mod mission_template_repository;
+pub mod points_ledger_repository;
+pub mod reward_shop_repository;
+pub mod streak_repository;
 
 pub use achievement_repository::AchievementRepository;
+pub use level_repository::LevelRepository;
 pub use mission_repository::MissionRepository;
+pub use mission_template_repository::MissionTemplateRepository;
+pub use points_ledger_repository::PointsLedgerRepository;
+pub use reward_shop_repository::RewardShopRepository;
+pub use streak_repository::StreakRepository;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::points_ledger::{BalanceMismatch, LedgerEntry};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PointsLedgerRepository: Send + Sync {
+    /// `None` when the user has no shadow record yet.
+    async fn get_total_score(&self, user_id: Uuid) -> Result<Option<i32>, String>;
+    /// The user's entries, newest first, starting below the `before` entry id.
+    async fn get_user_entries(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        before: Option<i64>,
+    ) -> Result<Vec<LedgerEntry>, String>;
+    /// Users whose `total_score` differs from the sum of their ledger.
+    async fn find_balance_mismatches(&self) -> Result<Vec<BalanceMismatch>, String>;
+    /// Resets every drifted `total_score` to its ledger sum. Returns the
+    /// balances as they were before the reset.
+    async fn rebuild_balances(&self) -> Result<Vec<BalanceMismatch>, String>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::reward_item::{PurchaseRejection, RewardItem};
+use crate::modules::gamification::domain::entities::reward_purchase::{
+    InventoryItem, RewardPurchase,
+};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait RewardShopRepository: Send + Sync {
+    /// The shop catalog; items taken off sale only when `include_inactive`.
+    async fn get_items(&self, include_inactive: bool) -> Result<Vec<RewardItem>, String>;
+    async fn create_item(&self, item: &RewardItem) -> Result<(), String>;
+    /// Buys one `item_id` for the user in a single transaction: checks the shop
+    /// rules against the locked balance and stock, records the purchase, takes
+    /// one from stock, adds the item to the inventory and debits the price from
+    /// the points ledger. A streak freeze is credited to the user's streak in
+    /// the same transaction. Concurrent purchases are serialized per user and
+    /// per item, so a balance never goes negative and stock is never oversold.
+    async fn purchase(
+        &self,
+        user_id: Uuid,
+        item_id: Uuid,
+        purchase_id: Uuid,
+        purchased_at: DateTime<Utc>,
+    ) -> Result<Result<RewardPurchase, PurchaseRejection>, String>;
+    async fn get_inventory(&self, user_id: Uuid) -> Result<Vec<InventoryItem>, String>;
+    /// Newest first.
+    async fn get_purchases(&self, user_id: Uuid) -> Result<Vec<RewardPurchase>, String>;
+}
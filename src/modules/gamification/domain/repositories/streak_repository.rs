@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::user_streak::UserStreak;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait StreakRepository: Send + Sync {
+    /// `None` until the user's first recorded activity.
+    async fn get_streak(&self, user_id: Uuid) -> Result<Option<UserStreak>, String>;
+    async fn save_streak(&self, streak: &UserStreak) -> Result<(), String>;
+}
@@ -1 +1,2 @@
 // Gamification database - SQLx implementations
+pub mod postgres;
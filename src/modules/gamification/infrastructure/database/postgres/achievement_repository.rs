@@ -1,169 +1,238 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
-use std::str::FromStr;
 
-use crate::modules::gamification::domain::ports::achievement_repository::AchievementRepository;
-use crate::modules::gamification::domain::entities::achievement::{Achievement, AchievementType, UserAchievement};
+use super::level_repository::record_points;
+use super::mappers::achievement_mapper::{
+    AchievementMapper, AchievementRow, UserAchievementMapper, UserAchievementRow,
+};
+use crate::modules::gamification::domain::entities::achievement::Achievement;
+use crate::modules::gamification::domain::entities::points_ledger::PointsEntry;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+use crate::modules::gamification::domain::repositories::AchievementRepository;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
 
 pub struct PostgresAchievementRepository {
-    pub pool: PgPool,
+    db: PgHandle,
 }
 
 impl PostgresAchievementRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, String> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| format!("Database error: {}", e))
     }
 }
 
+fn criteria_json(achievement: &Achievement) -> Result<String, String> {
+    serde_json::to_string(achievement.criteria())
+        .map_err(|e| format!("Gagal menyimpan kriteria achievement: {}", e))
+}
+
 #[async_trait]
 impl AchievementRepository for PostgresAchievementRepository {
-    
     async fn get_achievement_by_id(&self, id: Uuid) -> Result<Option<Achievement>, String> {
-        let record = sqlx::query!(
-            r#"
-            SELECT id, name, milestone_target, achievement_type, reward_points 
-            FROM achievements 
-            WHERE id = $1
-            "#,
-            id
+        let mut conn = self.conn().await?;
+        let row = sqlx::query_as::<_, AchievementRow>(
+            "SELECT id, name, milestone_target, achievement_type, reward_points, is_retired, retired_at, criteria::text AS criteria, chain, chain_tier FROM achievements WHERE id = $1",
         )
-        .fetch_optional(&self.pool)
+        .bind(id)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| format!("Database error (get_achievement_by_id): {}", e))?;
 
-        match record {
-            Some(row) => {
-                // Mapping manual string dari database kembali ke Enum Rust
-                let ach_type = match row.achievement_type.as_str() {
-                    "Rare" => AchievementType::Rare,
-                    "Epic" => AchievementType::Epic,
-                    "Legendary" => AchievementType::Legendary,
-                    _ => AchievementType::Common, // Default
-                };
-
-                let achievement = Achievement::new(
-                    row.id, 
-                    row.name, 
-                    row.milestone_target, 
-                    ach_type, 
-                    row.reward_points
-                ).map_err(|e| e.to_string())?;
-
-                Ok(Some(achievement))
-            },
-            None => Ok(None)
-        }
+        row.as_ref().map(AchievementMapper::from_row).transpose()
     }
 
     async fn get_user_achievements(&self, user_id: Uuid) -> Result<Vec<UserAchievement>, String> {
-        let records = sqlx::query!(
-            r#"
-            SELECT user_id, achievement_id, current_progress, is_completed, is_shown_on_profile, completed_at 
-            FROM user_achievements 
-            WHERE user_id = $1
-            "#,
-            user_id
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, UserAchievementRow>(
+            "SELECT user_id, achievement_id, current_progress, is_completed, is_shown_on_profile, completed_at, showcase_position FROM user_achievements WHERE user_id = $1",
         )
-        .fetch_all(&self.pool)
+        .bind(user_id)
+        .fetch_all(&mut *conn)
         .await
         .map_err(|e| format!("Database error (get_user_achievements): {}", e))?;
 
-        let mut achievements = Vec::new();
-        
-        for row in records {
-            // Karena field di struct UserAchievement bersifat 'pub', 
-            // kita bisa langsung merekonstruksi state masa lalunya dari database.
-            let mut user_ach = UserAchievement::new(row.user_id, row.achievement_id);
-            user_ach.current_progress = row.current_progress;
-            user_ach.is_completed = row.is_completed;
-            user_ach.is_shown_on_profile = row.is_shown_on_profile;
-            user_ach.completed_at = row.completed_at;
-
-            achievements.push(user_ach);
+        Ok(UserAchievementMapper::from_rows(&rows))
+    }
+
+    async fn save_user_achievements(
+        &self,
+        user_achievements: &[UserAchievement],
+    ) -> Result<(), String> {
+        if user_achievements.is_empty() {
+            return Ok(());
         }
 
-        Ok(achievements)
-    }
+        let mut user_ids = Vec::with_capacity(user_achievements.len());
+        let mut achievement_ids = Vec::with_capacity(user_achievements.len());
+        let mut progresses = Vec::with_capacity(user_achievements.len());
+        let mut completed = Vec::with_capacity(user_achievements.len());
+        let mut shown = Vec::with_capacity(user_achievements.len());
+        let mut completed_ats = Vec::with_capacity(user_achievements.len());
+        let mut positions = Vec::with_capacity(user_achievements.len());
+        for ua in user_achievements {
+            user_ids.push(ua.user_id());
+            achievement_ids.push(ua.achievement_id());
+            progresses.push(ua.current_progress());
+            completed.push(ua.is_completed());
+            shown.push(ua.is_shown_on_profile());
+            completed_ats.push(ua.completed_at());
+            positions.push(ua.showcase_position());
+        }
 
-    async fn save_user_achievement(&self, user_achievement: &UserAchievement) -> Result<(), String> {
-        // UPSERT: Insert jika baru pertama kali dapat progres, Update jika sudah ada
-        sqlx::query!(
-            r#"
-            INSERT INTO user_achievements 
-            (user_id, achievement_id, current_progress, is_completed, is_shown_on_profile, completed_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            ON CONFLICT (user_id, achievement_id) 
-            DO UPDATE SET 
-                current_progress = EXCLUDED.current_progress,
-                is_completed = EXCLUDED.is_completed,
-                is_shown_on_profile = EXCLUDED.is_shown_on_profile,
-                completed_at = EXCLUDED.completed_at
-            "#,
-            user_achievement.user_id(),
-            user_achievement.achievement_id(),
-            user_achievement.current_progress(),
-            user_achievement.is_completed(),
-            user_achievement.is_shown_on_profile(),
-            user_achievement.completed_at() // Ini Option<DateTime<Utc>>, sqlx bisa handle otomatis ke format NULL di DB
+        // UPSERT: Insert jika baru pertama kali dapat progres, Update jika sudah ada.
+        // Posisi showcase hanya diubah lewat save_showcase.
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "INSERT INTO user_achievements (user_id, achievement_id, current_progress, is_completed, is_shown_on_profile, completed_at, showcase_position) \
+             SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::int[], $4::bool[], $5::bool[], $6::timestamptz[], $7::int[]) \
+             ON CONFLICT (user_id, achievement_id) DO UPDATE SET \
+                current_progress = EXCLUDED.current_progress, \
+                is_completed = EXCLUDED.is_completed, \
+                completed_at = EXCLUDED.completed_at",
         )
-        .execute(&self.pool)
+        .bind(&user_ids)
+        .bind(&achievement_ids)
+        .bind(&progresses)
+        .bind(&completed)
+        .bind(&shown)
+        .bind(&completed_ats)
+        .bind(&positions)
+        .execute(&mut *conn)
         .await
         .map_err(|e| format!("Gagal menyimpan progres pencapaian: {}", e))?;
 
         Ok(())
     }
 
-    async fn add_user_score(&self, user_id: Uuid, points: i32) -> Result<(), String> {
-        // Query ini persis sama dengan yang ada di mission_repository, 
-        // tapi ditaruh di sini agar AchievementUseCase tetap independen.
-        sqlx::query!(
-            r#"
-            UPDATE engine_users 
-            SET total_score = total_score + $1 
-            WHERE user_id = $2
-            "#,
-            points,
-            user_id
+    async fn save_showcase(&self, user_achievements: &[UserAchievement]) -> Result<(), String> {
+        if user_achievements.is_empty() {
+            return Ok(());
+        }
+
+        let user_ids: Vec<Uuid> = user_achievements.iter().map(|ua| ua.user_id()).collect();
+        let achievement_ids: Vec<Uuid> = user_achievements
+            .iter()
+            .map(|ua| ua.achievement_id())
+            .collect();
+        let shown: Vec<bool> = user_achievements
+            .iter()
+            .map(|ua| ua.is_shown_on_profile())
+            .collect();
+        let positions: Vec<Option<i32>> = user_achievements
+            .iter()
+            .map(|ua| ua.showcase_position())
+            .collect();
+
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "UPDATE user_achievements ua SET \
+                is_shown_on_profile = s.is_shown_on_profile, \
+                showcase_position = s.showcase_position \
+             FROM UNNEST($1::uuid[], $2::uuid[], $3::bool[], $4::int[]) \
+                AS s(user_id, achievement_id, is_shown_on_profile, showcase_position) \
+             WHERE ua.user_id = s.user_id AND ua.achievement_id = s.achievement_id",
         )
-        .execute(&self.pool)
+        .bind(&user_ids)
+        .bind(&achievement_ids)
+        .bind(&shown)
+        .bind(&positions)
+        .execute(&mut *conn)
         .await
-        .map_err(|e| format!("Gagal menambah skor user dari pencapaian: {}", e))?;
+        .map_err(|e| format!("Gagal menyimpan showcase: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn award_points(&self, entries: &[PointsEntry]) -> Result<(), String> {
+        // Skor user yang tampil ke client disimpan di shadow_users (lihat modul user_sync).
+        let mut conn = self.conn().await?;
+        record_points(&mut conn, entries)
+            .await
+            .map_err(|e| format!("Gagal menambah skor user dari pencapaian: {}", e))?;
 
         Ok(())
     }
 
+    async fn count_article_reads(&self, user_id: Uuid, article_id: Uuid) -> Result<i64, String> {
+        let mut conn = self.conn().await?;
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM quiz_history WHERE user_id = $1 AND article_id = $2",
+        )
+        .bind(user_id)
+        .bind(article_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("Database error (count_article_reads): {}", e))
+    }
+
     async fn get_all_achievements(&self) -> Result<Vec<Achievement>, String> {
-        let records = sqlx::query!(
-            r#"
-            SELECT id, name, milestone_target, achievement_type, reward_points 
-            FROM achievements
-            "#
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, AchievementRow>(
+            "SELECT id, name, milestone_target, achievement_type, reward_points, is_retired, retired_at, criteria::text AS criteria, chain, chain_tier FROM achievements ORDER BY milestone_target, name",
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *conn)
         .await
         .map_err(|e| format!("Database error (get_all_achievements): {}", e))?;
 
-        let mut achievements = Vec::new();
-        for row in records {
-            let ach_type = match row.achievement_type.as_str() {
-                "Rare" => AchievementType::Rare,
-                "Epic" => AchievementType::Epic,
-                "Legendary" => AchievementType::Legendary,
-                _ => AchievementType::Common,
-            };
-
-            if let Ok(achievement) = Achievement::new(
-                row.id, 
-                row.name, 
-                row.milestone_target, 
-                ach_type, 
-                row.reward_points
-            ) {
-                achievements.push(achievement);
-            }
-        }
+        Ok(AchievementMapper::from_rows(&rows))
+    }
+
+    async fn create_achievement(&self, achievement: &Achievement) -> Result<(), String> {
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "INSERT INTO achievements (id, name, milestone_target, achievement_type, reward_points, is_retired, retired_at, criteria, chain, chain_tier) VALUES ($1, $2, $3, $4, $5, $6, $7, $8::jsonb, $9, $10)",
+        )
+        .bind(achievement.id())
+        .bind(achievement.name())
+        .bind(achievement.milestone_target())
+        .bind(achievement.achievement_type().to_string())
+        .bind(achievement.reward_points())
+        .bind(achievement.is_retired())
+        .bind(achievement.retired_at())
+        .bind(criteria_json(achievement)?)
+        .bind(achievement.chain().map(|link| link.chain.as_str()))
+        .bind(achievement.chain().map(|link| link.tier))
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Gagal menyimpan achievement: {}", e))?;
 
-        Ok(achievements)
+        Ok(())
+    }
+
+    async fn update_achievement(&self, achievement: &Achievement) -> Result<(), String> {
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "UPDATE achievements SET name = $2, milestone_target = $3, achievement_type = $4, reward_points = $5, is_retired = $6, retired_at = $7, criteria = $8::jsonb, chain = $9, chain_tier = $10 WHERE id = $1",
+        )
+        .bind(achievement.id())
+        .bind(achievement.name())
+        .bind(achievement.milestone_target())
+        .bind(achievement.achievement_type().to_string())
+        .bind(achievement.reward_points())
+        .bind(achievement.is_retired())
+        .bind(achievement.retired_at())
+        .bind(criteria_json(achievement)?)
+        .bind(achievement.chain().map(|link| link.chain.as_str()))
+        .bind(achievement.chain().map(|link| link.tier))
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Gagal memperbarui achievement: {}", e))?;
+
+        Ok(())
     }
-}
\ No newline at end of file
+}
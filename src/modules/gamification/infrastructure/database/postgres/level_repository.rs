@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::mappers::level_mapper::{LevelMapper, LevelRow, LevelUpRow};
+use crate::modules::gamification::domain::entities::level::{Level, LevelUp};
+use crate::modules::gamification::domain::entities::points_ledger::PointsEntry;
+use crate::modules::gamification::domain::repositories::level_repository::LevelRepository;
+use crate::shared::domain::outbox::EngineEvent;
+use crate::shared::infrastructure::database::outbox_repository::enqueue_events;
+
+pub struct PostgresLevelRepository {
+    pool: PgPool,
+}
+
+impl PostgresLevelRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// What `record_points` wrote.
+#[derive(Debug, Default)]
+pub struct RecordedPoints {
+    /// `(user_id, level)` for every level reached.
+    pub level_ups: Vec<(Uuid, i32)>,
+    /// Non-zero entries skipped because their user has no `shadow_users` row.
+    pub dropped: usize,
+}
+
+/// Appends `entries` to the points ledger and, in the same statement, moves
+/// each user's `total_score` projection and records every level of the curve
+/// the new total crosses. Every score change goes through here so the ledger,
+/// the total and level-ups cannot drift apart. Each level reached is announced
+/// as a `level_up` outbox event in the same transaction. Zero deltas are
+/// skipped; entries for users without a `shadow_users` row are skipped and
+/// counted in `dropped`. Runs in a savepoint when `conn` is inside a
+/// transaction, so it can record points alongside other writes.
+pub async fn record_points(
+    conn: &mut PgConnection,
+    entries: &[PointsEntry],
+) -> Result<RecordedPoints, sqlx::Error> {
+    let expected = entries.iter().filter(|e| e.delta != 0).count();
+    if expected == 0 {
+        return Ok(RecordedPoints::default());
+    }
+
+    let user_ids: Vec<Uuid> = entries.iter().map(|e| e.user_id).collect();
+    let deltas: Vec<i32> = entries.iter().map(|e| e.delta).collect();
+    let sources: Vec<&str> = entries.iter().map(|e| e.source.as_str()).collect();
+    let source_ids: Vec<Option<Uuid>> = entries.iter().map(|e| e.source_id).collect();
+    let occurred_at: Vec<DateTime<Utc>> = entries.iter().map(|e| e.occurred_at).collect();
+
+    let mut tx = conn.begin().await?;
+    // One row even when no level is reached, so the recorded count always comes back.
+    let rows = sqlx::query_as::<_, (i64, Option<Uuid>, Option<i32>, Option<String>)>(
+        "WITH recorded AS ( \
+             INSERT INTO points_ledger (user_id, delta, source_type, source_id, occurred_at) \
+             SELECT e.user_id, e.delta, e.source_type, e.source_id, e.occurred_at \
+             FROM UNNEST($1::uuid[], $2::int[], $3::text[], $4::uuid[], $5::timestamptz[]) \
+                 AS e(user_id, delta, source_type, source_id, occurred_at) \
+             JOIN shadow_users s ON s.user_id = e.user_id \
+             WHERE e.delta <> 0 \
+             RETURNING user_id, delta \
+         ), totals AS ( \
+             SELECT user_id, SUM(delta)::int AS delta FROM recorded GROUP BY user_id \
+         ), updated AS ( \
+             UPDATE shadow_users s SET total_score = s.total_score + t.delta \
+             FROM totals t WHERE s.user_id = t.user_id \
+             RETURNING s.user_id, s.total_score, s.total_score - t.delta AS previous_score \
+         ), reached AS ( \
+             INSERT INTO user_level_ups (user_id, level, title, total_score) \
+             SELECT u.user_id, l.level, l.title, u.total_score FROM updated u \
+             JOIN levels l ON l.min_score > u.previous_score AND l.min_score <= u.total_score \
+             ON CONFLICT (user_id, level) DO NOTHING \
+             RETURNING user_id, level, title \
+         ) \
+         SELECT (SELECT COUNT(*) FROM recorded), r.user_id, r.level, r.title \
+         FROM (SELECT 1) AS one LEFT JOIN reached r ON TRUE",
+    )
+    .bind(&user_ids)
+    .bind(&deltas)
+    .bind(&sources)
+    .bind(&source_ids)
+    .bind(&occurred_at)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let recorded = rows.first().map_or(0, |(count, ..)| *count as usize);
+    let reached: Vec<(Uuid, i32, String)> = rows
+        .into_iter()
+        .filter_map(|(_, user_id, level, title)| Some((user_id?, level?, title?)))
+        .collect();
+    let events: Vec<EngineEvent> = reached
+        .iter()
+        .map(|(user_id, level, title)| EngineEvent::LevelUp {
+            user_id: *user_id,
+            level: *level,
+            title: title.clone(),
+        })
+        .collect();
+    let latest = occurred_at.iter().copied().max().unwrap_or_else(Utc::now);
+    enqueue_events(&mut tx, &events, latest).await?;
+    tx.commit().await?;
+
+    for (user_id, level, _) in &reached {
+        tracing::info!(user_id = %user_id, level, "User naik level");
+    }
+    let dropped = expected.saturating_sub(recorded);
+    if dropped > 0 {
+        tracing::warn!(
+            dropped,
+            "Poin untuk user yang belum tersinkron ke shadow_users tidak dicatat"
+        );
+    }
+    let level_ups = reached
+        .into_iter()
+        .map(|(user_id, level, _)| (user_id, level))
+        .collect();
+
+    Ok(RecordedPoints { level_ups, dropped })
+}
+
+#[async_trait]
+impl LevelRepository for PostgresLevelRepository {
+    async fn get_levels(&self) -> Result<Vec<Level>, String> {
+        let rows = sqlx::query_as::<_, LevelRow>(
+            "SELECT level, title, min_score FROM levels ORDER BY min_score",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_levels): {}", e))?;
+
+        Ok(LevelMapper::from_rows(&rows))
+    }
+
+    async fn get_total_score(&self, user_id: Uuid) -> Result<Option<i32>, String> {
+        sqlx::query_scalar::<_, i32>("SELECT total_score FROM shadow_users WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Database error (get_total_score): {}", e))
+    }
+
+    async fn get_pending_level_ups(&self, user_id: Uuid) -> Result<Vec<LevelUp>, String> {
+        let rows = sqlx::query_as::<_, LevelUpRow>(
+            "SELECT user_id, level, title, total_score, reached_at, seen_at FROM user_level_ups \
+             WHERE user_id = $1 AND seen_at IS NULL ORDER BY level",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_pending_level_ups): {}", e))?;
+
+        Ok(LevelMapper::level_ups_from_rows(&rows))
+    }
+
+    async fn mark_level_ups_seen(
+        &self,
+        user_id: Uuid,
+        seen_at: DateTime<Utc>,
+    ) -> Result<u64, String> {
+        let result = sqlx::query(
+            "UPDATE user_level_ups SET seen_at = $2 WHERE user_id = $1 AND seen_at IS NULL",
+        )
+        .bind(user_id)
+        .bind(seen_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Gagal menandai level-up: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,204 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::achievement::{Achievement, AchievementType};
+use crate::modules::gamification::domain::entities::achievement_chain::ChainLink;
+use crate::modules::gamification::domain::entities::achievement_criteria::AchievementCriteria;
+use crate::modules::gamification::domain::entities::user_achievement::UserAchievement;
+
+pub struct AchievementMapper;
+
+#[derive(FromRow)]
+pub struct AchievementRow {
+    pub id: Uuid,
+    pub name: String,
+    pub milestone_target: i32,
+    pub achievement_type: String,
+    pub reward_points: i32,
+    pub is_retired: bool,
+    pub retired_at: Option<DateTime<Utc>>,
+    /// `criteria` JSONB column, selected as text.
+    pub criteria: String,
+    pub chain: Option<String>,
+    pub chain_tier: Option<i32>,
+}
+
+impl AchievementMapper {
+    pub fn from_row(row: &AchievementRow) -> Result<Achievement, String> {
+        let mut achievement = Achievement::new(
+            row.id,
+            row.name.clone(),
+            row.milestone_target,
+            AchievementTypeMapper::from_db_str(&row.achievement_type),
+            row.reward_points,
+        )
+        .map_err(|e| e.to_string())?;
+        achievement.is_retired = row.is_retired;
+        achievement.retired_at = row.retired_at;
+        achievement.criteria = serde_json::from_str::<AchievementCriteria>(&row.criteria)
+            .map_err(|e| format!("Kriteria achievement {} tidak valid: {}", row.id, e))?;
+        if let (Some(chain), Some(tier)) = (&row.chain, row.chain_tier) {
+            achievement.chain =
+                Some(ChainLink::new(chain.clone(), tier).map_err(|e| e.to_string())?);
+        }
+        Ok(achievement)
+    }
+
+    pub fn from_rows(rows: &[AchievementRow]) -> Vec<Achievement> {
+        rows.iter().filter_map(|r| Self::from_row(r).ok()).collect()
+    }
+}
+
+pub struct UserAchievementMapper;
+
+#[derive(FromRow)]
+pub struct UserAchievementRow {
+    pub user_id: Uuid,
+    pub achievement_id: Uuid,
+    pub current_progress: i32,
+    pub is_completed: bool,
+    pub is_shown_on_profile: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub showcase_position: Option<i32>,
+}
+
+impl UserAchievementMapper {
+    pub fn from_row(row: &UserAchievementRow) -> UserAchievement {
+        let mut user_achievement = UserAchievement::new(row.user_id, row.achievement_id);
+        user_achievement.current_progress = row.current_progress;
+        user_achievement.is_completed = row.is_completed;
+        user_achievement.is_shown_on_profile = row.is_shown_on_profile;
+        user_achievement.completed_at = row.completed_at;
+        user_achievement.showcase_position = row.showcase_position;
+        user_achievement
+    }
+
+    pub fn from_rows(rows: &[UserAchievementRow]) -> Vec<UserAchievement> {
+        rows.iter().map(Self::from_row).collect()
+    }
+}
+
+pub struct AchievementTypeMapper;
+
+impl AchievementTypeMapper {
+    pub fn from_db_str(s: &str) -> AchievementType {
+        s.parse().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_achievement_type_mapper_falls_back_to_common() {
+        assert_eq!(
+            AchievementTypeMapper::from_db_str("Legendary"),
+            AchievementType::Legendary
+        );
+        assert_eq!(
+            AchievementTypeMapper::from_db_str("unknown"),
+            AchievementType::Common
+        );
+    }
+
+    #[test]
+    fn test_achievement_mapper_skips_invalid_rows() {
+        let rows = vec![
+            AchievementRow {
+                id: Uuid::new_v4(),
+                name: "Kutu Buku".to_string(),
+                milestone_target: 10,
+                achievement_type: "Rare".to_string(),
+                reward_points: 100,
+                is_retired: false,
+                retired_at: None,
+                criteria: r#"{"type": "quizzes_completed"}"#.to_string(),
+                chain: None,
+                chain_tier: None,
+            },
+            AchievementRow {
+                id: Uuid::new_v4(),
+                name: "".to_string(),
+                milestone_target: 10,
+                achievement_type: "Rare".to_string(),
+                reward_points: 100,
+                is_retired: false,
+                retired_at: None,
+                criteria: r#"{"type": "quizzes_completed"}"#.to_string(),
+                chain: None,
+                chain_tier: None,
+            },
+        ];
+
+        let achievements = AchievementMapper::from_rows(&rows);
+        assert_eq!(achievements.len(), 1);
+        assert_eq!(achievements[0].name(), "Kutu Buku");
+    }
+
+    #[test]
+    fn test_achievement_mapper_restores_retirement_and_chain() {
+        let retired_at = Utc::now();
+        let row = AchievementRow {
+            id: Uuid::new_v4(),
+            name: "Musim Lalu".to_string(),
+            milestone_target: 1,
+            achievement_type: "Epic".to_string(),
+            reward_points: 10,
+            is_retired: true,
+            retired_at: Some(retired_at),
+            criteria: r#"{"type": "perfect_quizzes"}"#.to_string(),
+            chain: Some("Sempurna".to_string()),
+            chain_tier: Some(2),
+        };
+
+        let achievement = AchievementMapper::from_row(&row).unwrap();
+        assert!(achievement.is_retired());
+        assert_eq!(achievement.retired_at(), Some(retired_at));
+        assert_eq!(achievement.criteria(), &AchievementCriteria::PerfectQuizzes);
+        assert_eq!(
+            achievement.chain(),
+            Some(&ChainLink::new("Sempurna".to_string(), 2).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_achievement_mapper_rejects_unknown_criteria() {
+        let row = AchievementRow {
+            id: Uuid::new_v4(),
+            name: "Rahasia".to_string(),
+            milestone_target: 1,
+            achievement_type: "Common".to_string(),
+            reward_points: 10,
+            is_retired: false,
+            retired_at: None,
+            criteria: r#"{"type": "time_travel"}"#.to_string(),
+            chain: None,
+            chain_tier: None,
+        };
+
+        assert!(AchievementMapper::from_row(&row).is_err());
+    }
+
+    #[test]
+    fn test_user_achievement_mapper_restores_state() {
+        let completed_at = Utc::now();
+        let row = UserAchievementRow {
+            user_id: Uuid::new_v4(),
+            achievement_id: Uuid::new_v4(),
+            current_progress: 5,
+            is_completed: true,
+            is_shown_on_profile: true,
+            completed_at: Some(completed_at),
+            showcase_position: Some(2),
+        };
+
+        let user_achievement = UserAchievementMapper::from_row(&row);
+        assert_eq!(user_achievement.current_progress(), 5);
+        assert!(user_achievement.is_completed());
+        assert!(user_achievement.is_shown_on_profile());
+        assert_eq!(user_achievement.completed_at(), Some(completed_at));
+        assert_eq!(user_achievement.showcase_position(), Some(2));
+    }
+}
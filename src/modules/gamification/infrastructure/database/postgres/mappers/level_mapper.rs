@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::level::{Level, LevelUp};
+
+pub struct LevelMapper;
+
+#[derive(FromRow)]
+pub struct LevelRow {
+    pub level: i32,
+    pub title: String,
+    pub min_score: i32,
+}
+
+#[derive(FromRow)]
+pub struct LevelUpRow {
+    pub user_id: Uuid,
+    pub level: i32,
+    pub title: String,
+    pub total_score: i32,
+    pub reached_at: DateTime<Utc>,
+    pub seen_at: Option<DateTime<Utc>>,
+}
+
+impl LevelMapper {
+    pub fn from_rows(rows: &[LevelRow]) -> Vec<Level> {
+        rows.iter()
+            .map(|row| Level {
+                level: row.level,
+                title: row.title.clone(),
+                min_score: row.min_score,
+            })
+            .collect()
+    }
+
+    pub fn level_ups_from_rows(rows: &[LevelUpRow]) -> Vec<LevelUp> {
+        rows.iter()
+            .map(|row| LevelUp {
+                user_id: row.user_id,
+                level: row.level,
+                title: row.title.clone(),
+                total_score: row.total_score,
+                reached_at: row.reached_at,
+                seen_at: row.seen_at,
+            })
+            .collect()
+    }
+}
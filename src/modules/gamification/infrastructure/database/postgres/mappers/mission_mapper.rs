@@ -0,0 +1,184 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::daily_mission::{
+    DailyMission, MissionType, QuizConditions,
+};
+use crate::modules::gamification::domain::entities::mission_period::{
+    MissionPeriod, MissionWindow,
+};
+use crate::modules::gamification::domain::entities::user_mission::UserMission;
+
+pub struct DailyMissionMapper;
+
+#[derive(FromRow)]
+pub struct DailyMissionRow {
+    pub id: Uuid,
+    pub description: String,
+    pub target_count: i32,
+    pub date: NaiveDate,
+    pub reward_points: i32,
+    pub mission_type: String,
+    pub template_id: Option<Uuid>,
+    pub min_score: Option<i32>,
+    pub min_accuracy: Option<f64>,
+    pub period: String,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
+
+impl DailyMissionMapper {
+    pub fn from_row(row: &DailyMissionRow) -> Result<DailyMission, String> {
+        let window = MissionWindow::new(row.starts_at, row.ends_at).map_err(|e| e.to_string())?;
+        let mut mission = DailyMission::new(
+            row.id,
+            row.description.clone(),
+            row.target_count,
+            row.date,
+            row.reward_points,
+            MissionTypeMapper::from_db_str(&row.mission_type),
+        )
+        .map_err(|e| e.to_string())?
+        .with_period(MissionPeriodMapper::from_db_str(&row.period), window);
+        mission.template_id = row.template_id;
+        mission.quiz_conditions = QuizConditions {
+            min_score: row.min_score,
+            min_accuracy: row.min_accuracy,
+        };
+        Ok(mission)
+    }
+
+    pub fn from_rows(rows: &[DailyMissionRow]) -> Vec<DailyMission> {
+        rows.iter().filter_map(|r| Self::from_row(r).ok()).collect()
+    }
+}
+
+pub struct UserMissionMapper;
+
+#[derive(FromRow)]
+pub struct UserMissionRow {
+    pub user_id: Uuid,
+    pub mission_id: Uuid,
+    pub period_start: NaiveDateTime,
+    pub current_progress: i32,
+    pub is_claimed: bool,
+}
+
+impl UserMissionMapper {
+    pub fn from_row(row: &UserMissionRow) -> UserMission {
+        let mut user_mission = UserMission::new(row.user_id, row.mission_id, row.period_start);
+        user_mission.current_progress = row.current_progress;
+        user_mission.is_claimed = row.is_claimed;
+        user_mission
+    }
+}
+
+pub struct MissionTypeMapper;
+
+impl MissionTypeMapper {
+    pub fn from_db_str(s: &str) -> MissionType {
+        match s {
+            "Quiz" => MissionType::Quiz,
+            "DailyLogin" => MissionType::DailyLogin,
+            _ => MissionType::ReadArticle,
+        }
+    }
+
+    pub fn to_db_str(mission_type: &MissionType) -> &'static str {
+        match mission_type {
+            MissionType::ReadArticle => "ReadArticle",
+            MissionType::Quiz => "Quiz",
+            MissionType::DailyLogin => "DailyLogin",
+        }
+    }
+}
+
+pub struct MissionPeriodMapper;
+
+impl MissionPeriodMapper {
+    pub fn from_db_str(s: &str) -> MissionPeriod {
+        match s {
+            "Weekly" => MissionPeriod::Weekly,
+            "Custom" => MissionPeriod::Custom,
+            _ => MissionPeriod::Daily,
+        }
+    }
+
+    pub fn to_db_str(period: &MissionPeriod) -> &'static str {
+        match period {
+            MissionPeriod::Daily => "Daily",
+            MissionPeriod::Weekly => "Weekly",
+            MissionPeriod::Custom => "Custom",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mission_type_mapper_round_trip() {
+        for mission_type in [
+            MissionType::ReadArticle,
+            MissionType::Quiz,
+            MissionType::DailyLogin,
+        ] {
+            let db_value = MissionTypeMapper::to_db_str(&mission_type);
+            assert_eq!(MissionTypeMapper::from_db_str(db_value), mission_type);
+        }
+    }
+
+    #[test]
+    fn test_mission_type_mapper_defaults_to_read_article() {
+        assert_eq!(
+            MissionTypeMapper::from_db_str("unknown"),
+            MissionType::ReadArticle
+        );
+    }
+
+    #[test]
+    fn test_daily_mission_mapper_from_row() {
+        let row = DailyMissionRow {
+            id: Uuid::new_v4(),
+            description: "Kerjakan 2 Kuis".to_string(),
+            target_count: 2,
+            date: NaiveDate::from_ymd_opt(2026, 4, 20).unwrap(),
+            reward_points: 75,
+            mission_type: "Quiz".to_string(),
+            template_id: None,
+            min_score: Some(60),
+            min_accuracy: None,
+            period: "Weekly".to_string(),
+            starts_at: NaiveDate::from_ymd_opt(2026, 4, 20)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            ends_at: NaiveDate::from_ymd_opt(2026, 4, 27)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        };
+
+        let mission = DailyMissionMapper::from_row(&row).unwrap();
+        assert_eq!(mission.id(), row.id);
+        assert_eq!(mission.mission_type(), MissionType::Quiz);
+        assert_eq!(mission.reward_points(), 75);
+        assert_eq!(mission.quiz_conditions().min_score, Some(60));
+        assert_eq!(mission.period(), MissionPeriod::Weekly);
+        assert_eq!(mission.window().ends_at, row.ends_at);
+    }
+
+    #[test]
+    fn test_mission_period_mapper_round_trip() {
+        for period in [
+            MissionPeriod::Daily,
+            MissionPeriod::Weekly,
+            MissionPeriod::Custom,
+        ] {
+            let db_value = MissionPeriodMapper::to_db_str(&period);
+            assert_eq!(MissionPeriodMapper::from_db_str(db_value), period);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::mission_mapper::MissionTypeMapper;
+use crate::modules::gamification::domain::entities::daily_mission::QuizConditions;
+use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+
+pub struct MissionTemplateMapper;
+
+#[derive(FromRow)]
+pub struct MissionTemplateRow {
+    pub id: Uuid,
+    pub description: String,
+    pub mission_type: String,
+    pub min_target: i32,
+    pub max_target: i32,
+    pub min_reward: i32,
+    pub max_reward: i32,
+    pub weight: i32,
+    pub is_active: bool,
+    pub min_score: Option<i32>,
+    pub min_accuracy: Option<f64>,
+}
+
+impl MissionTemplateMapper {
+    pub fn from_row(row: &MissionTemplateRow) -> MissionTemplate {
+        MissionTemplate {
+            id: row.id,
+            description: row.description.clone(),
+            mission_type: MissionTypeMapper::from_db_str(&row.mission_type),
+            min_target: row.min_target,
+            max_target: row.max_target,
+            min_reward: row.min_reward,
+            max_reward: row.max_reward,
+            weight: row.weight,
+            is_active: row.is_active,
+            quiz_conditions: QuizConditions {
+                min_score: row.min_score,
+                min_accuracy: row.min_accuracy,
+            },
+        }
+    }
+
+    pub fn from_rows(rows: &[MissionTemplateRow]) -> Vec<MissionTemplate> {
+        rows.iter().map(Self::from_row).collect()
+    }
+}
@@ -1 +1,8 @@
 // Gamification mappers - DB to Domain conversion
+pub mod achievement_mapper;
+pub mod level_mapper;
+pub mod mission_mapper;
+pub mod mission_template_mapper;
+pub mod points_ledger_mapper;
+pub mod reward_mapper;
+pub mod streak_mapper;
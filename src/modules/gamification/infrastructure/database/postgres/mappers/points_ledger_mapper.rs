@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::points_ledger::{
+    BalanceMismatch, LedgerEntry, PointsEntry,
+};
+
+pub struct PointsLedgerMapper;
+
+#[derive(FromRow)]
+pub struct PointsLedgerRow {
+    pub id: i64,
+    pub user_id: Uuid,
+    pub delta: i32,
+    pub source_type: String,
+    pub source_id: Option<Uuid>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(FromRow)]
+pub struct BalanceMismatchRow {
+    pub user_id: Uuid,
+    pub total_score: i32,
+    pub ledger_total: i64,
+}
+
+impl PointsLedgerMapper {
+    pub fn from_row(row: &PointsLedgerRow) -> Result<LedgerEntry, String> {
+        let source = row
+            .source_type
+            .parse()
+            .map_err(|e| format!("{}: {}", e, row.source_type))?;
+
+        Ok(LedgerEntry {
+            id: row.id,
+            entry: PointsEntry::new(
+                row.user_id,
+                row.delta,
+                source,
+                row.source_id,
+                row.occurred_at,
+            ),
+        })
+    }
+
+    pub fn from_rows(rows: &[PointsLedgerRow]) -> Result<Vec<LedgerEntry>, String> {
+        rows.iter().map(Self::from_row).collect()
+    }
+
+    pub fn mismatches_from_rows(rows: &[BalanceMismatchRow]) -> Vec<BalanceMismatch> {
+        rows.iter()
+            .map(|row| BalanceMismatch {
+                user_id: row.user_id,
+                total_score: row.total_score,
+                ledger_total: row.ledger_total,
+            })
+            .collect()
+    }
+}
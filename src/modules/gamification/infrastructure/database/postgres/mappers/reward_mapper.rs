@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::reward_item::{
+    RewardItem, RewardKind, ScoreBoost,
+};
+use crate::modules::gamification::domain::entities::reward_purchase::{
+    InventoryItem, RewardPurchase,
+};
+
+pub struct RewardItemMapper;
+
+#[derive(FromRow)]
+pub struct RewardItemRow {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub kind: String,
+    pub price: i32,
+    pub stock: Option<i32>,
+    pub per_user_limit: Option<i32>,
+    pub boost_percent: Option<i32>,
+    pub boost_hours: Option<i32>,
+    pub is_active: bool,
+}
+
+impl RewardItemMapper {
+    pub fn from_row(row: &RewardItemRow) -> Result<RewardItem, String> {
+        let boost = match (row.boost_percent, row.boost_hours) {
+            (Some(percent), Some(hours)) => Some(ScoreBoost { percent, hours }),
+            _ => None,
+        };
+        let mut item = RewardItem::new(
+            row.id,
+            row.name.clone(),
+            row.description.clone(),
+            RewardKindMapper::from_db_str(&row.kind)?,
+            row.price,
+        )
+        .and_then(|item| item.with_stock(row.stock))
+        .and_then(|item| item.with_per_user_limit(row.per_user_limit))
+        .and_then(|item| item.with_boost(boost))
+        .map_err(|e| format!("Hadiah {} tidak valid: {}", row.id, e))?;
+        item.set_active(row.is_active);
+        Ok(item)
+    }
+
+    pub fn from_rows(rows: &[RewardItemRow]) -> Vec<RewardItem> {
+        rows.iter().filter_map(|r| Self::from_row(r).ok()).collect()
+    }
+}
+
+pub struct RewardPurchaseMapper;
+
+#[derive(FromRow)]
+pub struct RewardPurchaseRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub item_id: Uuid,
+    pub item_name: String,
+    pub kind: String,
+    pub price: i32,
+    pub purchased_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(FromRow)]
+pub struct InventoryItemRow {
+    pub item_id: Uuid,
+    pub name: String,
+    pub kind: String,
+    pub quantity: i32,
+    pub active_until: Option<DateTime<Utc>>,
+}
+
+impl RewardPurchaseMapper {
+    pub fn from_rows(rows: &[RewardPurchaseRow]) -> Result<Vec<RewardPurchase>, String> {
+        rows.iter()
+            .map(|row| {
+                Ok(RewardPurchase {
+                    id: row.id,
+                    user_id: row.user_id,
+                    item_id: row.item_id,
+                    item_name: row.item_name.clone(),
+                    kind: RewardKindMapper::from_db_str(&row.kind)?,
+                    price: row.price,
+                    purchased_at: row.purchased_at,
+                    expires_at: row.expires_at,
+                })
+            })
+            .collect()
+    }
+
+    pub fn inventory_from_rows(rows: &[InventoryItemRow]) -> Result<Vec<InventoryItem>, String> {
+        rows.iter()
+            .map(|row| {
+                Ok(InventoryItem {
+                    item_id: row.item_id,
+                    name: row.name.clone(),
+                    kind: RewardKindMapper::from_db_str(&row.kind)?,
+                    quantity: row.quantity,
+                    active_until: row.active_until,
+                })
+            })
+            .collect()
+    }
+}
+
+pub struct RewardKindMapper;
+
+impl RewardKindMapper {
+    pub fn from_db_str(s: &str) -> Result<RewardKind, String> {
+        s.parse()
+            .map_err(|e: &'static str| format!("{} ({})", e, s))
+    }
+
+    pub fn to_db_str(kind: &RewardKind) -> String {
+        kind.to_string()
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::entities::user_streak::UserStreak;
+
+pub struct StreakMapper;
+
+#[derive(FromRow)]
+pub struct UserStreakRow {
+    pub user_id: Uuid,
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub last_active_date: Option<NaiveDate>,
+    pub freezes_available: i32,
+}
+
+impl StreakMapper {
+    pub fn from_row(row: &UserStreakRow) -> UserStreak {
+        UserStreak {
+            user_id: row.user_id,
+            current_streak: row.current_streak,
+            longest_streak: row.longest_streak,
+            last_active_date: row.last_active_date,
+            freezes_available: row.freezes_available,
+        }
+    }
+}
@@ -1,158 +1,196 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
-use chrono::NaiveDate;
 
-use crate::modules::gamification::domain::ports::mission_repository::MissionRepository;
-use crate::modules::gamification::domain::entities::mission::{DailyMission, UserMission};
-use crate::modules::gamification::domain::entities::daily_mission::MissionType;
+use super::level_repository::record_points;
+use super::mappers::mission_mapper::{
+    DailyMissionMapper, DailyMissionRow, UserMissionMapper, UserMissionRow,
+};
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::points_ledger::PointsEntry;
+use crate::modules::gamification::domain::entities::user_mission::UserMission;
+use crate::modules::gamification::domain::repositories::MissionRepository;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
 
 pub struct PostgresMissionRepository {
-    pub pool: PgPool,
+    db: PgHandle,
 }
 
 impl PostgresMissionRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, String> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| format!("Database error: {}", e))
     }
 }
 
 #[async_trait]
 impl MissionRepository for PostgresMissionRepository {
-    async fn get_user_mission(&self, user_id: Uuid, mission_id: Uuid) -> Result<Option<UserMission>, String> {
-        let record = sqlx::query!(
-            r#"
-            SELECT user_id, mission_id, current_progress, is_claimed 
-            FROM user_missions 
-            WHERE user_id = $1 AND mission_id = $2
-            "#,
-            user_id,
-            mission_id
+    async fn get_user_mission(
+        &self,
+        user_id: Uuid,
+        mission_id: Uuid,
+        period_start: NaiveDateTime,
+    ) -> Result<Option<UserMission>, String> {
+        let mut conn = self.conn().await?;
+        let row = sqlx::query_as::<_, UserMissionRow>(
+            "SELECT user_id, mission_id, period_start, current_progress, is_claimed FROM user_missions \
+             WHERE user_id = $1 AND mission_id = $2 AND period_start = $3",
         )
-        .fetch_optional(&self.pool)
+        .bind(user_id)
+        .bind(mission_id)
+        .bind(period_start)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| format!("Database error: {}", e))?;
 
-        // Mapping dari hasil row database ke Entity Rust
-        match record {
-            Some(row) => {
-                let mut mission = UserMission::new(row.user_id, row.mission_id);
-                mission.current_progress = row.current_progress;
-                mission.is_claimed = row.is_claimed;
-                Ok(Some(mission))
-            },
-            None => Ok(None)
-        }
+        Ok(row.as_ref().map(UserMissionMapper::from_row))
     }
 
     async fn save_user_mission(&self, user_mission: &UserMission) -> Result<(), String> {
-        sqlx::query!(
-            r#"
-            INSERT INTO user_missions (user_id, mission_id, current_progress, is_claimed)
-            VALUES ($1, $2, $3, $4)
-            ON CONFLICT (user_id, mission_id) 
-            DO UPDATE SET 
-                current_progress = EXCLUDED.current_progress,
-                is_claimed = EXCLUDED.is_claimed
-            "#,
-            user_mission.user_id(),
-            user_mission.mission_id(),
-            user_mission.current_progress(),
-            user_mission.is_claimed()
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "INSERT INTO user_missions (user_id, mission_id, period_start, current_progress, is_claimed) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (user_id, mission_id, period_start) DO UPDATE SET \
+                current_progress = EXCLUDED.current_progress, \
+                is_claimed = EXCLUDED.is_claimed",
         )
-        .execute(&self.pool)
+        .bind(user_mission.user_id())
+        .bind(user_mission.mission_id())
+        .bind(user_mission.period_start())
+        .bind(user_mission.current_progress())
+        .bind(user_mission.is_claimed())
+        .execute(&mut *conn)
         .await
         .map_err(|e| format!("Gagal menyimpan progres misi: {}", e))?;
 
         Ok(())
     }
 
-    async fn add_user_score(&self, user_id: Uuid, points: i32) -> Result<(), String> {
-        sqlx::query!(
-            r#"
-            UPDATE engine_users 
-            SET total_score = total_score + $1 
-            WHERE user_id = $2
-            "#,
-            points,
-            user_id
+    async fn claim_user_mission(
+        &self,
+        user_id: Uuid,
+        mission_id: Uuid,
+        period_start: NaiveDateTime,
+        target_count: i32,
+    ) -> Result<bool, String> {
+        let mut conn = self.conn().await?;
+        let claimed = sqlx::query_scalar::<_, Uuid>(
+            "UPDATE user_missions SET is_claimed = TRUE \
+             WHERE user_id = $1 AND mission_id = $2 AND period_start = $3 \
+               AND NOT is_claimed AND current_progress >= $4 \
+             RETURNING mission_id",
         )
-        .execute(&self.pool)
+        .bind(user_id)
+        .bind(mission_id)
+        .bind(period_start)
+        .bind(target_count)
+        .fetch_optional(&mut *conn)
         .await
-        .map_err(|e| format!("Gagal menambah skor user: {}", e))?;
+        .map_err(|e| format!("Gagal mengklaim reward misi: {}", e))?;
+
+        Ok(claimed.is_some())
+    }
+
+    async fn user_exists(&self, user_id: Uuid) -> Result<bool, String> {
+        let mut conn = self.conn().await?;
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM shadow_users WHERE user_id = $1)",
+        )
+        .bind(user_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("Database error (user_exists): {}", e))
+    }
+
+    async fn award_points(&self, entries: &[PointsEntry]) -> Result<(), String> {
+        let mut conn = self.conn().await?;
+        record_points(&mut conn, entries)
+            .await
+            .map_err(|e| format!("Gagal menambah skor user: {}", e))?;
 
         Ok(())
     }
 
-    async fn get_active_missions_by_date(&self, date: NaiveDate) -> Result<Vec<DailyMission>, String> {
-        let records = sqlx::query!(
-            r#"
-            SELECT id, description, target_count, date, reward_points, mission_type
-            FROM daily_missions 
-            WHERE date = $1
-            "#,
-            date
+    async fn get_active_missions_at(&self, at: NaiveDateTime) -> Result<Vec<DailyMission>, String> {
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, DailyMissionRow>(
+            "SELECT id, description, target_count, date, reward_points, mission_type, template_id, min_score, min_accuracy, period, starts_at, ends_at \
+             FROM daily_missions WHERE starts_at <= $1 AND ends_at > $1 ORDER BY starts_at, description",
         )
-        .fetch_all(&self.pool)
+        .bind(at)
+        .fetch_all(&mut *conn)
         .await
         .map_err(|e| format!("Database error (get_active_missions): {}", e))?;
 
-        // Mengubah Vec dari row database menjadi Vec<DailyMission>
-        let mut missions = Vec::new();
-        for row in records {
-            let m_type = match row.mission_type.as_str() {
-                "Quiz" => MissionType::Quiz,
-                "DailyLogin" => MissionType::DailyLogin,
-                _ => MissionType::ReadArticle,
-            };
-
-            if let Ok(mission) = DailyMission::new(
-                row.id, 
-                row.description, 
-                row.target_count, 
-                row.date, 
-                row.reward_points,
-                m_type
-            ) {
-                missions.push(mission);
-            }
-        }
+        Ok(DailyMissionMapper::from_rows(&rows))
+    }
 
-        Ok(missions)
+    async fn get_user_missions_at(
+        &self,
+        user_id: Uuid,
+        at: NaiveDateTime,
+    ) -> Result<Vec<(DailyMission, Option<UserMission>)>, String> {
+        let missions = self.get_active_missions_at(at).await?;
+        let (mission_ids, period_starts): (Vec<Uuid>, Vec<NaiveDateTime>) = missions
+            .iter()
+            .filter_map(|m| m.instance_at(at).map(|i| (m.id(), i.starts_at)))
+            .unzip();
+
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, UserMissionRow>(
+            "SELECT um.user_id, um.mission_id, um.period_start, um.current_progress, um.is_claimed \
+             FROM user_missions um \
+             JOIN UNNEST($2::uuid[], $3::timestamp[]) AS i(mission_id, period_start) \
+               ON i.mission_id = um.mission_id AND i.period_start = um.period_start \
+             WHERE um.user_id = $1",
+        )
+        .bind(user_id)
+        .bind(&mission_ids)
+        .bind(&period_starts)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| format!("Database error (get_user_missions_at): {}", e))?;
+
+        let mut progress: HashMap<Uuid, UserMission> = rows
+            .iter()
+            .map(|row| (row.mission_id, UserMissionMapper::from_row(row)))
+            .collect();
+        Ok(missions
+            .into_iter()
+            .map(|mission| {
+                let user_mission = progress.remove(&mission.id());
+                (mission, user_mission)
+            })
+            .collect())
     }
 
     async fn get_daily_mission_by_id(&self, id: Uuid) -> Result<Option<DailyMission>, String> {
-       let record = sqlx::query!(
-            r#"
-            SELECT id, description, target_count, date, reward_points, mission_type 
-            FROM daily_missions 
-            WHERE id = $1
-            "#,
-            id
+        let mut conn = self.conn().await?;
+        let row = sqlx::query_as::<_, DailyMissionRow>(
+            "SELECT id, description, target_count, date, reward_points, mission_type, template_id, min_score, min_accuracy, period, starts_at, ends_at \
+             FROM daily_missions WHERE id = $1",
         )
-        .fetch_optional(&self.pool)
+        .bind(id)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| format!("Database error (get_daily_mission): {}", e))?;
 
-        match record {
-            Some(row) => {
-                let m_type = match row.mission_type.as_str() {
-                    "Quiz" => MissionType::Quiz,
-                    "DailyLogin" => MissionType::DailyLogin,
-                    _ => MissionType::ReadArticle,
-                };
-
-                let mission = DailyMission::new(
-                    row.id, 
-                    row.description, 
-                    row.target_count, 
-                    row.date, 
-                    row.reward_points,
-                    m_type
-                ).map_err(|e| e.to_string())?;
-                Ok(Some(mission))
-            },
-            None => Ok(None)
-        }
+        row.as_ref().map(DailyMissionMapper::from_row).transpose()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::mappers::mission_mapper::{
+    DailyMissionMapper, DailyMissionRow, MissionPeriodMapper, MissionTypeMapper,
+};
+use super::mappers::mission_template_mapper::{MissionTemplateMapper, MissionTemplateRow};
+use crate::modules::gamification::domain::entities::daily_mission::DailyMission;
+use crate::modules::gamification::domain::entities::mission_template::MissionTemplate;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+pub struct PostgresMissionTemplateRepository {
+    pub pool: PgPool,
+}
+
+impl PostgresMissionTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MissionTemplateRepository for PostgresMissionTemplateRepository {
+    async fn get_templates(&self) -> Result<Vec<MissionTemplate>, String> {
+        let rows = sqlx::query_as::<_, MissionTemplateRow>(
+            "SELECT id, description, mission_type, min_target, max_target, min_reward, max_reward, weight, is_active, min_score, min_accuracy \
+             FROM mission_templates ORDER BY created_at, id",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_templates): {}", e))?;
+
+        Ok(MissionTemplateMapper::from_rows(&rows))
+    }
+
+    async fn create_template(&self, template: &MissionTemplate) -> Result<(), String> {
+        sqlx::query(
+            "INSERT INTO mission_templates \
+                (id, description, mission_type, min_target, max_target, min_reward, max_reward, weight, is_active, min_score, min_accuracy) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+        )
+        .bind(template.id)
+        .bind(&template.description)
+        .bind(MissionTypeMapper::to_db_str(&template.mission_type))
+        .bind(template.min_target)
+        .bind(template.max_target)
+        .bind(template.min_reward)
+        .bind(template.max_reward)
+        .bind(template.weight)
+        .bind(template.is_active)
+        .bind(template.quiz_conditions.min_score)
+        .bind(template.quiz_conditions.min_accuracy)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Gagal menyimpan template misi: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn get_missions_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyMission>, String> {
+        let rows = sqlx::query_as::<_, DailyMissionRow>(
+            "SELECT id, description, target_count, date, reward_points, mission_type, template_id, min_score, min_accuracy, period, starts_at, ends_at \
+             FROM daily_missions WHERE date BETWEEN $1 AND $2 ORDER BY date, description",
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_missions_between): {}", e))?;
+
+        Ok(DailyMissionMapper::from_rows(&rows))
+    }
+
+    async fn insert_daily_missions(&self, missions: &[DailyMission]) -> Result<u64, String> {
+        if missions.is_empty() {
+            return Ok(0);
+        }
+
+        let mut ids: Vec<Uuid> = Vec::with_capacity(missions.len());
+        let mut descriptions = Vec::with_capacity(missions.len());
+        let mut targets = Vec::with_capacity(missions.len());
+        let mut dates = Vec::with_capacity(missions.len());
+        let mut rewards = Vec::with_capacity(missions.len());
+        let mut types = Vec::with_capacity(missions.len());
+        let mut template_ids: Vec<Option<Uuid>> = Vec::with_capacity(missions.len());
+        let mut min_scores: Vec<Option<i32>> = Vec::with_capacity(missions.len());
+        let mut min_accuracies: Vec<Option<f64>> = Vec::with_capacity(missions.len());
+        let mut periods = Vec::with_capacity(missions.len());
+        let mut starts = Vec::with_capacity(missions.len());
+        let mut ends = Vec::with_capacity(missions.len());
+        for mission in missions {
+            ids.push(mission.id);
+            descriptions.push(mission.description.clone());
+            targets.push(mission.target_count);
+            dates.push(mission.date);
+            rewards.push(mission.reward_points);
+            types.push(MissionTypeMapper::to_db_str(&mission.mission_type).to_string());
+            template_ids.push(mission.template_id);
+            min_scores.push(mission.quiz_conditions.min_score);
+            min_accuracies.push(mission.quiz_conditions.min_accuracy);
+            periods.push(MissionPeriodMapper::to_db_str(&mission.period).to_string());
+            starts.push(mission.window.starts_at);
+            ends.push(mission.window.ends_at);
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO daily_missions (id, description, target_count, date, reward_points, mission_type, template_id, min_score, min_accuracy, period, starts_at, ends_at) \
+             SELECT * FROM UNNEST($1::uuid[], $2::varchar[], $3::int[], $4::date[], $5::int[], $6::varchar[], $7::uuid[], $8::int[], $9::float8[], $10::varchar[], $11::timestamp[], $12::timestamp[]) \
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(&ids)
+        .bind(&descriptions)
+        .bind(&targets)
+        .bind(&dates)
+        .bind(&rewards)
+        .bind(&types)
+        .bind(&template_ids)
+        .bind(&min_scores)
+        .bind(&min_accuracies)
+        .bind(&periods)
+        .bind(&starts)
+        .bind(&ends)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Gagal menyimpan jadwal misi: {}", e))?;
+
+        Ok(result.rows_affected())
+    }
+}
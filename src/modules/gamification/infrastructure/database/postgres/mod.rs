@@ -1 +1,17 @@
 // PostgreSQL implementations for Gamification
+pub mod achievement_repository;
+pub mod level_repository;
+pub mod mappers;
+pub mod mission_repository;
+pub mod mission_template_repository;
+pub mod points_ledger_repository;
+pub mod reward_shop_repository;
+pub mod streak_repository;
+
+pub use achievement_repository::PostgresAchievementRepository;
+pub use level_repository::PostgresLevelRepository;
+pub use mission_repository::PostgresMissionRepository;
+pub use mission_template_repository::PostgresMissionTemplateRepository;
+pub use points_ledger_repository::PostgresPointsLedgerRepository;
+pub use reward_shop_repository::PostgresRewardShopRepository;
+pub use streak_repository::PostgresStreakRepository;
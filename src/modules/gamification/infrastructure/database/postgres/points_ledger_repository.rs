@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::mappers::points_ledger_mapper::{
+    BalanceMismatchRow, PointsLedgerMapper, PointsLedgerRow,
+};
+use crate::modules::gamification::domain::entities::points_ledger::{BalanceMismatch, LedgerEntry};
+use crate::modules::gamification::domain::repositories::PointsLedgerRepository;
+
+/// Per-user ledger sums next to the `total_score` projection, drifted users only.
+const MISMATCHES_SQL: &str = "SELECT s.user_id, s.total_score, \
+         COALESCE(SUM(pl.delta), 0)::BIGINT AS ledger_total \
+     FROM shadow_users s \
+     LEFT JOIN points_ledger pl ON pl.user_id = s.user_id \
+     GROUP BY s.user_id, s.total_score \
+     HAVING s.total_score <> COALESCE(SUM(pl.delta), 0)";
+
+pub struct PostgresPointsLedgerRepository {
+    pool: PgPool,
+}
+
+impl PostgresPointsLedgerRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PointsLedgerRepository for PostgresPointsLedgerRepository {
+    async fn get_total_score(&self, user_id: Uuid) -> Result<Option<i32>, String> {
+        sqlx::query_scalar::<_, i32>("SELECT total_score FROM shadow_users WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Database error (get_total_score): {}", e))
+    }
+
+    async fn get_user_entries(
+        &self,
+        user_id: Uuid,
+        limit: i64,
+        before: Option<i64>,
+    ) -> Result<Vec<LedgerEntry>, String> {
+        let rows = sqlx::query_as::<_, PointsLedgerRow>(
+            "SELECT id, user_id, delta, source_type, source_id, occurred_at FROM points_ledger \
+             WHERE user_id = $1 AND ($2::BIGINT IS NULL OR id < $2) \
+             ORDER BY id DESC LIMIT $3",
+        )
+        .bind(user_id)
+        .bind(before)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_user_entries): {}", e))?;
+
+        PointsLedgerMapper::from_rows(&rows)
+    }
+
+    async fn find_balance_mismatches(&self) -> Result<Vec<BalanceMismatch>, String> {
+        let rows = sqlx::query_as::<_, BalanceMismatchRow>(&format!(
+            "{} ORDER BY s.user_id",
+            MISMATCHES_SQL
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (find_balance_mismatches): {}", e))?;
+
+        Ok(PointsLedgerMapper::mismatches_from_rows(&rows))
+    }
+
+    async fn rebuild_balances(&self) -> Result<Vec<BalanceMismatch>, String> {
+        // Shift by the drift instead of overwriting, so an award committed while
+        // the sums were read (ledger row and total together) is kept.
+        let rows = sqlx::query_as::<_, BalanceMismatchRow>(&format!(
+            "WITH drift AS ({}) \
+             UPDATE shadow_users s \
+             SET total_score = s.total_score + (d.ledger_total - d.total_score)::INT \
+             FROM drift d WHERE s.user_id = d.user_id \
+             RETURNING d.user_id, d.total_score, d.ledger_total",
+            MISMATCHES_SQL
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Gagal menghitung ulang skor dari ledger: {}", e))?;
+
+        Ok(PointsLedgerMapper::mismatches_from_rows(&rows))
+    }
+}
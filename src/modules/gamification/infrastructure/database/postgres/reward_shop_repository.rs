@@ -0,0 +1,238 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::level_repository::record_points;
+use super::mappers::reward_mapper::{
+    InventoryItemRow, RewardItemMapper, RewardItemRow, RewardKindMapper, RewardPurchaseMapper,
+    RewardPurchaseRow,
+};
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::domain::entities::reward_item::{
+    PurchaseRejection, RewardItem, RewardKind,
+};
+use crate::modules::gamification::domain::entities::reward_purchase::{
+    InventoryItem, RewardPurchase,
+};
+use crate::modules::gamification::domain::entities::user_streak::StreakPolicy;
+use crate::modules::gamification::domain::repositories::RewardShopRepository;
+
+const ITEM_COLUMNS: &str = "id, name, description, kind, price, stock, per_user_limit, \
+     boost_percent, boost_hours, is_active";
+
+pub struct PostgresRewardShopRepository {
+    pool: PgPool,
+    /// Freezes a streak starts with, for users whose first freeze is bought.
+    initial_freezes: i32,
+}
+
+impl PostgresRewardShopRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            initial_freezes: StreakPolicy::default().initial_freezes,
+        }
+    }
+
+    /// Starts streaks created by a freeze purchase on `policy`'s initial freezes.
+    pub fn with_streak_policy(mut self, policy: &StreakPolicy) -> Self {
+        self.initial_freezes = policy.initial_freezes;
+        self
+    }
+}
+
+#[async_trait]
+impl RewardShopRepository for PostgresRewardShopRepository {
+    async fn get_items(&self, include_inactive: bool) -> Result<Vec<RewardItem>, String> {
+        let rows = sqlx::query_as::<_, RewardItemRow>(&format!(
+            "SELECT {} FROM reward_items WHERE is_active OR $1 ORDER BY price, name",
+            ITEM_COLUMNS
+        ))
+        .bind(include_inactive)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_items): {}", e))?;
+
+        Ok(RewardItemMapper::from_rows(&rows))
+    }
+
+    async fn create_item(&self, item: &RewardItem) -> Result<(), String> {
+        sqlx::query(
+            "INSERT INTO reward_items (id, name, description, kind, price, stock, per_user_limit, \
+             boost_percent, boost_hours, is_active) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+        )
+        .bind(item.id())
+        .bind(item.name())
+        .bind(item.description())
+        .bind(RewardKindMapper::to_db_str(&item.kind()))
+        .bind(item.price())
+        .bind(item.stock())
+        .bind(item.per_user_limit())
+        .bind(item.boost().map(|b| b.percent))
+        .bind(item.boost().map(|b| b.hours))
+        .bind(item.is_active())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Gagal menyimpan hadiah: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn purchase(
+        &self,
+        user_id: Uuid,
+        item_id: Uuid,
+        purchase_id: Uuid,
+        purchased_at: DateTime<Utc>,
+    ) -> Result<Result<RewardPurchase, PurchaseRejection>, String> {
+        let db_err = |e: sqlx::Error| format!("Gagal memproses pembelian hadiah: {}", e);
+        let mut tx = self.pool.begin().await.map_err(db_err)?;
+
+        // Always lock the user before the item so concurrent purchases queue
+        // up instead of deadlocking. Dropping `tx` on a rejection rolls back.
+        let balance = sqlx::query_scalar::<_, i32>(
+            "SELECT total_score FROM shadow_users WHERE user_id = $1 FOR UPDATE",
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_err)?;
+        let Some(balance) = balance else {
+            return Ok(Err(PurchaseRejection::UnknownUser));
+        };
+
+        let row = sqlx::query_as::<_, RewardItemRow>(&format!(
+            "SELECT {} FROM reward_items WHERE id = $1 FOR UPDATE",
+            ITEM_COLUMNS
+        ))
+        .bind(item_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_err)?;
+        let Some(row) = row else {
+            return Ok(Err(PurchaseRejection::UnknownItem));
+        };
+        let item = RewardItemMapper::from_row(&row)?;
+
+        let bought = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM reward_purchases WHERE user_id = $1 AND item_id = $2",
+        )
+        .bind(user_id)
+        .bind(item_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        if let Err(rejection) = item.check_purchase(balance, bought) {
+            return Ok(Err(rejection));
+        }
+        let purchase = item.purchase(purchase_id, user_id, purchased_at);
+
+        sqlx::query(
+            "INSERT INTO reward_purchases (id, user_id, item_id, price, purchased_at, expires_at) \
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(purchase.id)
+        .bind(user_id)
+        .bind(item_id)
+        .bind(purchase.price)
+        .bind(purchased_at)
+        .bind(purchase.expires_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        sqlx::query(
+            "UPDATE reward_items SET stock = stock - 1 WHERE id = $1 AND stock IS NOT NULL",
+        )
+        .bind(item_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        sqlx::query(
+            "INSERT INTO user_inventory (user_id, item_id, quantity, active_until, updated_at) \
+             VALUES ($1, $2, 1, $3, $4) \
+             ON CONFLICT (user_id, item_id) DO UPDATE SET \
+                 quantity = user_inventory.quantity + 1, \
+                 active_until = GREATEST(user_inventory.active_until, EXCLUDED.active_until), \
+                 updated_at = EXCLUDED.updated_at",
+        )
+        .bind(user_id)
+        .bind(item_id)
+        .bind(purchase.expires_at)
+        .bind(purchased_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        // A bought freeze is ready to cover a missed day right away. Quiz syncs
+        // lock the same shadow_users row first, so they cannot overwrite it.
+        if purchase.kind == RewardKind::StreakFreeze {
+            let credited = sqlx::query(
+                "INSERT INTO user_streaks (user_id, freezes_available, updated_at) \
+                 SELECT user_id, $2 + 1, $3 FROM engine_users WHERE user_id = $1 \
+                 ON CONFLICT (user_id) DO UPDATE SET \
+                     freezes_available = user_streaks.freezes_available + 1, \
+                     updated_at = EXCLUDED.updated_at",
+            )
+            .bind(user_id)
+            .bind(self.initial_freezes)
+            .bind(purchased_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+            if credited.rows_affected() == 0 {
+                return Ok(Err(PurchaseRejection::UnknownUser));
+            }
+        }
+
+        record_points(
+            &mut tx,
+            &[PointsEntry::new(
+                user_id,
+                -purchase.price,
+                PointsSource::Purchase,
+                Some(purchase.id),
+                purchased_at,
+            )],
+        )
+        .await
+        .map_err(db_err)?;
+
+        tx.commit().await.map_err(db_err)?;
+
+        Ok(Ok(purchase))
+    }
+
+    async fn get_inventory(&self, user_id: Uuid) -> Result<Vec<InventoryItem>, String> {
+        let rows = sqlx::query_as::<_, InventoryItemRow>(
+            "SELECT ui.item_id, ri.name, ri.kind, ui.quantity, ui.active_until \
+             FROM user_inventory ui JOIN reward_items ri ON ri.id = ui.item_id \
+             WHERE ui.user_id = $1 AND ui.quantity > 0 ORDER BY ri.name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_inventory): {}", e))?;
+
+        RewardPurchaseMapper::inventory_from_rows(&rows)
+    }
+
+    async fn get_purchases(&self, user_id: Uuid) -> Result<Vec<RewardPurchase>, String> {
+        let rows = sqlx::query_as::<_, RewardPurchaseRow>(
+            "SELECT rp.id, rp.user_id, rp.item_id, ri.name AS item_name, ri.kind, rp.price, \
+                 rp.purchased_at, rp.expires_at \
+             FROM reward_purchases rp JOIN reward_items ri ON ri.id = rp.item_id \
+             WHERE rp.user_id = $1 ORDER BY rp.purchased_at DESC, rp.id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Database error (get_purchases): {}", e))?;
+
+        RewardPurchaseMapper::from_rows(&rows)
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::mappers::streak_mapper::{StreakMapper, UserStreakRow};
+use crate::modules::gamification::domain::entities::user_streak::UserStreak;
+use crate::modules::gamification::domain::repositories::streak_repository::StreakRepository;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
+
+pub struct PostgresStreakRepository {
+    db: PgHandle,
+}
+
+impl PostgresStreakRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, String> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| format!("Database error: {}", e))
+    }
+}
+
+#[async_trait]
+impl StreakRepository for PostgresStreakRepository {
+    async fn get_streak(&self, user_id: Uuid) -> Result<Option<UserStreak>, String> {
+        let mut conn = self.conn().await?;
+        let row = sqlx::query_as::<_, UserStreakRow>(
+            "SELECT user_id, current_streak, longest_streak, last_active_date, freezes_available FROM user_streaks WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| format!("Database error (get_streak): {}", e))?;
+
+        Ok(row.as_ref().map(StreakMapper::from_row))
+    }
+
+    async fn save_streak(&self, streak: &UserStreak) -> Result<(), String> {
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "INSERT INTO user_streaks (user_id, current_streak, longest_streak, last_active_date, freezes_available) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (user_id) DO UPDATE SET \
+                current_streak = EXCLUDED.current_streak, \
+                longest_streak = EXCLUDED.longest_streak, \
+                last_active_date = EXCLUDED.last_active_date, \
+                freezes_available = EXCLUDED.freezes_available, \
+                updated_at = NOW()",
+        )
+        .bind(streak.user_id)
+        .bind(streak.current_streak)
+        .bind(streak.longest_streak)
+        .bind(streak.last_active_date)
+        .bind(streak.freezes_available)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Gagal menyimpan streak: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -1 +1,3 @@
 // Gamification Infrastructure - PostgreSQL & Redis adapters
+pub mod database;
+pub mod scheduler;
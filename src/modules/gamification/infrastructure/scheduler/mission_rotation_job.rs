@@ -0,0 +1,38 @@
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::modules::gamification::application::use_cases::mission_rotation::{
+    GenerateMissionScheduleUseCase, MAX_SCHEDULE_DAYS,
+};
+use crate::modules::gamification::domain::entities::mission_rotation::MissionRotation;
+use crate::modules::gamification::domain::repositories::MissionTemplateRepository;
+
+const ROTATION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Keeps the next `days_ahead` days of daily missions generated.
+///
+/// Runs once at startup and then hourly. Each run only fills days that have
+/// no missions yet, and the (date, template) unique index makes concurrent
+/// runs from several instances harmless.
+pub fn spawn_mission_rotation(
+    repository: Arc<dyn MissionTemplateRepository>,
+    rotation: MissionRotation,
+    days_ahead: u32,
+) -> JoinHandle<()> {
+    let use_case = GenerateMissionScheduleUseCase::new(repository, rotation);
+    let days = days_ahead.clamp(1, MAX_SCHEDULE_DAYS);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(ROTATION_INTERVAL);
+        loop {
+            ticker.tick().await;
+            match use_case.execute(Utc::now().date_naive(), days).await {
+                Ok(0) => {}
+                Ok(created) => tracing::info!("Mission rotation generated {} missions", created),
+                Err(e) => tracing::warn!("Mission rotation failed: {}", e),
+            }
+        }
+    })
+}
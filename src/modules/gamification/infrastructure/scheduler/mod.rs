@@ -0,0 +1,3 @@
+pub mod mission_rotation_job;
+
+pub use mission_rotation_job::spawn_mission_rotation;
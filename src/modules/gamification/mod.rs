@@ -1,9 +1,5 @@
 // Gamification Module - Achievements & Missions (Hexagonal Architecture)
-#[allow(dead_code, unused_imports)]
 pub mod application;
-#[allow(dead_code, unused_imports)]
 pub mod domain;
-#[allow(dead_code)]
 pub mod infrastructure;
-#[allow(dead_code)]
 pub mod presentation;
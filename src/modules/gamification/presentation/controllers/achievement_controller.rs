@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{
+    AchievementDto, UserAchievementDto, UserAchievementOverviewDto,
+};
+use crate::modules::gamification::application::use_cases::{
+    GetAchievementsUseCase, GetUserAchievementOverviewUseCase, GetUserAchievementsUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/achievements",
+    responses(
+        (status = 200, description = "Achievement catalog", body = [AchievementDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "achievements"
+)]
+pub async fn list_achievements_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<AchievementDto>>>, GamificationError> {
+    let use_case = GetAchievementsUseCase::new(state.achievement_repo.clone());
+
+    let achievements = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Daftar achievement berhasil diambil",
+        achievements,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/achievements",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "The user's achievement chains at their current tier, plus standalone achievements", body = UserAchievementOverviewDto),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "achievements"
+)]
+pub async fn get_user_achievements_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UserAchievementOverviewDto>>, GamificationError> {
+    let use_case = GetUserAchievementOverviewUseCase::new(state.achievement_repo.clone());
+
+    let overview = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Progres achievement user berhasil diambil",
+        overview,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/internal/users/{user_id}/achievements",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Flat achievement catalog with the user's progress, every chain tier listed", body = [UserAchievementDto]),
+        (status = 401, description = "Missing or invalid x-api-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("internal_key" = [])),
+    tag = "achievements"
+)]
+pub async fn get_user_achievements_internal_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<UserAchievementDto>>>, GamificationError> {
+    let use_case = GetUserAchievementsUseCase::new(state.achievement_repo.clone());
+
+    let achievements = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Progres achievement user berhasil diambil",
+        achievements,
+    )))
+}
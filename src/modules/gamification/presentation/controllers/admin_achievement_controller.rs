@@ -0,0 +1,168 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{
+    AchievementBackfillReportDto, AchievementBackfillRequestDto, AchievementDto,
+    AchievementRequestDto,
+};
+use crate::modules::gamification::application::use_cases::BackfillAchievementsUseCase;
+use crate::modules::gamification::application::use_cases::achievement_catalog::{
+    CreateAchievementUseCase, ListAchievementCatalogUseCase, RetireAchievementUseCase,
+    UpdateAchievementUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::shared::infrastructure::database::PostgresUnitOfWorkFactory;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/achievements",
+    responses(
+        (status = 200, description = "Full achievement catalog, retired entries included", body = [AchievementDto]),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_achievement_catalog_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<AchievementDto>>>, GamificationError> {
+    let use_case = ListAchievementCatalogUseCase::new(state.achievement_repo.clone());
+
+    let achievements = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Katalog achievement berhasil diambil",
+        achievements,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/achievements",
+    request_body = AchievementRequestDto,
+    responses(
+        (status = 201, description = "Achievement created", body = AchievementDto),
+        (status = 400, description = "Invalid name, target, reward or rarity"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn create_achievement_handler(
+    State(state): State<AppState>,
+    Json(dto): Json<AchievementRequestDto>,
+) -> Result<(StatusCode, Json<ApiResponse<AchievementDto>>), GamificationError> {
+    let use_case = CreateAchievementUseCase::new(state.achievement_repo.clone());
+
+    let achievement = use_case.execute(dto).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Achievement berhasil dibuat",
+            achievement,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/achievements/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Achievement ID")
+    ),
+    request_body = AchievementRequestDto,
+    responses(
+        (status = 200, description = "Achievement updated", body = AchievementDto),
+        (status = 400, description = "Invalid name, target, reward or rarity"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Achievement not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn update_achievement_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<AchievementRequestDto>,
+) -> Result<Json<ApiResponse<AchievementDto>>, GamificationError> {
+    let use_case = UpdateAchievementUseCase::new(state.achievement_repo.clone());
+
+    let achievement = use_case.execute(id, dto).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Achievement berhasil diperbarui",
+        achievement,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/achievements/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Achievement ID")
+    ),
+    responses(
+        (status = 200, description = "Achievement retired (soft delete)", body = AchievementDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Achievement not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn retire_achievement_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<AchievementDto>>, GamificationError> {
+    let use_case = RetireAchievementUseCase::new(state.achievement_repo.clone());
+
+    let achievement = use_case.execute(id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Achievement berhasil dipensiunkan",
+        achievement,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/achievements/backfill",
+    request_body = AchievementBackfillRequestDto,
+    responses(
+        (status = 200, description = "Quiz history replayed into achievement progress", body = AchievementBackfillReportDto),
+        (status = 400, description = "Invalid batch size, or a retired achievement or one quiz history cannot count"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Achievement not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn backfill_achievements_handler(
+    State(state): State<AppState>,
+    Json(dto): Json<AchievementBackfillRequestDto>,
+) -> Result<Json<ApiResponse<AchievementBackfillReportDto>>, GamificationError> {
+    let use_case = BackfillAchievementsUseCase::new(
+        state.achievement_repo.clone(),
+        Arc::new(PostgresUnitOfWorkFactory::new(state.db.clone())),
+    );
+
+    let report = use_case.execute(dto, Utc::now()).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Backfill achievement berhasil dijalankan",
+        report,
+    )))
+}
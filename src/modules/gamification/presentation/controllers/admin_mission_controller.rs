@@ -0,0 +1,149 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{
+    MissionTemplateDto, MissionTemplateRequestDto, ScheduledMissionDto,
+};
+use crate::modules::gamification::application::use_cases::mission_rotation::{
+    CreateMissionTemplateUseCase, GenerateMissionScheduleUseCase, ListMissionTemplatesUseCase,
+    PreviewMissionScheduleUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresMissionTemplateRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleQuery {
+    days: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/mission-templates",
+    responses(
+        (status = 200, description = "All mission templates", body = [MissionTemplateDto]),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_mission_templates_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<MissionTemplateDto>>>, GamificationError> {
+    let repository = PostgresMissionTemplateRepository::new(state.db);
+    let use_case = ListMissionTemplatesUseCase::new(Arc::new(repository));
+
+    let templates = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Template misi berhasil diambil",
+        templates,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/mission-templates",
+    request_body = MissionTemplateRequestDto,
+    responses(
+        (status = 201, description = "Mission template created", body = MissionTemplateDto),
+        (status = 400, description = "Invalid description, type, ranges or weight"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn create_mission_template_handler(
+    State(state): State<AppState>,
+    Json(dto): Json<MissionTemplateRequestDto>,
+) -> Result<(StatusCode, Json<ApiResponse<MissionTemplateDto>>), GamificationError> {
+    let repository = PostgresMissionTemplateRepository::new(state.db);
+    let use_case = CreateMissionTemplateUseCase::new(Arc::new(repository));
+
+    let template = use_case.execute(dto).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Template misi berhasil dibuat",
+            template,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/missions/schedule",
+    params(
+        ("days" = Option<u32>, Query, description = "Days to preview from today (1-31, defaults to MISSION_ROTATION_DAYS_AHEAD)")
+    ),
+    responses(
+        (status = 200, description = "Stored and still-to-be-generated missions, by date", body = [ScheduledMissionDto]),
+        (status = 400, description = "Days out of range"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn preview_mission_schedule_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ScheduleQuery>,
+) -> Result<Json<ApiResponse<Vec<ScheduledMissionDto>>>, GamificationError> {
+    let repository = PostgresMissionTemplateRepository::new(state.db);
+    let use_case =
+        PreviewMissionScheduleUseCase::new(Arc::new(repository), state.config.mission_rotation());
+
+    let days = query
+        .days
+        .unwrap_or(state.config.mission_rotation_days_ahead);
+    let schedule = use_case.execute(Utc::now().date_naive(), days).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Jadwal misi berhasil diambil",
+        schedule,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/missions/schedule",
+    params(
+        ("days" = Option<u32>, Query, description = "Days to generate from today (1-31, defaults to MISSION_ROTATION_DAYS_AHEAD)")
+    ),
+    responses(
+        (status = 200, description = "Number of missions generated", body = u64),
+        (status = 400, description = "Days out of range"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn generate_mission_schedule_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ScheduleQuery>,
+) -> Result<Json<ApiResponse<u64>>, GamificationError> {
+    let repository = PostgresMissionTemplateRepository::new(state.db);
+    let use_case =
+        GenerateMissionScheduleUseCase::new(Arc::new(repository), state.config.mission_rotation());
+
+    let days = query
+        .days
+        .unwrap_or(state.config.mission_rotation_days_ahead);
+    let created = use_case.execute(Utc::now().date_naive(), days).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Jadwal misi berhasil dibuat",
+        created,
+    )))
+}
@@ -0,0 +1,61 @@
+use axum::{extract::State, response::Json};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::PointsBalanceReportDto;
+use crate::modules::gamification::application::use_cases::{
+    RecomputePointsBalancesUseCase, VerifyPointsBalancesUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresPointsLedgerRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/points/balances",
+    responses(
+        (status = 200, description = "Users whose total score differs from their points ledger", body = PointsBalanceReportDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn verify_points_balances_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<PointsBalanceReportDto>>, GamificationError> {
+    let repository = PostgresPointsLedgerRepository::new(state.db);
+    let use_case = VerifyPointsBalancesUseCase::new(Arc::new(repository));
+
+    let report = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Pemeriksaan saldo poin selesai",
+        report,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/points/recompute",
+    responses(
+        (status = 200, description = "Drifted total scores rewritten from the points ledger", body = PointsBalanceReportDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn recompute_points_balances_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<PointsBalanceReportDto>>, GamificationError> {
+    let repository = PostgresPointsLedgerRepository::new(state.db);
+    let use_case = RecomputePointsBalancesUseCase::new(Arc::new(repository));
+
+    let report = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Skor user berhasil dihitung ulang dari ledger",
+        report,
+    )))
+}
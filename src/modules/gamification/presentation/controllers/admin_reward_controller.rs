@@ -0,0 +1,64 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{RewardItemDto, RewardItemRequestDto};
+use crate::modules::gamification::application::use_cases::reward_shop::{
+    CreateRewardItemUseCase, ListRewardItemsUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresRewardShopRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/rewards",
+    responses(
+        (status = 200, description = "The whole reward catalog, items off sale included", body = [RewardItemDto]),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_reward_catalog_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<RewardItemDto>>>, GamificationError> {
+    let repository = PostgresRewardShopRepository::new(state.db);
+    let use_case = ListRewardItemsUseCase::new(Arc::new(repository));
+
+    let items = use_case.execute(true).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Katalog hadiah berhasil diambil",
+        items,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/rewards",
+    request_body = RewardItemRequestDto,
+    responses(
+        (status = 201, description = "Reward item created", body = RewardItemDto),
+        (status = 400, description = "Invalid name, kind, price, stock, limit or boost"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn create_reward_item_handler(
+    State(state): State<AppState>,
+    Json(dto): Json<RewardItemRequestDto>,
+) -> Result<(StatusCode, Json<ApiResponse<RewardItemDto>>), GamificationError> {
+    let repository = PostgresRewardShopRepository::new(state.db);
+    let use_case = CreateRewardItemUseCase::new(Arc::new(repository));
+
+    let item = use_case.execute(dto).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Hadiah berhasil dibuat", item)),
+    ))
+}
@@ -0,0 +1,74 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{LevelUpsSeenResponseDto, UserLevelDto};
+use crate::modules::gamification::application::use_cases::{
+    AcknowledgeLevelUpsUseCase, GetUserLevelUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresLevelRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/level",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Level derived from the user's total score, with unseen level-ups", body = UserLevelDto),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "levels"
+)]
+pub async fn get_user_level_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UserLevelDto>>, GamificationError> {
+    let use_case =
+        GetUserLevelUseCase::new(Arc::new(PostgresLevelRepository::new(state.db.clone())));
+
+    let level = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Level user berhasil diambil",
+        level,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/{user_id}/level-ups/seen",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Pending level-ups marked as shown", body = LevelUpsSeenResponseDto),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "levels"
+)]
+pub async fn acknowledge_level_ups_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<LevelUpsSeenResponseDto>>, GamificationError> {
+    let use_case =
+        AcknowledgeLevelUpsUseCase::new(Arc::new(PostgresLevelRepository::new(state.db.clone())));
+
+    let marked_seen = use_case.execute(user_id, Utc::now()).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Level-up sudah ditandai terlihat",
+        LevelUpsSeenResponseDto {
+            user_id,
+            marked_seen,
+        },
+    )))
+}
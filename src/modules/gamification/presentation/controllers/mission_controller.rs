@@ -0,0 +1,168 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{
+    ClaimMissionDto, ClaimMissionResponseDto, DailyLoginResponseDto, TodayMissionsDto,
+    UserMissionDto,
+};
+use crate::modules::gamification::application::use_cases::{
+    ClaimMissionRewardUseCase, GetTodayMissionsUseCase, GetUserMissionsUseCase,
+    RecordDailyLoginUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::shared::domain::unit_of_work::{UnitOfWork, finish};
+use crate::shared::infrastructure::database::PostgresUnitOfWork;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/missions",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Missions of the user's local day with the user's progress", body = [UserMissionDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "missions"
+)]
+pub async fn get_user_missions_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<UserMissionDto>>>, GamificationError> {
+    let use_case = GetUserMissionsUseCase::new(state.mission_repo.clone());
+    let timezone = state.user_clock().timezone(user_id, None).await?;
+
+    let missions = use_case
+        .execute(user_id, timezone.local_time(Utc::now()))
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        "Daftar misi harian berhasil diambil",
+        missions,
+    )))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodayMissionsQuery {
+    user_id: Uuid,
+    utc_offset_minutes: Option<i32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/missions/today",
+    params(
+        ("user_id" = Uuid, Query, description = "User ID"),
+        ("utc_offset_minutes" = Option<i32>, Query, description = "Offset of the user's clock for this lookup; defaults to the user's synced timezone")
+    ),
+    responses(
+        (status = 200, description = "Missions of the user's local day with progress, claimable and claimed state", body = TodayMissionsDto),
+        (status = 400, description = "Offset out of range"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "missions"
+)]
+pub async fn get_today_missions_handler(
+    State(state): State<AppState>,
+    Query(query): Query<TodayMissionsQuery>,
+) -> Result<Json<ApiResponse<TodayMissionsDto>>, GamificationError> {
+    let use_case = GetTodayMissionsUseCase::new(state.mission_repo.clone(), state.user_clock());
+
+    let today = use_case
+        .execute(query.user_id, Utc::now(), query.utc_offset_minutes)
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        "Daftar misi hari ini berhasil diambil",
+        today,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/missions/{id}/claim",
+    params(
+        ("id" = Uuid, Path, description = "Daily mission ID")
+    ),
+    request_body = ClaimMissionDto,
+    responses(
+        (status = 200, description = "Mission reward claimed", body = ClaimMissionResponseDto),
+        (status = 400, description = "Mission not completed yet"),
+        (status = 404, description = "Mission or user progress not found"),
+        (status = 409, description = "Mission reward already claimed"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "missions"
+)]
+pub async fn claim_mission_handler(
+    State(state): State<AppState>,
+    Path(mission_id): Path<Uuid>,
+    Json(dto): Json<ClaimMissionDto>,
+) -> Result<Json<ApiResponse<ClaimMissionResponseDto>>, GamificationError> {
+    let timezone = state.user_clock().timezone(dto.user_id, None).await?;
+    let uow = PostgresUnitOfWork::begin(&state.db).await?;
+    // Quiz syncs rewrite the same user_missions rows; see UnitOfWork::lock_user.
+    uow.lock_user(dto.user_id).await?;
+    let use_case = ClaimMissionRewardUseCase::new(uow.missions())
+        .with_achievements(uow.achievements(), uow.outbox());
+
+    let result = use_case
+        .execute(dto.user_id, mission_id, Utc::now(), timezone)
+        .await;
+    let reward_points = finish(&uow, result).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Reward misi harian berhasil diklaim",
+        ClaimMissionResponseDto {
+            user_id: dto.user_id,
+            mission_id,
+            reward_points,
+        },
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/internal/users/{user_id}/login",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Login applied to the DailyLogin missions of the user's local day", body = DailyLoginResponseDto),
+        (status = 401, description = "Missing or invalid x-api-key"),
+        (status = 404, description = "User not found in Engine DB"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("internal_key" = [])),
+    tag = "missions"
+)]
+pub async fn record_daily_login_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<DailyLoginResponseDto>>, GamificationError> {
+    let timezone = state.user_clock().timezone(user_id, None).await?;
+    let uow = PostgresUnitOfWork::begin(&state.db).await?;
+    uow.lock_user(user_id).await?;
+    let use_case = RecordDailyLoginUseCase::new(uow.missions()).with_outbox(uow.outbox());
+
+    let result = use_case
+        .execute(user_id, timezone.local_time(Utc::now()))
+        .await;
+    let missions_updated = finish(&uow, result).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Login harian berhasil dicatat",
+        DailyLoginResponseDto {
+            user_id,
+            missions_updated,
+        },
+    )))
+}
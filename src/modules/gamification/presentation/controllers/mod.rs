@@ -1 +1,13 @@
 // Gamification controllers - Axum handlers
+pub mod achievement_controller;
+pub mod admin_achievement_controller;
+pub mod admin_mission_controller;
+pub mod admin_points_controller;
+pub mod admin_reward_controller;
+pub mod level_controller;
+pub mod mission_controller;
+pub mod points_controller;
+pub mod profile_controller;
+pub mod reward_controller;
+pub mod showcase_controller;
+pub mod streak_controller;
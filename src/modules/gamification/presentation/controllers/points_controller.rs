@@ -0,0 +1,52 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::PointsLedgerDto;
+use crate::modules::gamification::application::use_cases::GetPointsLedgerUseCase;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresPointsLedgerRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct PointsLedgerQuery {
+    limit: Option<i64>,
+    before: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/points/ledger",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("limit" = Option<i64>, Query, description = "Entries per page (1-100, default 20)"),
+        ("before" = Option<i64>, Query, description = "The next_before of the previous page")
+    ),
+    responses(
+        (status = 200, description = "Where the user's points came from, newest first", body = PointsLedgerDto),
+        (status = 400, description = "Limit out of range"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "points"
+)]
+pub async fn get_points_ledger_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    Query(query): Query<PointsLedgerQuery>,
+) -> Result<Json<ApiResponse<PointsLedgerDto>>, GamificationError> {
+    let use_case =
+        GetPointsLedgerUseCase::new(Arc::new(PostgresPointsLedgerRepository::new(state.db)));
+
+    let ledger = use_case.execute(user_id, query.limit, query.before).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Riwayat poin user berhasil diambil",
+        ledger,
+    )))
+}
@@ -0,0 +1,96 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::UserProfileDto;
+use crate::modules::gamification::application::use_cases::GetUserProfileUseCase;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresLevelRepository;
+use crate::modules::league::application::use_cases::GetUserTierUseCase;
+use crate::modules::league::infrastructure::database::postgres::ClanPostgresRepo;
+use crate::modules::league::infrastructure::database::redis::LeaderboardRedisRepo;
+use crate::shared::utils::response::ApiResponse;
+
+/// Clients may reuse a profile for this long before revalidating with `If-None-Match`.
+const PROFILE_CACHE_CONTROL: &str = "private, max-age=30";
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/profile",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a previously fetched profile")
+    ),
+    responses(
+        (status = 200, description = "Score, level, streak, clan standing, today's missions, recent unlocks and showcase in one response", body = UserProfileDto,
+            headers(
+                ("ETag" = String, description = "Weak validator for the profile body"),
+                ("Cache-Control" = String, description = "How long the profile may be reused")
+            )
+        ),
+        (status = 304, description = "The profile has not changed since the given ETag"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "profile"
+)]
+pub async fn get_user_profile_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<Response, GamificationError> {
+    let leaderboard = LeaderboardRedisRepo::new(state.redis.clone())
+        .with_ranking_mode(state.config.leaderboard_ranking_mode);
+    let use_case = GetUserProfileUseCase::new(
+        state.mission_repo.clone(),
+        state.achievement_repo.clone(),
+        state.streak_repo.clone(),
+        Arc::new(PostgresLevelRepository::new(state.db.clone())),
+        state.config.streak_policy(),
+        GetUserTierUseCase::new(ClanPostgresRepo::new(state.db.clone()), leaderboard),
+    );
+
+    let timezone = state.user_clock().timezone(user_id, None).await?;
+    let profile = use_case.execute(user_id, Utc::now(), timezone).await?;
+
+    let body = serde_json::to_vec(&ApiResponse::success(
+        "Profil gamifikasi berhasil diambil",
+        profile,
+    ))
+    .map_err(|e| GamificationError::DatabaseError(format!("Gagal menyusun profil: {}", e)))?;
+    let etag = profile_etag(&body);
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, PROFILE_CACHE_CONTROL.to_string()),
+    ];
+
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+    if not_modified {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, "application/json")],
+        body,
+    )
+        .into_response())
+}
+
+/// Weak validator over the serialized body: equal bodies give equal tags.
+fn profile_etag(body: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("W/\"{:016x}\"", hasher.finish())
+}
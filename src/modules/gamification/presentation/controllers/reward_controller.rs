@@ -0,0 +1,129 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{
+    InventoryItemDto, PurchaseRewardDto, RewardItemDto, RewardPurchaseDto,
+};
+use crate::modules::gamification::application::use_cases::reward_shop::{
+    GetInventoryUseCase, GetPurchaseHistoryUseCase, ListRewardItemsUseCase, PurchaseRewardUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::modules::gamification::infrastructure::database::postgres::PostgresRewardShopRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/rewards",
+    responses(
+        (status = 200, description = "Items on sale in the reward shop, cheapest first", body = [RewardItemDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "rewards"
+)]
+pub async fn list_reward_items_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<RewardItemDto>>>, GamificationError> {
+    let use_case =
+        ListRewardItemsUseCase::new(Arc::new(PostgresRewardShopRepository::new(state.db)));
+
+    let items = use_case.execute(false).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Daftar hadiah berhasil diambil",
+        items,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/rewards/{id}/purchase",
+    params(
+        ("id" = Uuid, Path, description = "Reward item ID")
+    ),
+    request_body = PurchaseRewardDto,
+    responses(
+        (status = 201, description = "Item bought and added to the user's inventory", body = RewardPurchaseDto),
+        (status = 404, description = "User or item not found, or item no longer on sale"),
+        (status = 409, description = "Not enough points, sold out, or per-user limit reached"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "rewards"
+)]
+pub async fn purchase_reward_handler(
+    State(state): State<AppState>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<PurchaseRewardDto>,
+) -> Result<(StatusCode, Json<ApiResponse<RewardPurchaseDto>>), GamificationError> {
+    let repository = PostgresRewardShopRepository::new(state.db.clone())
+        .with_streak_policy(&state.config.streak_policy());
+    let use_case = PurchaseRewardUseCase::new(Arc::new(repository));
+
+    let purchase = use_case
+        .execute(payload.user_id, item_id, Utc::now())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success("Hadiah berhasil dibeli", purchase)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/inventory",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Items the user holds", body = [InventoryItemDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "rewards"
+)]
+pub async fn get_inventory_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<InventoryItemDto>>>, GamificationError> {
+    let use_case = GetInventoryUseCase::new(Arc::new(PostgresRewardShopRepository::new(state.db)));
+
+    let inventory = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Inventaris user berhasil diambil",
+        inventory,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/purchases",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "The user's reward purchases, newest first", body = [RewardPurchaseDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "rewards"
+)]
+pub async fn get_purchase_history_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<RewardPurchaseDto>>>, GamificationError> {
+    let use_case =
+        GetPurchaseHistoryUseCase::new(Arc::new(PostgresRewardShopRepository::new(state.db)));
+
+    let purchases = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Riwayat pembelian berhasil diambil",
+        purchases,
+    )))
+}
@@ -0,0 +1,74 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::{ShowcaseBadgeDto, UpdateShowcaseRequestDto};
+use crate::modules::gamification::application::use_cases::{
+    GetShowcaseUseCase, UpdateShowcaseUseCase,
+};
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::shared::domain::unit_of_work::{UnitOfWork, finish};
+use crate::shared::infrastructure::database::PostgresUnitOfWork;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/showcase",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Badges pinned on the user's profile, in order", body = [ShowcaseBadgeDto]),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "achievements"
+)]
+pub async fn get_showcase_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ShowcaseBadgeDto>>>, GamificationError> {
+    let use_case = GetShowcaseUseCase::new(state.achievement_repo.clone());
+
+    let badges = use_case.execute(user_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Showcase user berhasil diambil",
+        badges,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/{user_id}/showcase",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = UpdateShowcaseRequestDto,
+    responses(
+        (status = 200, description = "Showcase replaced", body = [ShowcaseBadgeDto]),
+        (status = 400, description = "Unfinished, unknown or duplicate achievement, or too many badges"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "achievements"
+)]
+pub async fn update_showcase_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<UpdateShowcaseRequestDto>,
+) -> Result<Json<ApiResponse<Vec<ShowcaseBadgeDto>>>, GamificationError> {
+    let uow = PostgresUnitOfWork::begin(&state.db).await?;
+    // Quiz syncs rewrite the same user_achievements rows; see UnitOfWork::lock_user.
+    uow.lock_user(user_id).await?;
+    let use_case = UpdateShowcaseUseCase::new(uow.achievements(), state.config.showcase_max_badges);
+
+    let result = use_case.execute(user_id, &payload.achievement_ids).await;
+    let badges = finish(&uow, result).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Showcase user berhasil diperbarui",
+        badges,
+    )))
+}
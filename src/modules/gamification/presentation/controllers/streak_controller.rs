@@ -0,0 +1,40 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::gamification::application::dto::UserStreakDto;
+use crate::modules::gamification::application::use_cases::GetUserStreakUseCase;
+use crate::modules::gamification::domain::errors::GamificationError;
+use crate::shared::utils::response::ApiResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{user_id}/streak",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "The user's reading streak and freezes", body = UserStreakDto),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "streaks"
+)]
+pub async fn get_user_streak_handler(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UserStreakDto>>, GamificationError> {
+    let use_case =
+        GetUserStreakUseCase::new(state.streak_repo.clone(), state.config.streak_policy());
+
+    let timezone = state.user_clock().timezone(user_id, None).await?;
+    let streak = use_case.execute(user_id, Utc::now(), timezone).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Streak user berhasil diambil",
+        streak,
+    )))
+}
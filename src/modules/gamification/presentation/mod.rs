@@ -1 +1,3 @@
 // Gamification Presentation - Controllers & Routes
+pub mod controllers;
+pub mod routes;
@@ -1,43 +1,131 @@
-use std::sync::Arc;
+use super::controllers::{
+    achievement_controller, admin_achievement_controller, admin_mission_controller,
+    admin_points_controller, admin_reward_controller, level_controller, mission_controller,
+    points_controller, profile_controller, reward_controller, showcase_controller,
+    streak_controller,
+};
+use crate::AppState;
 use axum::{
-    routing::post,
     Router,
+    routing::{get, post, put},
 };
 
-use crate::modules::gamification::presentation::controllers::{
-    sync_quiz_controller,
-    claim_mission_controller,
-};
-use crate::modules::gamification::application::use_cases::{
-    sync_quiz_gamification::SyncQuizGamificationUseCase,
-    claim_mission_reward::ClaimMissionRewardUseCase,
-};
-
-// untuk use case yang dibutuhkan  controller
-pub struct GamificationState {
-    pub sync_quiz_use_case: Arc<SyncQuizGamificationUseCase>,
-    pub claim_mission_use_case: Arc<ClaimMissionRewardUseCase>,
+pub fn gamification_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/missions/today",
+            get(mission_controller::get_today_missions_handler),
+        )
+        .route(
+            "/missions/{id}/claim",
+            post(mission_controller::claim_mission_handler),
+        )
+        .route(
+            "/users/{user_id}/missions",
+            get(mission_controller::get_user_missions_handler),
+        )
+        .route(
+            "/achievements",
+            get(achievement_controller::list_achievements_handler),
+        )
+        .route(
+            "/users/{user_id}/achievements",
+            get(achievement_controller::get_user_achievements_handler),
+        )
+        .route(
+            "/users/{user_id}/showcase",
+            get(showcase_controller::get_showcase_handler)
+                .put(showcase_controller::update_showcase_handler),
+        )
+        .route(
+            "/users/{user_id}/streak",
+            get(streak_controller::get_user_streak_handler),
+        )
+        .route(
+            "/users/{user_id}/level",
+            get(level_controller::get_user_level_handler),
+        )
+        .route(
+            "/users/{user_id}/level-ups/seen",
+            post(level_controller::acknowledge_level_ups_handler),
+        )
+        .route(
+            "/users/{user_id}/profile",
+            get(profile_controller::get_user_profile_handler),
+        )
+        .route(
+            "/users/{user_id}/points/ledger",
+            get(points_controller::get_points_ledger_handler),
+        )
+        .route(
+            "/rewards",
+            get(reward_controller::list_reward_items_handler),
+        )
+        .route(
+            "/rewards/{id}/purchase",
+            post(reward_controller::purchase_reward_handler),
+        )
+        .route(
+            "/users/{user_id}/inventory",
+            get(reward_controller::get_inventory_handler),
+        )
+        .route(
+            "/users/{user_id}/purchases",
+            get(reward_controller::get_purchase_history_handler),
+        )
 }
 
-pub fn create_gamification_router(state: Arc<GamificationState>) -> Router {
-    // internal group: Menggunakan x-api-key (validasi di controller)
-    let internal_routes = Router::new()
+/// Routes called by the Java core service, mounted under `/api/internal`.
+pub fn gamification_internal_routes() -> Router<AppState> {
+    Router::new()
         .route(
-            "/api/internal/quiz-history/sync", 
-            post(sync_quiz_controller::sync_quiz_history)
-        );
-
-    // public group: akses fitur user (nannti dikunci oleh JWT)
-    let public_routes = Router::new()
+            "/users/{user_id}/achievements",
+            get(achievement_controller::get_user_achievements_internal_handler),
+        )
         .route(
-            "/api/v1/missions/:id/claim", 
-            post(claim_mission_controller::claim_mission_reward)
-        );
-        // TODO: .route_layer(...)  untuk JWT Middleware
+            "/users/{user_id}/login",
+            post(mission_controller::record_daily_login_handler),
+        )
+}
 
-    // gabungkan internal group dan public group
+/// Catalog, mission rotation, points ledger and reward shop maintenance routes, mounted under `/api/admin` behind `require_admin_key`.
+pub fn gamification_admin_routes() -> Router<AppState> {
     Router::new()
-        .merge(internal_routes)
-        .merge(public_routes)
-        .with_state(state) // State disuntikkan ke semua rute
-}
\ No newline at end of file
+        .route(
+            "/achievements",
+            get(admin_achievement_controller::list_achievement_catalog_handler)
+                .post(admin_achievement_controller::create_achievement_handler),
+        )
+        .route(
+            "/achievements/backfill",
+            post(admin_achievement_controller::backfill_achievements_handler),
+        )
+        .route(
+            "/achievements/{id}",
+            put(admin_achievement_controller::update_achievement_handler)
+                .delete(admin_achievement_controller::retire_achievement_handler),
+        )
+        .route(
+            "/mission-templates",
+            get(admin_mission_controller::list_mission_templates_handler)
+                .post(admin_mission_controller::create_mission_template_handler),
+        )
+        .route(
+            "/missions/schedule",
+            get(admin_mission_controller::preview_mission_schedule_handler)
+                .post(admin_mission_controller::generate_mission_schedule_handler),
+        )
+        .route(
+            "/points/balances",
+            get(admin_points_controller::verify_points_balances_handler),
+        )
+        .route(
+            "/points/recompute",
+            post(admin_points_controller::recompute_points_balances_handler),
+        )
+        .route(
+            "/rewards",
+            get(admin_reward_controller::list_reward_catalog_handler)
+                .post(admin_reward_controller::create_reward_item_handler),
+        )
+}
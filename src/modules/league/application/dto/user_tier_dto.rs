@@ -1,14 +1,29 @@
+use crate::modules::league::domain::entities::leaderboard_ranking::ClanStanding;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// DTO for user tier response
+///
+/// Ranking fields are `None` when the user has no clan or the clan has not
+/// scored on the leaderboard yet.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserTierDto {
     pub user_id: Uuid,
     pub clan_id: Option<Uuid>,
     pub clan_name: Option<String>,
     pub tier: Option<String>,
+    /// Clan rank within its tier leaderboard (1 = first).
+    pub tier_rank: Option<usize>,
+    /// Clan rank across all tiers.
+    pub global_rank: Option<usize>,
+    /// Share of the tier ranked at or below the clan, 0-100.
+    pub percentile: Option<f64>,
+    /// Points the clan needs to climb one rank in its tier.
+    pub points_to_next_rank: Option<i64>,
+    pub next_tier: Option<String>,
+    /// Points the clan needs to reach `next_tier`'s threshold.
+    pub points_to_next_tier: Option<i64>,
 }
 
 impl UserTierDto {
@@ -18,15 +33,41 @@ impl UserTierDto {
             clan_id: None,
             clan_name: None,
             tier: None,
+            tier_rank: None,
+            global_rank: None,
+            percentile: None,
+            points_to_next_rank: None,
+            next_tier: None,
+            points_to_next_tier: None,
         }
     }
 
     pub fn from_clan(user_id: Uuid, clan_id: Uuid, clan_name: String, tier: String) -> Self {
         Self {
-            user_id,
             clan_id: Some(clan_id),
             clan_name: Some(clan_name),
             tier: Some(tier),
+            ..Self::not_in_clan(user_id)
+        }
+    }
+
+    pub fn with_tier_standing(mut self, standing: Option<ClanStanding>) -> Self {
+        if let Some(standing) = standing {
+            self.tier_rank = Some(standing.rank);
+            self.percentile = Some(standing.percentile());
+            self.points_to_next_rank = standing.points_to_next_rank;
         }
+        self
+    }
+
+    pub fn with_global_standing(mut self, standing: Option<ClanStanding>) -> Self {
+        self.global_rank = standing.map(|s| s.rank);
+        self
+    }
+
+    pub fn with_next_tier(mut self, next_tier: Option<String>, points: Option<i64>) -> Self {
+        self.next_tier = next_tier;
+        self.points_to_next_tier = points;
+        self
     }
 }
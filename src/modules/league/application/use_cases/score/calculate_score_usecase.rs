@@ -16,10 +16,16 @@ impl<R: ClanRepository, L: LeaderboardCache> UpdateScoreUseCase<R, L> {
     pub async fn execute(&self, dto: UpdateScoreDto) -> Result<i64, AppError> {
         let final_score = (dto.base_score as f64 * dto.multiplier) as i64;
 
+        let clan = self
+            .repo
+            .get_clan_by_id(dto.clan_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Clan not found".to_string()))?;
+
         self.repo.add_score(dto.clan_id, final_score).await?;
 
         self.leaderboard
-            .update_clan_score(dto.clan_id, final_score)
+            .update_clan_score(dto.clan_id, &clan.tier().to_string(), final_score)
             .await?;
 
         Ok(final_score)
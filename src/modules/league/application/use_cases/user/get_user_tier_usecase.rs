@@ -1,17 +1,25 @@
 use crate::modules::league::application::dto::user_tier_dto::UserTierDto;
-use crate::modules::league::domain::repositories::ClanRepository;
+use crate::modules::league::domain::repositories::{ClanRepository, LeaderboardCache};
 use crate::shared::domain::base_error::AppError;
 use uuid::Uuid;
 
-pub struct GetUserTierUseCase<R: ClanRepository> {
+pub struct GetUserTierUseCase<R: ClanRepository, L: LeaderboardCache> {
     repository: R,
+    leaderboard: L,
 }
 
-impl<R: ClanRepository> GetUserTierUseCase<R> {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+impl<R: ClanRepository, L: LeaderboardCache> GetUserTierUseCase<R, L> {
+    pub fn new(repository: R, leaderboard: L) -> Self {
+        Self {
+            repository,
+            leaderboard,
+        }
     }
 
+    /// Returns the user's clan and tier, plus where that clan stands on the
+    /// tier and global leaderboards and how far it is from the next tier. Ranks
+    /// come from the leaderboard; the distance to the next tier from the clan's
+    /// own total, which the leaderboard only caches.
     pub async fn execute(&self, user_id: Uuid) -> Result<UserTierDto, AppError> {
         let clan_id = self.repository.get_user_clan_id(user_id).await?;
 
@@ -21,14 +29,22 @@ impl<R: ClanRepository> GetUserTierUseCase<R> {
 
         let clan = self.repository.get_clan_by_id(clan_id).await?;
 
-        match clan {
-            Some(c) => Ok(UserTierDto::from_clan(
-                user_id,
-                c.id(),
-                c.name().to_string(),
-                c.tier().to_string(),
-            )),
-            None => Ok(UserTierDto::not_in_clan(user_id)),
-        }
+        let Some(c) = clan else {
+            return Ok(UserTierDto::not_in_clan(user_id));
+        };
+
+        let tier = c.tier().to_string();
+        let tier_standing = self.leaderboard.get_clan_standing(&tier, c.id()).await?;
+        let global_standing = self.leaderboard.get_clan_standing("global", c.id()).await?;
+
+        Ok(
+            UserTierDto::from_clan(user_id, c.id(), c.name().to_string(), tier)
+                .with_tier_standing(tier_standing)
+                .with_global_standing(global_standing)
+                .with_next_tier(
+                    c.tier().next().map(|t| t.to_string()),
+                    c.tier().points_to_next(c.total_score()),
+                ),
+        )
     }
 }
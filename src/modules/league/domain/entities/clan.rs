@@ -22,6 +22,33 @@ impl std::fmt::Display for ClanTier {
     }
 }
 
+impl ClanTier {
+    /// Minimum clan score for the tier (see README, "Clan Tier System").
+    pub fn min_score(&self) -> i64 {
+        match self {
+            ClanTier::Bronze => 0,
+            ClanTier::Silver => 5_000,
+            ClanTier::Gold => 20_000,
+            ClanTier::Diamond => 50_000,
+        }
+    }
+
+    pub fn next(&self) -> Option<ClanTier> {
+        match self {
+            ClanTier::Bronze => Some(ClanTier::Silver),
+            ClanTier::Silver => Some(ClanTier::Gold),
+            ClanTier::Gold => Some(ClanTier::Diamond),
+            ClanTier::Diamond => None,
+        }
+    }
+
+    /// Points a clan with `score` still needs to reach the next tier's
+    /// threshold, or `None` at the top tier.
+    pub fn points_to_next(&self, score: i64) -> Option<i64> {
+        self.next().map(|next| (next.min_score() - score).max(0))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Clan {
     id: Uuid,
@@ -103,4 +130,12 @@ mod tests {
         let clan = Clan::new("Test Clan".to_string(), Uuid::new_v4());
         assert_eq!(clan.total_score(), 0);
     }
+
+    #[test]
+    fn test_tier_points_to_next() {
+        assert_eq!(ClanTier::Bronze.points_to_next(1_200), Some(3_800));
+        assert_eq!(ClanTier::Gold.points_to_next(20_000), Some(30_000));
+        assert_eq!(ClanTier::Silver.points_to_next(25_000), Some(0));
+        assert_eq!(ClanTier::Diamond.points_to_next(80_000), None);
+    }
 }
@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+/// A clan's position input for ranking: its score and when it got there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub clan_id: Uuid,
+    pub score: i64,
+    /// Unix epoch millis at which the clan reached `score`, if recorded.
+    pub reached_at: Option<i64>,
+}
+
+impl Standing {
+    /// Leaderboard order: higher score first. On equal scores, the clan that
+    /// reached the score earlier ranks higher. Clans without a recorded time
+    /// go after those with one, and the clan id settles anything left so the
+    /// order is total and stable.
+    pub fn cmp_rank(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .cmp(&self.score)
+            .then_with(|| match (self.reached_at, other.reached_at) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+            .then_with(|| self.clan_id.cmp(&other.clan_id))
+    }
+
+    /// Whether `self` is placed ahead of `other` on the leaderboard.
+    pub fn outranks(&self, other: &Self) -> bool {
+        self.cmp_rank(other) == Ordering::Less
+    }
+}
+
+/// Where one clan sits on one leaderboard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClanStanding {
+    pub rank: usize,
+    /// Number of clans on the leaderboard.
+    pub total: usize,
+    pub score: i64,
+    /// Points needed to climb one rank, `None` when already first.
+    pub points_to_next_rank: Option<i64>,
+}
+
+impl ClanStanding {
+    /// Share of the leaderboard ranked at or below this clan, 0-100.
+    /// The leader of a 10-clan board is at 100.0, the last clan at 10.0.
+    pub fn percentile(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let raw = (self.total + 1 - self.rank.min(self.total)) as f64 / self.total as f64 * 100.0;
+        (raw * 100.0).round() / 100.0
+    }
+}
+
+/// How ranks are numbered once clans are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RankingMode {
+    /// Every clan gets its own rank (1, 2, 3), ties broken by `Standing::cmp_rank`.
+    #[default]
+    Sequential,
+    /// Clans with equal scores share a rank and the next rank is skipped (1, 1, 3).
+    Shared,
+}
+
+impl RankingMode {
+    /// Assigns 1-based ranks to standings already sorted with `Standing::cmp_rank`.
+    pub fn assign_ranks(self, ordered: &[Standing]) -> Vec<usize> {
+        let mut ranks = Vec::with_capacity(ordered.len());
+        for (idx, standing) in ordered.iter().enumerate() {
+            let rank = match self {
+                RankingMode::Shared if idx > 0 && ordered[idx - 1].score == standing.score => {
+                    ranks[idx - 1]
+                }
+                _ => idx + 1,
+            };
+            ranks.push(rank);
+        }
+        ranks
+    }
+
+    /// Rank of a clan given how many clans have a strictly higher score and
+    /// how many clans on the same score are ordered ahead of it.
+    pub fn rank_from_counts(self, higher: usize, tied_ahead: usize) -> usize {
+        match self {
+            RankingMode::Sequential => higher + tied_ahead + 1,
+            RankingMode::Shared => higher + 1,
+        }
+    }
+
+    /// Points a clan on `score` needs to move up one rank.
+    ///
+    /// `next_higher` is the lowest score strictly above `score`. Under
+    /// sequential ranking a tie is lost to whoever got there first, so the
+    /// clan must pass the one directly ahead; under shared ranking matching
+    /// the next score is enough.
+    pub fn points_to_next_rank(
+        self,
+        score: i64,
+        tied_ahead: usize,
+        next_higher: Option<i64>,
+    ) -> Option<i64> {
+        match self {
+            RankingMode::Sequential if tied_ahead > 0 => Some(1),
+            RankingMode::Sequential => next_higher.map(|above| above - score + 1),
+            RankingMode::Shared => next_higher.map(|above| above - score),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing(score: i64, reached_at: Option<i64>) -> Standing {
+        Standing {
+            clan_id: Uuid::new_v4(),
+            score,
+            reached_at,
+        }
+    }
+
+    #[test]
+    fn higher_score_ranks_first() {
+        let a = standing(200, Some(50));
+        let b = standing(100, Some(10));
+        assert!(a.outranks(&b));
+    }
+
+    #[test]
+    fn equal_score_reached_earlier_ranks_first() {
+        let early = standing(100, Some(10));
+        let late = standing(100, Some(20));
+        assert!(early.outranks(&late));
+        assert!(!late.outranks(&early));
+    }
+
+    #[test]
+    fn missing_timestamp_ranks_after_recorded_one() {
+        let recorded = standing(100, Some(99));
+        let unknown = standing(100, None);
+        assert!(recorded.outranks(&unknown));
+    }
+
+    #[test]
+    fn sequential_ranks_are_unique() {
+        let ordered = vec![
+            standing(300, Some(1)),
+            standing(300, Some(2)),
+            standing(100, Some(3)),
+        ];
+        assert_eq!(
+            RankingMode::Sequential.assign_ranks(&ordered),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn rank_from_counts_by_mode() {
+        assert_eq!(RankingMode::Sequential.rank_from_counts(3, 2), 6);
+        assert_eq!(RankingMode::Shared.rank_from_counts(3, 2), 4);
+    }
+
+    #[test]
+    fn points_to_next_rank_sequential() {
+        let mode = RankingMode::Sequential;
+        assert_eq!(mode.points_to_next_rank(100, 1, Some(150)), Some(1));
+        assert_eq!(mode.points_to_next_rank(100, 0, Some(150)), Some(51));
+        assert_eq!(mode.points_to_next_rank(100, 0, None), None);
+    }
+
+    #[test]
+    fn points_to_next_rank_shared() {
+        let mode = RankingMode::Shared;
+        assert_eq!(mode.points_to_next_rank(100, 1, Some(150)), Some(50));
+        assert_eq!(mode.points_to_next_rank(100, 0, None), None);
+    }
+
+    #[test]
+    fn percentile_of_leader_and_last() {
+        let leader = ClanStanding {
+            rank: 1,
+            total: 10,
+            score: 500,
+            points_to_next_rank: None,
+        };
+        let last = ClanStanding { rank: 10, ..leader };
+        assert_eq!(leader.percentile(), 100.0);
+        assert_eq!(last.percentile(), 10.0);
+    }
+
+    #[test]
+    fn shared_ranks_skip_after_ties() {
+        let ordered = vec![
+            standing(300, Some(1)),
+            standing(300, Some(2)),
+            standing(100, Some(3)),
+            standing(50, Some(4)),
+            standing(50, Some(5)),
+        ];
+        assert_eq!(
+            RankingMode::Shared.assign_ranks(&ordered),
+            vec![1, 1, 3, 4, 4]
+        );
+    }
+}
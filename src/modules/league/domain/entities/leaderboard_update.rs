@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A single change to a clan's leaderboard score.
+///
+/// Published every time a clan score is incremented so that live
+/// leaderboard subscribers know their view is stale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardUpdate {
+    pub clan_id: Uuid,
+    pub tier: String,
+    pub total_score: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LeaderboardUpdate {
+    pub fn new(clan_id: Uuid, tier: impl Into<String>, total_score: i64) -> Self {
+        Self {
+            clan_id,
+            tier: tier.into(),
+            total_score,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Whether a subscriber watching `tier` should react to this update.
+    pub fn affects_tier(&self, tier: &str) -> bool {
+        self.tier.eq_ignore_ascii_case(tier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affects_tier_is_case_insensitive() {
+        let update = LeaderboardUpdate::new(Uuid::new_v4(), "Gold", 120);
+        assert!(update.affects_tier("Gold"));
+        assert!(update.affects_tier("gold"));
+        assert!(!update.affects_tier("Silver"));
+    }
+}
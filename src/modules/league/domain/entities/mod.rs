@@ -1,3 +1,5 @@
 pub mod clan;
 pub mod clan_member;
+pub mod leaderboard_ranking;
+pub mod leaderboard_update;
 pub mod score;
@@ -1,14 +1,27 @@
 use crate::modules::league::application::dto::LeaderboardEntry;
+use crate::modules::league::domain::entities::leaderboard_ranking::ClanStanding;
 use crate::shared::domain::base_error::AppError;
 use async_trait::async_trait;
 use uuid::Uuid;
 
 #[async_trait]
 pub trait LeaderboardCache: Send + Sync {
-    async fn update_clan_score(&self, clan_id: Uuid, score: i64) -> Result<(), AppError>;
+    async fn update_clan_score(
+        &self,
+        clan_id: Uuid,
+        tier: &str,
+        score: i64,
+    ) -> Result<(), AppError>;
     async fn get_top_clans(
         &self,
         tier: &str,
         limit: usize,
     ) -> Result<Vec<LeaderboardEntry>, AppError>;
+    /// Rank and neighbourhood of a clan on `tier` (or `"global"`); `None`
+    /// when the clan has no score there yet.
+    async fn get_clan_standing(
+        &self,
+        tier: &str,
+        clan_id: Uuid,
+    ) -> Result<Option<ClanStanding>, AppError>;
 }
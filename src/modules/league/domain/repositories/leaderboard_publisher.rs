@@ -0,0 +1,9 @@
+use crate::modules::league::domain::entities::leaderboard_update::LeaderboardUpdate;
+use crate::shared::domain::base_error::AppError;
+use async_trait::async_trait;
+
+/// Port for announcing leaderboard changes to live subscribers.
+#[async_trait]
+pub trait LeaderboardPublisher: Send + Sync {
+    async fn publish(&self, update: &LeaderboardUpdate) -> Result<(), AppError>;
+}
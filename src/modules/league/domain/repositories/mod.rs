@@ -1,5 +1,7 @@
 pub mod clan_repository;
 pub mod leaderboard_cache;
+pub mod leaderboard_publisher;
 
 pub use clan_repository::ClanRepository;
 pub use leaderboard_cache::LeaderboardCache;
+pub use leaderboard_publisher::LeaderboardPublisher;
@@ -3,17 +3,32 @@ use crate::modules::league::domain::entities::clan::ClanTier;
 use crate::modules::league::domain::entities::clan_member::ClanMember;
 use crate::modules::league::domain::repositories::ClanRepository;
 use crate::shared::domain::base_error::AppError;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
 use async_trait::async_trait;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
 pub struct ClanPostgresRepo {
-    pool: PgPool,
+    db: PgHandle,
 }
 
 impl ClanPostgresRepo {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
     }
 }
 
@@ -21,6 +36,7 @@ impl ClanPostgresRepo {
 impl ClanRepository for ClanPostgresRepo {
     /// Inserts a new clan record into the clans table.
     async fn create_clan(&self, clan: &Clan) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
         sqlx::query(
             "INSERT INTO clans (id, name, leader_id, tier, total_score, created_at) VALUES ($1, $2, $3, $4, $5, $6)"
         )
@@ -30,7 +46,7 @@ impl ClanRepository for ClanPostgresRepo {
         .bind(clan.tier().to_string())
         .bind(clan.total_score())
         .bind(clan.created_at())
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await
         .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -42,11 +58,12 @@ impl ClanRepository for ClanPostgresRepo {
     /// Maps database tier string ("Bronze", "Silver", etc.) to ClanTier enum.
     /// Returns None if clan does not exist.
     async fn get_clan_by_id(&self, clan_id: Uuid) -> Result<Option<Clan>, AppError> {
+        let mut conn = self.conn().await?;
         let row = sqlx::query_as::<_, ClanRow>(
             "SELECT id, name, leader_id, tier, total_score::int8, created_at FROM clans WHERE id = $1",
         )
         .bind(clan_id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -73,11 +90,12 @@ impl ClanRepository for ClanPostgresRepo {
 
     /// Inserts a new member into the clan_members table.
     async fn add_member(&self, member: &ClanMember) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
         sqlx::query("INSERT INTO clan_members (clan_id, user_id, joined_at) VALUES ($1, $2, $3)")
             .bind(member.clan_id())
             .bind(member.user_id())
             .bind(member.joined_at())
-            .execute(&self.pool)
+            .execute(&mut *conn)
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -97,10 +115,11 @@ impl ClanRepository for ClanPostgresRepo {
         };
 
         // Get all members for this clan
+        let mut conn = self.conn().await?;
         let rows =
             sqlx::query("SELECT clan_id, user_id, joined_at FROM clan_members WHERE clan_id = $1")
                 .bind(clan_id)
-                .fetch_all(&self.pool)
+                .fetch_all(&mut *conn)
                 .await
                 .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -128,9 +147,10 @@ impl ClanRepository for ClanPostgresRepo {
     ///
     /// Used for validation before creating or joining a clan.
     async fn is_user_in_any_clan(&self, user_id: Uuid) -> Result<bool, AppError> {
+        let mut conn = self.conn().await?;
         let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM clan_members WHERE user_id = $1")
             .bind(user_id)
-            .fetch_one(&self.pool)
+            .fetch_one(&mut *conn)
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -138,9 +158,10 @@ impl ClanRepository for ClanPostgresRepo {
     }
 
     async fn get_user_clan_id(&self, user_id: Uuid) -> Result<Option<Uuid>, AppError> {
+        let mut conn = self.conn().await?;
         let row = sqlx::query("SELECT clan_id FROM clan_members WHERE user_id = $1")
             .bind(user_id)
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *conn)
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
@@ -150,10 +171,11 @@ impl ClanRepository for ClanPostgresRepo {
         }
     }
     async fn add_score(&self, clan_id: Uuid, score: i64) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
         sqlx::query("UPDATE clans SET total_score = total_score + $1 WHERE id = $2")
             .bind(score)
             .bind(clan_id)
-            .execute(&self.pool)
+            .execute(&mut *conn)
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
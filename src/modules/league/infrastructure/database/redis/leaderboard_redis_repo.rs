@@ -1,62 +1,150 @@
 use crate::modules::league::application::dto::LeaderboardEntry;
-use crate::modules::league::domain::repositories::LeaderboardCache;
+use crate::modules::league::domain::entities::leaderboard_ranking::{
+    ClanStanding, RankingMode, Standing,
+};
+use crate::modules::league::domain::entities::leaderboard_update::LeaderboardUpdate;
+use crate::modules::league::domain::repositories::{LeaderboardCache, LeaderboardPublisher};
 use crate::shared::domain::base_error::AppError;
 use async_trait::async_trait;
 use redis::aio::MultiplexedConnection;
+use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct LeaderboardRedisRepo {
     conn: MultiplexedConnection,
+    publisher: Option<Arc<dyn LeaderboardPublisher>>,
+    ranking_mode: RankingMode,
 }
 
 impl LeaderboardRedisRepo {
     pub fn new(conn: MultiplexedConnection) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            publisher: None,
+            ranking_mode: RankingMode::default(),
+        }
+    }
+
+    /// Announces every score change through `publisher` so live
+    /// leaderboard streams can refresh.
+    pub fn with_publisher(mut self, publisher: Arc<dyn LeaderboardPublisher>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    pub fn with_ranking_mode(mut self, ranking_mode: RankingMode) -> Self {
+        self.ranking_mode = ranking_mode;
+        self
     }
 
     fn get_key(&self, tier: &str) -> String {
         format!("leaderboard:{}", tier)
     }
+
+    /// Hash of clan id -> epoch millis at which the clan reached its current
+    /// score in the sorted set `leaderboard:{tier}`. Used to break ties.
+    fn get_reached_at_key(&self, tier: &str) -> String {
+        format!("leaderboard:{}:reached_at", tier)
+    }
+
+    async fn fetch_reached_at(
+        &self,
+        tier: &str,
+        members: &[String],
+    ) -> Result<Vec<Option<i64>>, AppError> {
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut con = self.conn.clone();
+
+        redis::cmd("HMGET")
+            .arg(self.get_reached_at_key(tier))
+            .arg(members)
+            .query_async(&mut con)
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
+    }
+}
+
+fn parse_score(raw: &str) -> i64 {
+    raw.parse::<f64>().unwrap_or(0.0) as i64
 }
 
 #[async_trait]
 impl LeaderboardCache for LeaderboardRedisRepo {
     /// Increments clan score in Redis using ZINCRBY command.
     ///
-    /// Updates the global leaderboard (not tier-specific).
-    /// Thread-safe atomic increment operation.
-    async fn update_clan_score(&self, clan_id: Uuid, score: i64) -> Result<(), AppError> {
+    /// Updates both the global leaderboard and the clan's tier leaderboard,
+    /// and records when the clan reached its new score for tie-breaking.
+    /// Then publishes the new tier score to live subscribers (if a publisher
+    /// is attached). A failed publish is logged but does not fail the update.
+    async fn update_clan_score(
+        &self,
+        clan_id: Uuid,
+        tier: &str,
+        score: i64,
+    ) -> Result<(), AppError> {
         let mut con = self.conn.clone();
 
-        let key = self.get_key("global");
+        let member = clan_id.to_string();
+        let reached_at = chrono::Utc::now().timestamp_millis();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for key in ["global", tier] {
+            pipe.cmd("ZINCRBY")
+                .arg(self.get_key(key))
+                .arg(score)
+                .arg(&member);
+            // A zero increment leaves the score untouched, so the clan keeps
+            // its original place among equal scores.
+            if score != 0 {
+                pipe.cmd("HSET")
+                    .arg(self.get_reached_at_key(key))
+                    .arg(&member)
+                    .arg(reached_at)
+                    .ignore();
+            }
+        }
 
         // ZINCRBY returns the new score as a string
-        let _: String = redis::cmd("ZINCRBY")
-            .arg(&key)
-            .arg(score)
-            .arg(clan_id.to_string())
+        let (_, tier_score): (String, String) = pipe
             .query_async(&mut con)
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
+        if let Some(publisher) = &self.publisher {
+            let update = LeaderboardUpdate::new(clan_id, tier, parse_score(&tier_score));
+            if let Err(e) = publisher.publish(&update).await {
+                tracing::warn!("Failed to publish leaderboard update: {}", e);
+            }
+        }
+
         Ok(())
     }
 
     /// Fetches top clans from Redis sorted set using ZREVRANGE.
     ///
     /// Redis key format: "leaderboard:{tier}"
-    /// Returns clans with ranks 1-10 by default. Score is parsed as i64.
+    /// Clans tied on the last score of the page are pulled in as well so the
+    /// tie-break (earliest to reach the score wins) decides who makes the
+    /// cut, rather than Redis's lexicographic member order.
     async fn get_top_clans(
         &self,
         tier: &str,
         limit: usize,
     ) -> Result<Vec<LeaderboardEntry>, AppError> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
         let mut con = self.conn.clone();
 
         let key = self.get_key(tier);
 
         // ZREVRANGE with WITHSCORES returns Vec<(String, String)>
-        let results: Vec<(String, String)> = redis::cmd("ZREVRANGE")
+        let mut results: Vec<(String, String)> = redis::cmd("ZREVRANGE")
             .arg(&key)
             .arg(0)
             .arg(limit - 1)
@@ -65,22 +153,137 @@ impl LeaderboardCache for LeaderboardRedisRepo {
             .await
             .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
-        let entries: Vec<LeaderboardEntry> = results
+        if results.len() == limit
+            && let Some((_, boundary)) = results.last().cloned()
+        {
+            let tied: Vec<String> = redis::cmd("ZRANGEBYSCORE")
+                .arg(&key)
+                .arg(&boundary)
+                .arg(&boundary)
+                .query_async(&mut con)
+                .await
+                .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+            results.retain(|(_, score)| score != &boundary);
+            results.extend(tied.into_iter().map(|member| (member, boundary.clone())));
+        }
+
+        let members: Vec<String> = results.iter().map(|(member, _)| member.clone()).collect();
+        let reached_at = self.fetch_reached_at(tier, &members).await?;
+
+        let mut standings: Vec<(Standing, &str)> = results
+            .iter()
+            .zip(reached_at)
+            .map(|((clan_id_str, score_str), reached_at)| {
+                let standing = Standing {
+                    clan_id: Uuid::parse_str(clan_id_str).unwrap_or_else(|_| Uuid::nil()),
+                    score: parse_score(score_str),
+                    reached_at,
+                };
+                (standing, clan_id_str.as_str())
+            })
+            .collect();
+
+        standings.sort_by(|(a, _), (b, _)| a.cmp_rank(b));
+        standings.truncate(limit);
+
+        let ordered: Vec<Standing> = standings.iter().map(|(standing, _)| *standing).collect();
+        let ranks = self.ranking_mode.assign_ranks(&ordered);
+
+        let entries: Vec<LeaderboardEntry> = standings
             .iter()
-            .enumerate()
-            .map(|(idx, (clan_id_str, score_str))| {
-                let clan_id = Uuid::parse_str(clan_id_str).unwrap_or_else(|_| Uuid::nil());
-                let total_score: i64 = score_str.parse().unwrap_or(0);
-                LeaderboardEntry {
-                    clan_id,
-                    clan_name: format!("Clan {}", &clan_id_str[..8.min(clan_id_str.len())]),
-                    total_score,
-                    tier: tier.to_string(),
-                    rank: idx + 1,
-                }
+            .zip(ranks)
+            .map(|((standing, clan_id_str), rank)| LeaderboardEntry {
+                clan_id: standing.clan_id,
+                clan_name: format!("Clan {}", &clan_id_str[..8.min(clan_id_str.len())]),
+                total_score: standing.score,
+                tier: tier.to_string(),
+                rank,
             })
             .collect();
 
         Ok(entries)
     }
+
+    /// Computes a clan's rank from ZSCORE/ZCARD/ZCOUNT plus the tie-break hash.
+    ///
+    /// ZREVRANK is not used on its own because it orders equal scores by
+    /// member name; instead the rank counts clans with a strictly higher
+    /// score and the tied clans that reached the score first.
+    async fn get_clan_standing(
+        &self,
+        tier: &str,
+        clan_id: Uuid,
+    ) -> Result<Option<ClanStanding>, AppError> {
+        let mut con = self.conn.clone();
+
+        let key = self.get_key(tier);
+        let member = clan_id.to_string();
+
+        let (score, total): (Option<String>, usize) = redis::pipe()
+            .cmd("ZSCORE")
+            .arg(&key)
+            .arg(&member)
+            .cmd("ZCARD")
+            .arg(&key)
+            .query_async(&mut con)
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        let Some(score) = score else {
+            return Ok(None);
+        };
+
+        let exclusive = format!("({}", score);
+        let (higher, tied, next_higher): (usize, Vec<String>, Vec<(String, String)>) =
+            redis::pipe()
+                .cmd("ZCOUNT")
+                .arg(&key)
+                .arg(&exclusive)
+                .arg("+inf")
+                .cmd("ZRANGEBYSCORE")
+                .arg(&key)
+                .arg(&score)
+                .arg(&score)
+                .cmd("ZRANGEBYSCORE")
+                .arg(&key)
+                .arg(&exclusive)
+                .arg("+inf")
+                .arg("WITHSCORES")
+                .arg("LIMIT")
+                .arg(0)
+                .arg(1)
+                .query_async(&mut con)
+                .await
+                .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        let score = parse_score(&score);
+        let reached_at = self.fetch_reached_at(tier, &tied).await?;
+        let standings: Vec<Standing> = tied
+            .iter()
+            .zip(reached_at)
+            .map(|(id, reached_at)| Standing {
+                clan_id: Uuid::parse_str(id).unwrap_or_else(|_| Uuid::nil()),
+                score,
+                reached_at,
+            })
+            .collect();
+
+        let tied_ahead = match standings.iter().find(|s| s.clan_id == clan_id) {
+            Some(own) => standings.iter().filter(|s| s.outranks(own)).count(),
+            None => 0,
+        };
+        let next_higher = next_higher.first().map(|(_, raw)| parse_score(raw));
+
+        Ok(Some(ClanStanding {
+            rank: self.ranking_mode.rank_from_counts(higher, tied_ahead),
+            total,
+            score,
+            points_to_next_rank: self.ranking_mode.points_to_next_rank(
+                score,
+                tied_ahead,
+                next_higher,
+            ),
+        }))
+    }
 }
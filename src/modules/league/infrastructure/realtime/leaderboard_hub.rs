@@ -0,0 +1,117 @@
+use crate::modules::league::domain::entities::leaderboard_update::LeaderboardUpdate;
+use crate::modules::league::domain::repositories::LeaderboardPublisher;
+use crate::shared::domain::base_error::AppError;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use redis::aio::MultiplexedConnection;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+/// Redis pub/sub channel shared by every engine instance.
+pub const LEADERBOARD_CHANNEL: &str = "leaderboard:updates";
+
+const DEFAULT_CAPACITY: usize = 256;
+const RELAY_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// In-process fan-out point for leaderboard updates.
+///
+/// Every SSE connection subscribes to the hub's broadcast channel. How
+/// updates reach the hub depends on the deployment:
+/// - `local`: `publish` sends straight into the broadcast channel, which is
+///   enough when a single instance serves all clients.
+/// - `redis`: `publish` goes through Redis pub/sub and a relay task
+///   (`spawn_redis_relay`) feeds the hub on every instance.
+#[derive(Clone, Debug)]
+pub struct LeaderboardHub {
+    sender: broadcast::Sender<LeaderboardUpdate>,
+    redis: Option<MultiplexedConnection>,
+}
+
+impl LeaderboardHub {
+    pub fn local() -> Self {
+        let (sender, _) = broadcast::channel(DEFAULT_CAPACITY);
+        Self {
+            sender,
+            redis: None,
+        }
+    }
+
+    pub fn redis(conn: MultiplexedConnection) -> Self {
+        let (sender, _) = broadcast::channel(DEFAULT_CAPACITY);
+        Self {
+            sender,
+            redis: Some(conn),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<LeaderboardUpdate> {
+        self.sender.subscribe()
+    }
+
+    /// Hands an update to the clients connected to this instance.
+    pub fn dispatch(&self, update: LeaderboardUpdate) {
+        // An error only means nobody is listening right now.
+        let _ = self.sender.send(update);
+    }
+}
+
+#[async_trait]
+impl LeaderboardPublisher for LeaderboardHub {
+    async fn publish(&self, update: &LeaderboardUpdate) -> Result<(), AppError> {
+        let Some(conn) = &self.redis else {
+            self.dispatch(update.clone());
+            return Ok(());
+        };
+
+        let payload =
+            serde_json::to_string(update).map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        let mut con = conn.clone();
+        let _: i64 = redis::cmd("PUBLISH")
+            .arg(LEADERBOARD_CHANNEL)
+            .arg(payload)
+            .query_async(&mut con)
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Subscribes to `LEADERBOARD_CHANNEL` and forwards every message into `hub`.
+///
+/// Runs until the runtime shuts down, reconnecting after a short delay
+/// whenever the subscription drops.
+pub fn spawn_redis_relay(client: redis::Client, hub: LeaderboardHub) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            match client.get_async_pubsub().await {
+                Ok(mut pubsub) => {
+                    if let Err(e) = pubsub.subscribe(LEADERBOARD_CHANNEL).await {
+                        tracing::warn!("Failed to subscribe to {}: {}", LEADERBOARD_CHANNEL, e);
+                    } else {
+                        let mut messages = pubsub.on_message();
+                        while let Some(msg) = messages.next().await {
+                            let payload: String = match msg.get_payload() {
+                                Ok(payload) => payload,
+                                Err(e) => {
+                                    tracing::warn!("Invalid leaderboard payload: {}", e);
+                                    continue;
+                                }
+                            };
+                            match serde_json::from_str::<LeaderboardUpdate>(&payload) {
+                                Ok(update) => hub.dispatch(update),
+                                Err(e) => tracing::warn!("Invalid leaderboard payload: {}", e),
+                            }
+                        }
+                        tracing::warn!("Leaderboard relay subscription closed");
+                    }
+                }
+                Err(e) => tracing::warn!("Leaderboard relay failed to connect: {}", e),
+            }
+
+            tokio::time::sleep(RELAY_RETRY_DELAY).await;
+        }
+    })
+}
@@ -0,0 +1,51 @@
+use crate::modules::league::domain::entities::leaderboard_update::LeaderboardUpdate;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+/// A single client's view of the hub, filtered to one tier and debounced.
+pub struct LeaderboardSubscription {
+    receiver: broadcast::Receiver<LeaderboardUpdate>,
+    tier: String,
+    debounce: Duration,
+}
+
+impl LeaderboardSubscription {
+    pub fn new(
+        receiver: broadcast::Receiver<LeaderboardUpdate>,
+        tier: impl Into<String>,
+        debounce: Duration,
+    ) -> Self {
+        Self {
+            receiver,
+            tier: tier.into(),
+            debounce,
+        }
+    }
+
+    /// Waits until the subscribed tier changes.
+    ///
+    /// After the first relevant update, keeps absorbing updates for the
+    /// debounce window so a burst of score changes results in a single
+    /// refresh. Returns `false` once the hub is gone.
+    pub async fn changed(&mut self) -> bool {
+        loop {
+            match self.receiver.recv().await {
+                Ok(update) if update.affects_tier(&self.tier) => break,
+                Ok(_) => continue,
+                // Missed updates still mean the view is stale.
+                Err(RecvError::Lagged(_)) => break,
+                Err(RecvError::Closed) => return false,
+            }
+        }
+
+        tokio::time::sleep(self.debounce).await;
+
+        loop {
+            match self.receiver.try_recv() {
+                Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+                Err(TryRecvError::Empty) => return true,
+                Err(TryRecvError::Closed) => return false,
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod leaderboard_hub;
+pub mod leaderboard_subscription;
+
+pub use leaderboard_hub::{LEADERBOARD_CHANNEL, LeaderboardHub, spawn_redis_relay};
+pub use leaderboard_subscription::LeaderboardSubscription;
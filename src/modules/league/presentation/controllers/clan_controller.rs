@@ -16,6 +16,7 @@ use crate::modules::league::domain::entities::clan::Clan;
 use crate::modules::league::domain::entities::clan_member::ClanMember;
 use crate::modules::league::domain::errors::LeagueError;
 use crate::modules::league::infrastructure::database::postgres::ClanPostgresRepo;
+use crate::modules::league::infrastructure::database::redis::LeaderboardRedisRepo;
 use crate::shared::domain::base_error::AppError;
 use crate::shared::utils::response::ApiResponse;
 use uuid::Uuid;
@@ -125,7 +126,9 @@ pub async fn get_user_tier_handler(
     Path(user_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<UserTierDto>>, LeagueError> {
     let repository = ClanPostgresRepo::new(state.db.clone());
-    let use_case = GetUserTierUseCase::new(repository);
+    let leaderboard = LeaderboardRedisRepo::new(state.redis.clone())
+        .with_ranking_mode(state.config.leaderboard_ranking_mode);
+    let use_case = GetUserTierUseCase::new(repository, leaderboard);
 
     let tier_info = use_case.execute(user_id).await?;
 
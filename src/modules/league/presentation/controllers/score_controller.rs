@@ -1,16 +1,28 @@
 use axum::{
     extract::{Query, State},
-    response::Json,
+    response::{
+        Json,
+        sse::{Event, KeepAlive, Sse},
+    },
 };
+use futures_util::stream::{self, Stream};
+use std::convert::Infallible;
+use std::time::Duration;
 
 use crate::AppState;
 use crate::modules::league::application::GetLeaderboardUseCase;
 use crate::modules::league::application::dto::LeaderboardDto;
 use crate::modules::league::infrastructure::database::redis::LeaderboardRedisRepo;
+use crate::modules::league::infrastructure::realtime::LeaderboardSubscription;
 use crate::shared::domain::base_error::AppError;
 use crate::shared::utils::response::ApiResponse;
 use serde::Deserialize;
 
+/// Bursts of score updates within this window produce a single push.
+const STREAM_DEBOUNCE: Duration = Duration::from_millis(500);
+/// Keeps idle connections alive through proxies.
+const STREAM_HEARTBEAT: Duration = Duration::from_secs(15);
+
 #[derive(Debug, Deserialize)]
 pub struct LeaderboardQuery {
     #[serde(default = "default_tier")]
@@ -37,7 +49,8 @@ pub async fn get_leaderboard_handler(
     State(state): State<AppState>,
     Query(query): Query<LeaderboardQuery>,
 ) -> Result<Json<ApiResponse<LeaderboardDto>>, AppError> {
-    let redis_repo = LeaderboardRedisRepo::new(state.redis);
+    let redis_repo = LeaderboardRedisRepo::new(state.redis)
+        .with_ranking_mode(state.config.leaderboard_ranking_mode);
     let use_case = GetLeaderboardUseCase::new(redis_repo);
 
     let leaderboard = use_case.execute(query.tier).await?;
@@ -47,3 +60,55 @@ pub async fn get_leaderboard_handler(
         leaderboard,
     )))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/leaderboards/stream",
+    params(
+        ("tier" = String, Query, description = "Leaderboard tier (Bronze, Silver, Gold, Diamond)")
+    ),
+    responses(
+        (status = 200, description = "Server-Sent Events stream; each `leaderboard` event carries the current top clans", content_type = "text/event-stream")
+    ),
+    tag = "leaderboard"
+)]
+pub async fn stream_leaderboard_handler(
+    State(state): State<AppState>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    // Subscribe before the first snapshot so no update slips in between.
+    let subscription = LeaderboardSubscription::new(
+        state.leaderboard_hub.subscribe(),
+        query.tier.clone(),
+        STREAM_DEBOUNCE,
+    );
+    let use_case = GetLeaderboardUseCase::new(
+        LeaderboardRedisRepo::new(state.redis)
+            .with_ranking_mode(state.config.leaderboard_ranking_mode),
+    );
+
+    let events = stream::unfold(
+        (subscription, use_case, query.tier, true),
+        |(mut subscription, use_case, tier, first)| async move {
+            if !first && !subscription.changed().await {
+                return None;
+            }
+
+            let event = match use_case.execute(tier.clone()).await {
+                Ok(leaderboard) => Event::default()
+                    .event("leaderboard")
+                    .json_data(&leaderboard)
+                    .unwrap_or_else(|e| Event::default().event("error").data(e.to_string())),
+                Err(e) => Event::default().event("error").data(e.to_string()),
+            };
+
+            Some((Ok(event), (subscription, use_case, tier, false)))
+        },
+    );
+
+    Sse::new(events).keep_alive(
+        KeepAlive::new()
+            .interval(STREAM_HEARTBEAT)
+            .text("heartbeat"),
+    )
+}
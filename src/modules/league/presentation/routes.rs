@@ -14,6 +14,10 @@ pub fn league_routes() -> Router<AppState> {
             "/leaderboards",
             get(score_controller::get_leaderboard_handler),
         )
+        .route(
+            "/leaderboards/stream",
+            get(score_controller::stream_leaderboard_handler),
+        )
         .route(
             "/users/{user_id}/tier",
             get(clan_controller::get_user_tier_handler),
@@ -1,3 +1,4 @@
 pub mod gamification;
 pub mod league;
 pub mod user_sync;
+pub mod webhooks;
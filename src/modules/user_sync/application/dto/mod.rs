@@ -1,7 +1,9 @@
 pub mod quiz_history_dto;
+pub mod quiz_review_dto;
 pub mod sync_user_dto;
 pub mod sync_user_response_dto;
 
-pub use quiz_history_dto::{QuizHistoryRequestDto, QuizHistoryResponseDto};
+pub use quiz_history_dto::{QuizHistoryRequestDto, QuizHistoryResponseDto, QuizQuarantineDto};
+pub use quiz_review_dto::QuizReviewDto;
 pub use sync_user_dto::SyncUserRequestDto;
 pub use sync_user_response_dto::SyncUserResponseDto;
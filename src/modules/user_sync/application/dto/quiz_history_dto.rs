@@ -8,11 +8,33 @@ pub struct QuizHistoryRequestDto {
     pub article_id: Uuid,
     pub score: i32,
     pub accuracy: f64,
+    /// The user's current UTC offset in minutes (e.g. 420 for WIB). It becomes
+    /// the user's timezone, in which streak and mission days are counted; when
+    /// absent the last synced one (or `DEFAULT_UTC_OFFSET_MINUTES`) applies.
+    #[serde(default)]
+    pub utc_offset_minutes: Option<i32>,
+    /// Id of the upstream (outbox) event. A retried delivery with the same id is
+    /// not applied again; it gets the first delivery's result back.
+    #[serde(default)]
+    pub event_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct QuizHistoryResponseDto {
     pub user_id: Uuid,
+    /// For a quiz held for review, the id of the review.
+    pub quiz_id: Uuid,
     pub missions_updated: i32,
+    /// The event was already processed and this is the stored result.
+    pub replayed: bool,
+    /// Set when the fraud detectors held the quiz for review; nothing was credited.
+    pub quarantine: Option<QuizQuarantineDto>,
     pub message: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuizQuarantineDto {
+    pub review_id: Uuid,
+    /// `rate_limit`, `impossible_frequency`, `article_spam` or `score_inconsistent`.
+    pub reason: String,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::user_sync::domain::entities::quiz_review::QuarantinedQuiz;
+
+/// A held quiz sync as shown in the admin review queue.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuizReviewDto {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub score: i32,
+    pub accuracy: f64,
+    pub event_id: Option<Uuid>,
+    /// `rate_limit`, `impossible_frequency`, `article_spam` or `score_inconsistent`.
+    pub reason: String,
+    pub detail: String,
+    /// `pending`, `approved` or `rejected`.
+    pub status: String,
+    /// The quiz credited when the review was approved.
+    pub quiz_id: Option<Uuid>,
+    /// When the user finished the quiz; approval credits it as of then.
+    pub completed_at: DateTime<Utc>,
+    pub received_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<&QuarantinedQuiz> for QuizReviewDto {
+    fn from(quiz: &QuarantinedQuiz) -> Self {
+        Self {
+            id: quiz.id,
+            user_id: quiz.user_id,
+            article_id: quiz.article_id,
+            score: quiz.score,
+            accuracy: quiz.accuracy,
+            event_id: quiz.event_id,
+            reason: quiz.reason.to_string(),
+            detail: quiz.detail.clone(),
+            status: quiz.status.to_string(),
+            quiz_id: quiz.quiz_id,
+            completed_at: quiz.completed_at,
+            received_at: quiz.received_at,
+            reviewed_at: quiz.reviewed_at,
+        }
+    }
+}
@@ -5,4 +5,8 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SyncUserRequestDto {
     pub user_id: Uuid,
+    /// The user's UTC offset in minutes (e.g. 420 for WIB). Missions and
+    /// streaks count days in it; when absent the synced one is kept.
+    #[serde(default)]
+    pub utc_offset_minutes: Option<i32>,
 }
@@ -3,5 +3,8 @@
 pub mod dto;
 pub mod use_cases;
 
+pub use use_cases::ListQuizReviewsUseCase;
+pub use use_cases::ProcessQuizSyncUseCase;
+pub use use_cases::RejectQuizReviewUseCase;
 pub use use_cases::SyncNewUserUseCase;
 pub use use_cases::SyncQuizHistoryUseCase;
@@ -0,0 +1,84 @@
+use crate::modules::user_sync::application::dto::QuizReviewDto;
+use crate::modules::user_sync::domain::entities::quiz_review::QuizReviewStatus;
+use crate::modules::user_sync::domain::errors::UserSyncError;
+use crate::modules::user_sync::domain::repositories::QuizReviewRepository;
+use std::sync::Arc;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+pub struct ListQuizReviewsUseCase {
+    reviews: Arc<dyn QuizReviewRepository>,
+}
+
+impl ListQuizReviewsUseCase {
+    pub fn new(reviews: Arc<dyn QuizReviewRepository>) -> Self {
+        Self { reviews }
+    }
+
+    /// The review queue, most recently held first.
+    pub async fn execute(
+        &self,
+        status: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<QuizReviewDto>, UserSyncError> {
+        let status = status
+            .map(|s| s.parse::<QuizReviewStatus>())
+            .transpose()
+            .map_err(|_| {
+                UserSyncError::ValidationError(
+                    "Status harus pending, approved, atau rejected.".to_string(),
+                )
+            })?;
+        let limit = limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(UserSyncError::ValidationError(format!(
+                "Limit harus di antara 1 dan {}.",
+                MAX_LIMIT
+            )));
+        }
+
+        let held = self
+            .reviews
+            .list(status, limit)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        Ok(held.iter().map(QuizReviewDto::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::user_sync::domain::repositories::quiz_review_repository::MockQuizReviewRepository;
+
+    #[tokio::test]
+    async fn test_rejects_unknown_status_and_limit_out_of_range() {
+        let use_case = ListQuizReviewsUseCase::new(Arc::new(MockQuizReviewRepository::new()));
+
+        assert!(matches!(
+            use_case.execute(Some("closed".to_string()), None).await,
+            Err(UserSyncError::ValidationError(_))
+        ));
+        assert!(matches!(
+            use_case.execute(None, Some(500)).await,
+            Err(UserSyncError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lists_pending_reviews_with_default_limit() {
+        let mut reviews = MockQuizReviewRepository::new();
+        reviews
+            .expect_list()
+            .withf(|status, limit| *status == Some(QuizReviewStatus::Pending) && *limit == 50)
+            .returning(|_, _| Ok(vec![]));
+
+        let listed = ListQuizReviewsUseCase::new(Arc::new(reviews))
+            .execute(Some("pending".to_string()), None)
+            .await
+            .unwrap();
+
+        assert!(listed.is_empty());
+    }
+}
@@ -1,7 +1,13 @@
 // User Sync Use Cases
 
+pub mod list_quiz_reviews_usecase;
+pub mod process_quiz_sync_usecase;
+pub mod reject_quiz_review_usecase;
 pub mod sync_new_user_usecase;
 pub mod sync_quiz_history_usecase;
 
+pub use list_quiz_reviews_usecase::ListQuizReviewsUseCase;
+pub use process_quiz_sync_usecase::ProcessQuizSyncUseCase;
+pub use reject_quiz_review_usecase::RejectQuizReviewUseCase;
 pub use sync_new_user_usecase::SyncNewUserUseCase;
 pub use sync_quiz_history_usecase::SyncQuizHistoryUseCase;
@@ -0,0 +1,185 @@
+use crate::modules::gamification::application::{
+    SyncQuizGamificationUseCase, SyncQuizHistoryRequestDto,
+};
+use crate::modules::gamification::domain::entities::user_streak::StreakPolicy;
+use crate::modules::user_sync::application::dto::QuizHistoryRequestDto;
+use crate::modules::user_sync::application::dto::QuizHistoryResponseDto;
+use crate::modules::user_sync::application::dto::QuizReviewDto;
+use crate::modules::user_sync::application::use_cases::SyncQuizHistoryUseCase;
+use crate::modules::user_sync::domain::entities::quiz_fraud::QuizFraudChain;
+use crate::modules::user_sync::domain::entities::quiz_review::QuizReviewStatus;
+use crate::modules::user_sync::domain::errors::UserSyncError;
+use crate::shared::domain::unit_of_work::{UnitOfWork, UnitOfWorkFactory, finish};
+use crate::shared::domain::user_timezone::{UserClock, UserTimezone};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ProcessQuizSyncUseCase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    streak_policy: StreakPolicy,
+    default_timezone: UserTimezone,
+    fraud_detectors: Option<QuizFraudChain>,
+}
+
+impl ProcessQuizSyncUseCase {
+    pub fn new(
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+        streak_policy: StreakPolicy,
+        default_timezone: UserTimezone,
+    ) -> Self {
+        Self {
+            unit_of_work,
+            streak_policy,
+            default_timezone,
+            fraud_detectors: None,
+        }
+    }
+
+    /// Holds quizzes flagged by `detectors` for review instead of crediting them.
+    pub fn with_fraud_detection(mut self, detectors: QuizFraudChain) -> Self {
+        self.fraud_detectors = Some(detectors);
+        self
+    }
+
+    /// Records the quiz, its score, the gamification progress it earns and the
+    /// outbox events announcing that progress as one unit of work. When any
+    /// step fails nothing is kept, so the Java core can retry the delivery as a
+    /// whole. A quiz the fraud detectors flag only adds its review.
+    pub async fn execute(
+        &self,
+        dto: QuizHistoryRequestDto,
+    ) -> Result<QuizHistoryResponseDto, UserSyncError> {
+        let uow = self
+            .unit_of_work
+            .begin()
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+
+        let applied = self
+            .apply(uow.as_ref(), dto, self.fraud_detectors.as_ref(), Utc::now())
+            .await;
+        finish(uow.as_ref(), applied).await
+    }
+
+    /// Credits a held quiz as a sync completed when the user finished it,
+    /// without screening it again, and closes its review as approved at `at` in
+    /// the same unit of work.
+    pub async fn approve_review(
+        &self,
+        review_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<QuizReviewDto, UserSyncError> {
+        let uow = self
+            .unit_of_work
+            .begin()
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+
+        let approved = async {
+            let reviews = uow.quiz_reviews();
+            let held = reviews
+                .get(review_id)
+                .await
+                .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?
+                .ok_or_else(|| review_not_found(review_id))?;
+            if !held.is_pending() {
+                return Err(review_resolved(review_id, held.status));
+            }
+
+            let dto = QuizHistoryRequestDto {
+                user_id: held.user_id,
+                article_id: held.article_id,
+                score: held.score,
+                accuracy: held.accuracy,
+                utc_offset_minutes: None,
+                event_id: held.event_id,
+            };
+            let credited = self
+                .apply(uow.as_ref(), dto, None, held.completed_at)
+                .await?;
+
+            // Only one reviewer gets the pending row; the other rolls back.
+            let resolved = reviews
+                .resolve(
+                    review_id,
+                    QuizReviewStatus::Approved,
+                    Some(credited.quiz_id),
+                    at,
+                )
+                .await
+                .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?
+                .ok_or_else(|| review_resolved(review_id, QuizReviewStatus::Approved))?;
+            Ok(QuizReviewDto::from(&resolved))
+        }
+        .await;
+        finish(uow.as_ref(), approved).await
+    }
+
+    async fn apply(
+        &self,
+        uow: &dyn UnitOfWork,
+        dto: QuizHistoryRequestDto,
+        fraud_detectors: Option<&QuizFraudChain>,
+        completed_at: DateTime<Utc>,
+    ) -> Result<QuizHistoryResponseDto, UserSyncError> {
+        let reported_timezone = dto
+            .utc_offset_minutes
+            .map(UserTimezone::from_offset_minutes)
+            .transpose()
+            .map_err(|e| UserSyncError::ValidationError(e.to_string()))?;
+        let mut gamification_payload = SyncQuizHistoryRequestDto {
+            user_id: dto.user_id,
+            article_id: dto.article_id,
+            score: dto.score,
+            accuracy: dto.accuracy,
+            timezone: self.default_timezone,
+            completed_at,
+        };
+
+        let user_id = dto.user_id;
+        uow.lock_user(user_id)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        let mut quiz_sync = SyncQuizHistoryUseCase::new(uow.users(), uow.quiz_histories());
+        if let Some(detectors) = fraud_detectors {
+            quiz_sync = quiz_sync.with_fraud_screening(detectors.clone(), uow.quiz_reviews());
+        }
+        let mut response = quiz_sync.execute_at(dto, completed_at).await?;
+        if response.replayed || response.quarantine.is_some() {
+            return Ok(response);
+        }
+
+        gamification_payload.timezone = UserClock::new(uow.timezones(), self.default_timezone)
+            .timezone(gamification_payload.user_id, reported_timezone)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        let gamification = SyncQuizGamificationUseCase::new(
+            uow.missions(),
+            uow.achievements(),
+            uow.streaks(),
+            self.streak_policy.clone(),
+        )
+        .with_outbox(uow.outbox());
+        response.missions_updated = gamification
+            .execute(gamification_payload)
+            .await
+            .map_err(|e| UserSyncError::SyncFailed(e.to_string()))?;
+        quiz_sync
+            .record_result(response.quiz_id, response.missions_updated)
+            .await?;
+
+        Ok(response)
+    }
+}
+
+pub(crate) fn review_not_found(review_id: Uuid) -> UserSyncError {
+    UserSyncError::ReviewNotFound(format!("Review kuis {} tidak ditemukan", review_id))
+}
+
+pub(crate) fn review_resolved(review_id: Uuid, status: QuizReviewStatus) -> UserSyncError {
+    UserSyncError::ReviewAlreadyResolved(format!(
+        "Review kuis {} sudah diputuskan ({})",
+        review_id, status
+    ))
+}
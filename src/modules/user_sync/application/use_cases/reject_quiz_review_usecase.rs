@@ -0,0 +1,47 @@
+use crate::modules::user_sync::application::dto::QuizReviewDto;
+use crate::modules::user_sync::application::use_cases::process_quiz_sync_usecase::{
+    review_not_found, review_resolved,
+};
+use crate::modules::user_sync::domain::entities::quiz_review::QuizReviewStatus;
+use crate::modules::user_sync::domain::errors::UserSyncError;
+use crate::modules::user_sync::domain::repositories::QuizReviewRepository;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RejectQuizReviewUseCase {
+    reviews: Arc<dyn QuizReviewRepository>,
+}
+
+impl RejectQuizReviewUseCase {
+    pub fn new(reviews: Arc<dyn QuizReviewRepository>) -> Self {
+        Self { reviews }
+    }
+
+    /// Closes a pending review without crediting anything. Redeliveries of its
+    /// event keep being answered as held.
+    pub async fn execute(
+        &self,
+        review_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<QuizReviewDto, UserSyncError> {
+        let resolved = self
+            .reviews
+            .resolve(review_id, QuizReviewStatus::Rejected, None, at)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        if let Some(rejected) = resolved {
+            return Ok(QuizReviewDto::from(&rejected));
+        }
+
+        let held = self
+            .reviews
+            .get(review_id)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        Err(match held {
+            Some(held) => review_resolved(review_id, held.status),
+            None => review_not_found(review_id),
+        })
+    }
+}
@@ -1,29 +1,64 @@
 use crate::modules::user_sync::domain::entities::ShadowUser;
 use crate::modules::user_sync::domain::errors::UserSyncError;
 use crate::modules::user_sync::domain::repositories::UserRepository;
+use crate::shared::domain::user_timezone::UserTimezone;
+use crate::shared::domain::user_timezone_repository::UserTimezoneRepository;
+use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct SyncNewUserUseCase<R: UserRepository> {
     repository: R,
+    timezones: Option<Arc<dyn UserTimezoneRepository>>,
 }
 
 impl<R: UserRepository> SyncNewUserUseCase<R> {
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            timezones: None,
+        }
+    }
+
+    /// Stores the timezone carried by the sync request through `timezones`.
+    pub fn with_timezones(mut self, timezones: Arc<dyn UserTimezoneRepository>) -> Self {
+        self.timezones = Some(timezones);
+        self
     }
 
+    /// Creates the shadow user, or returns it when it already exists, and
+    /// stores the timezone the request carries.
     pub async fn execute(
         &self,
         dto: crate::modules::user_sync::application::dto::SyncUserRequestDto,
     ) -> Result<ShadowUser, UserSyncError> {
+        let timezone = dto
+            .utc_offset_minutes
+            .map(UserTimezone::from_offset_minutes)
+            .transpose()
+            .map_err(|e| UserSyncError::ValidationError(e.to_string()))?;
+
+        let user = self.find_or_insert(dto.user_id).await?;
+
+        if let (Some(timezones), Some(timezone)) = (&self.timezones, timezone) {
+            timezones
+                .set_timezone(dto.user_id, timezone)
+                .await
+                .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(user)
+    }
+
+    async fn find_or_insert(&self, user_id: Uuid) -> Result<ShadowUser, UserSyncError> {
         if self
             .repository
-            .exists_shadow_user(dto.user_id)
+            .exists_shadow_user(user_id)
             .await
             .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?
         {
             let existing = self
                 .repository
-                .get_shadow_user(dto.user_id)
+                .get_shadow_user(user_id)
                 .await
                 .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
             if let Some(user) = existing {
@@ -31,7 +66,7 @@ impl<R: UserRepository> SyncNewUserUseCase<R> {
             }
         }
 
-        let shadow_user = ShadowUser::new(dto.user_id);
+        let shadow_user = ShadowUser::new(user_id);
         self.repository
             .insert_shadow_user(&shadow_user)
             .await
@@ -47,6 +82,7 @@ mod tests {
     use crate::modules::user_sync::application::dto::SyncUserRequestDto;
     use crate::modules::user_sync::domain::entities::ShadowUser;
     use crate::shared::domain::base_error::AppError;
+    use crate::shared::domain::user_timezone_repository::MockUserTimezoneRepository;
     use async_trait::async_trait;
     use uuid::Uuid;
 
@@ -113,6 +149,8 @@ mod tests {
             &self,
             _user_id: Uuid,
             _score_to_add: i32,
+            _quiz_id: Uuid,
+            _occurred_at: chrono::DateTime<chrono::Utc>,
         ) -> Result<(), AppError> {
             Ok(())
         }
@@ -125,7 +163,10 @@ mod tests {
         let repo = MockUserRepository::new();
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         assert!(result.is_ok());
@@ -140,7 +181,10 @@ mod tests {
         let repo = MockUserRepository::with_existing_user(user_id);
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         assert!(result.is_ok());
@@ -155,7 +199,10 @@ mod tests {
         let repo = MockUserRepository::new();
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         // Zero UUID should still be processed (not an error), but it depends on business rules
@@ -172,7 +219,10 @@ mod tests {
         let repo = MockUserRepository::new();
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
 
         let result1 = use_case.execute(dto.clone()).await;
         assert!(result1.is_ok());
@@ -192,7 +242,10 @@ mod tests {
         let repo = MockUserRepository::new().with_insert_failure();
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         assert!(result.is_err());
@@ -206,7 +259,10 @@ mod tests {
         let repo = MockUserRepository::with_existing_user(user_id);
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         assert!(result.is_ok());
@@ -219,7 +275,10 @@ mod tests {
         let repo = MockUserRepository::new();
         let use_case = SyncNewUserUseCase::new(repo);
 
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
         let result = use_case.execute(dto).await;
 
         assert!(result.is_ok());
@@ -227,4 +286,43 @@ mod tests {
         assert_eq!(shadow_user.user_id(), user_id);
         assert_eq!(shadow_user.total_score(), 0);
     }
+
+    #[tokio::test]
+    async fn execute_stores_the_synced_timezone() {
+        let user_id = Uuid::new_v4();
+        let mut timezones = MockUserTimezoneRepository::new();
+        timezones
+            .expect_set_timezone()
+            .withf(move |id, timezone| *id == user_id && timezone.utc_offset_minutes() == 420)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let use_case =
+            SyncNewUserUseCase::new(MockUserRepository::new()).with_timezones(Arc::new(timezones));
+
+        let result = use_case
+            .execute(SyncUserRequestDto {
+                user_id,
+                utc_offset_minutes: Some(420),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_an_impossible_offset_before_writing() {
+        let mut timezones = MockUserTimezoneRepository::new();
+        timezones.expect_set_timezone().never();
+        let use_case = SyncNewUserUseCase::new(MockUserRepository::new().with_insert_failure())
+            .with_timezones(Arc::new(timezones));
+
+        let result = use_case
+            .execute(SyncUserRequestDto {
+                user_id: Uuid::new_v4(),
+                utc_offset_minutes: Some(15 * 60),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UserSyncError::ValidationError(_))));
+    }
 }
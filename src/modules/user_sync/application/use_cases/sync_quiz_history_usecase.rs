@@ -1,13 +1,25 @@
 use crate::modules::user_sync::application::dto::QuizHistoryRequestDto;
 use crate::modules::user_sync::application::dto::QuizHistoryResponseDto;
+use crate::modules::user_sync::application::dto::QuizQuarantineDto;
+use crate::modules::user_sync::domain::entities::quiz_fraud::QuizFraudChain;
 use crate::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+use crate::modules::user_sync::domain::entities::quiz_review::QuarantinedQuiz;
 use crate::modules::user_sync::domain::errors::UserSyncError;
 use crate::modules::user_sync::domain::repositories::QuizHistoryRepository;
+use crate::modules::user_sync::domain::repositories::QuizReviewRepository;
 use crate::modules::user_sync::domain::repositories::UserRepository;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+const SYNCED_MESSAGE: &str = "Data riwayat kuis berhasil dicatat dan diproses oleh Engine";
+const QUARANTINED_MESSAGE: &str =
+    "Data riwayat kuis diterima dan ditahan untuk ditinjau sebelum skornya dihitung";
 
 pub struct SyncQuizHistoryUseCase<U: UserRepository, Q: QuizHistoryRepository> {
     user_repo: U,
     quiz_repo: Q,
+    screening: Option<(QuizFraudChain, Arc<dyn QuizReviewRepository>)>,
 }
 
 impl<U: UserRepository, Q: QuizHistoryRepository> SyncQuizHistoryUseCase<U, Q> {
@@ -15,12 +27,37 @@ impl<U: UserRepository, Q: QuizHistoryRepository> SyncQuizHistoryUseCase<U, Q> {
         Self {
             user_repo,
             quiz_repo,
+            screening: None,
         }
     }
 
+    /// Runs every new quiz past `detectors` first; a flagged one is stored in
+    /// `reviews` instead of being recorded and scored.
+    pub fn with_fraud_screening(
+        mut self,
+        detectors: QuizFraudChain,
+        reviews: Arc<dyn QuizReviewRepository>,
+    ) -> Self {
+        self.screening = Some((detectors, reviews));
+        self
+    }
+
+    /// Records the quiz and adds its score. A quiz whose `event_id` was already
+    /// recorded is not applied again: the first delivery's result is returned
+    /// with `replayed` set. With fraud screening, a suspicious quiz is held for
+    /// review and the response carries its `quarantine`.
     pub async fn execute(
         &self,
         dto: QuizHistoryRequestDto,
+    ) -> Result<QuizHistoryResponseDto, UserSyncError> {
+        self.execute_at(dto, Utc::now()).await
+    }
+
+    /// `execute` for a quiz the user finished at `completed_at`.
+    pub async fn execute_at(
+        &self,
+        dto: QuizHistoryRequestDto,
+        completed_at: DateTime<Utc>,
     ) -> Result<QuizHistoryResponseDto, UserSyncError> {
         if dto.score < 0 {
             return Err(UserSyncError::InvalidQuizData(format!(
@@ -49,22 +86,148 @@ impl<U: UserRepository, Q: QuizHistoryRepository> SyncQuizHistoryUseCase<U, Q> {
             )));
         }
 
-        let quiz = QuizHistory::new(dto.user_id, dto.article_id, dto.score, dto.accuracy);
+        let quiz = QuizHistory::new(dto.user_id, dto.article_id, dto.score, dto.accuracy)
+            .with_event_id(dto.event_id)
+            .with_completed_at(completed_at);
 
-        self.quiz_repo
+        if let Some((detectors, reviews)) = &self.screening
+            && let Some(response) = self.screen(&quiz, detectors, reviews.as_ref()).await?
+        {
+            return Ok(response);
+        }
+
+        let inserted = self
+            .quiz_repo
             .insert_quiz_history(&quiz)
             .await
             .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        if let (false, Some(event_id)) = (inserted, dto.event_id) {
+            return self.replay(event_id).await;
+        }
 
         self.user_repo
-            .update_total_score(dto.user_id, dto.score)
+            .update_total_score(dto.user_id, dto.score, quiz.id(), quiz.completed_at())
             .await
             .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
 
         Ok(QuizHistoryResponseDto {
             user_id: dto.user_id,
+            quiz_id: quiz.id(),
             missions_updated: 0,
-            message: "Data riwayat kuis berhasil dicatat dan diproses oleh Engine".to_string(),
+            replayed: false,
+            quarantine: None,
+            message: SYNCED_MESSAGE.to_string(),
+        })
+    }
+
+    /// The response for a quiz that must not be credited now: a redelivery of
+    /// an event that was already recorded or held, or a quiz the detectors flag.
+    async fn screen(
+        &self,
+        quiz: &QuizHistory,
+        detectors: &QuizFraudChain,
+        reviews: &dyn QuizReviewRepository,
+    ) -> Result<Option<QuizHistoryResponseDto>, UserSyncError> {
+        if let Some(event_id) = quiz.event_id() {
+            let recorded = self
+                .quiz_repo
+                .get_sync_receipt(event_id)
+                .await
+                .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+            if recorded.is_some() {
+                return self.replay(event_id).await.map(Some);
+            }
+            if let Some(held) = find_held(reviews, event_id).await? {
+                return Ok(Some(quarantined(&held, true)));
+            }
+        }
+
+        let recent = self
+            .quiz_repo
+            .get_quiz_histories_since(quiz.user_id(), quiz.completed_at() - detectors.lookback())
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        let Some(flag) = detectors.inspect(quiz, &recent) else {
+            return Ok(None);
+        };
+
+        let held = QuarantinedQuiz::hold(quiz, flag);
+        let stored = reviews
+            .quarantine(&held)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?;
+        if let (false, Some(event_id)) = (stored, quiz.event_id()) {
+            // Another delivery of the same event was held first.
+            if let Some(first) = find_held(reviews, event_id).await? {
+                return Ok(Some(quarantined(&first, true)));
+            }
+        }
+
+        tracing::warn!(
+            "Quiz sync for user {} held for review: {} ({})",
+            held.user_id,
+            held.reason,
+            held.detail
+        );
+        Ok(Some(quarantined(&held, false)))
+    }
+
+    /// Stores the gamification outcome of a recorded quiz for later replays.
+    pub async fn record_result(
+        &self,
+        quiz_id: Uuid,
+        missions_updated: i32,
+    ) -> Result<(), UserSyncError> {
+        self.quiz_repo
+            .record_sync_result(quiz_id, missions_updated)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))
+    }
+
+    async fn replay(&self, event_id: Uuid) -> Result<QuizHistoryResponseDto, UserSyncError> {
+        let receipt = self
+            .quiz_repo
+            .get_sync_receipt(event_id)
+            .await
+            .map_err(|e| UserSyncError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                UserSyncError::SyncFailed(format!(
+                    "Event {} tercatat tetapi riwayat kuisnya tidak ditemukan",
+                    event_id
+                ))
+            })?;
+
+        Ok(QuizHistoryResponseDto {
+            user_id: receipt.user_id,
+            quiz_id: receipt.quiz_id,
+            missions_updated: receipt.missions_updated.unwrap_or(0),
+            replayed: true,
+            quarantine: None,
+            message: SYNCED_MESSAGE.to_string(),
         })
     }
 }
+
+async fn find_held(
+    reviews: &dyn QuizReviewRepository,
+    event_id: Uuid,
+) -> Result<Option<QuarantinedQuiz>, UserSyncError> {
+    reviews
+        .find_by_event(event_id)
+        .await
+        .map_err(|e| UserSyncError::DatabaseError(e.to_string()))
+}
+
+fn quarantined(held: &QuarantinedQuiz, replayed: bool) -> QuizHistoryResponseDto {
+    QuizHistoryResponseDto {
+        user_id: held.user_id,
+        quiz_id: held.id,
+        missions_updated: 0,
+        replayed,
+        quarantine: Some(QuizQuarantineDto {
+            review_id: held.id,
+            reason: held.reason.to_string(),
+        }),
+        message: QUARANTINED_MESSAGE.to_string(),
+    }
+}
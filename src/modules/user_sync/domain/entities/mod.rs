@@ -1,5 +1,11 @@
+pub mod quiz_fraud;
 pub mod quiz_history;
+pub mod quiz_review;
 pub mod shadow_user;
 
-pub use quiz_history::QuizHistory;
+pub use quiz_fraud::{
+    QuizFraudChain, QuizFraudDetector, QuizFraudFlag, QuizFraudPolicy, QuizFraudReason,
+};
+pub use quiz_history::{QuizHistory, QuizSyncReceipt};
+pub use quiz_review::{QuarantinedQuiz, QuizReviewStatus};
 pub use shadow_user::ShadowUser;
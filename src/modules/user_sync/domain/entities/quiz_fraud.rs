@@ -0,0 +1,398 @@
+use chrono::TimeDelta;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+
+/// Why a quiz sync was held back for review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuizFraudReason {
+    /// More quizzes in the window than a reader can take.
+    RateLimit,
+    /// Completed too soon after the previous quiz to have read the article.
+    ImpossibleFrequency,
+    /// The same article taken again and again for its points.
+    ArticleSpam,
+    /// The score does not fit the accuracy it came with.
+    ScoreInconsistent,
+}
+
+impl QuizFraudReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuizFraudReason::RateLimit => "rate_limit",
+            QuizFraudReason::ImpossibleFrequency => "impossible_frequency",
+            QuizFraudReason::ArticleSpam => "article_spam",
+            QuizFraudReason::ScoreInconsistent => "score_inconsistent",
+        }
+    }
+}
+
+impl fmt::Display for QuizFraudReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for QuizFraudReason {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rate_limit" => Ok(QuizFraudReason::RateLimit),
+            "impossible_frequency" => Ok(QuizFraudReason::ImpossibleFrequency),
+            "article_spam" => Ok(QuizFraudReason::ArticleSpam),
+            "score_inconsistent" => Ok(QuizFraudReason::ScoreInconsistent),
+            _ => Err("Alasan karantina kuis tidak dikenal"),
+        }
+    }
+}
+
+/// A detector's verdict on one quiz, with a note for the reviewer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizFraudFlag {
+    pub reason: QuizFraudReason,
+    pub detail: String,
+}
+
+/// One anti-cheat heuristic. `recent` holds the user's recorded quizzes that
+/// completed within `lookback` of the incoming one, newest first.
+pub trait QuizFraudDetector: Send + Sync {
+    fn lookback(&self) -> TimeDelta;
+    fn inspect(&self, quiz: &QuizHistory, recent: &[QuizHistory]) -> Option<QuizFraudFlag>;
+}
+
+fn within(quiz: &QuizHistory, window: TimeDelta) -> impl Fn(&&QuizHistory) -> bool + '_ {
+    move |other| other.completed_at() > quiz.completed_at() - window
+}
+
+/// At most `max_quizzes` recorded quizzes per `window`.
+pub struct RateLimitDetector {
+    pub max_quizzes: u32,
+    pub window: TimeDelta,
+}
+
+impl QuizFraudDetector for RateLimitDetector {
+    fn lookback(&self) -> TimeDelta {
+        self.window
+    }
+
+    fn inspect(&self, quiz: &QuizHistory, recent: &[QuizHistory]) -> Option<QuizFraudFlag> {
+        let taken = recent.iter().filter(within(quiz, self.window)).count();
+        (taken >= self.max_quizzes as usize).then(|| QuizFraudFlag {
+            reason: QuizFraudReason::RateLimit,
+            detail: format!(
+                "{} kuis dalam {} menit terakhir, batasnya {}",
+                taken,
+                self.window.num_minutes(),
+                self.max_quizzes
+            ),
+        })
+    }
+}
+
+/// A quiz cannot be finished less than `min_interval` after the previous one.
+pub struct CompletionIntervalDetector {
+    pub min_interval: TimeDelta,
+}
+
+impl QuizFraudDetector for CompletionIntervalDetector {
+    fn lookback(&self) -> TimeDelta {
+        self.min_interval
+    }
+
+    fn inspect(&self, quiz: &QuizHistory, recent: &[QuizHistory]) -> Option<QuizFraudFlag> {
+        let gap = recent
+            .iter()
+            .map(|other| quiz.completed_at() - other.completed_at())
+            .filter(|gap| *gap >= TimeDelta::zero())
+            .min()?;
+        (gap < self.min_interval).then(|| QuizFraudFlag {
+            reason: QuizFraudReason::ImpossibleFrequency,
+            detail: format!(
+                "Selesai {} detik setelah kuis sebelumnya, minimal {} detik",
+                gap.num_seconds(),
+                self.min_interval.num_seconds()
+            ),
+        })
+    }
+}
+
+/// The same article is scored at most `max_repeats` times per `window`.
+pub struct ArticleRepeatDetector {
+    pub max_repeats: u32,
+    pub window: TimeDelta,
+}
+
+impl QuizFraudDetector for ArticleRepeatDetector {
+    fn lookback(&self) -> TimeDelta {
+        self.window
+    }
+
+    fn inspect(&self, quiz: &QuizHistory, recent: &[QuizHistory]) -> Option<QuizFraudFlag> {
+        let repeats = recent
+            .iter()
+            .filter(within(quiz, self.window))
+            .filter(|other| other.article_id() == quiz.article_id())
+            .count();
+        (repeats >= self.max_repeats as usize).then(|| QuizFraudFlag {
+            reason: QuizFraudReason::ArticleSpam,
+            detail: format!(
+                "Artikel {} sudah dikerjakan {} kali dalam {} jam terakhir, batasnya {}",
+                quiz.article_id(),
+                repeats,
+                self.window.num_hours(),
+                self.max_repeats
+            ),
+        })
+    }
+}
+
+/// Points need correct answers: no score without accuracy, and never above
+/// `max_score`.
+pub struct ScoreConsistencyDetector {
+    pub max_score: i32,
+}
+
+impl QuizFraudDetector for ScoreConsistencyDetector {
+    fn lookback(&self) -> TimeDelta {
+        TimeDelta::zero()
+    }
+
+    fn inspect(&self, quiz: &QuizHistory, _recent: &[QuizHistory]) -> Option<QuizFraudFlag> {
+        let detail = if quiz.score() > self.max_score {
+            format!(
+                "Skor {} melebihi skor maksimal {}",
+                quiz.score(),
+                self.max_score
+            )
+        } else if quiz.score() > 0 && quiz.accuracy() <= 0.0 {
+            format!("Skor {} dengan akurasi 0", quiz.score())
+        } else {
+            return None;
+        };
+        Some(QuizFraudFlag {
+            reason: QuizFraudReason::ScoreInconsistent,
+            detail,
+        })
+    }
+}
+
+/// Limits of the standard detector chain. A limit of zero turns its detector
+/// off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizFraudPolicy {
+    pub max_quizzes_per_hour: u32,
+    pub min_quiz_interval: TimeDelta,
+    pub max_article_repeats_per_day: u32,
+    pub max_score: i32,
+}
+
+impl Default for QuizFraudPolicy {
+    fn default() -> Self {
+        Self {
+            max_quizzes_per_hour: 20,
+            min_quiz_interval: TimeDelta::seconds(30),
+            max_article_repeats_per_day: 3,
+            max_score: 1000,
+        }
+    }
+}
+
+/// Detectors run in order; the first one to flag a quiz decides why it is held.
+#[derive(Clone, Default)]
+pub struct QuizFraudChain {
+    detectors: Vec<Arc<dyn QuizFraudDetector>>,
+}
+
+impl QuizFraudChain {
+    pub fn new(detectors: Vec<Arc<dyn QuizFraudDetector>>) -> Self {
+        Self { detectors }
+    }
+
+    /// Rate limit, completion interval, article repeats and score consistency,
+    /// leaving out the ones `policy` switches off.
+    pub fn standard(policy: &QuizFraudPolicy) -> Self {
+        let mut chain = Self::default();
+        if policy.max_quizzes_per_hour > 0 {
+            chain = chain.with(Arc::new(RateLimitDetector {
+                max_quizzes: policy.max_quizzes_per_hour,
+                window: TimeDelta::hours(1),
+            }));
+        }
+        if policy.min_quiz_interval > TimeDelta::zero() {
+            chain = chain.with(Arc::new(CompletionIntervalDetector {
+                min_interval: policy.min_quiz_interval,
+            }));
+        }
+        if policy.max_article_repeats_per_day > 0 {
+            chain = chain.with(Arc::new(ArticleRepeatDetector {
+                max_repeats: policy.max_article_repeats_per_day,
+                window: TimeDelta::days(1),
+            }));
+        }
+        if policy.max_score > 0 {
+            chain = chain.with(Arc::new(ScoreConsistencyDetector {
+                max_score: policy.max_score,
+            }));
+        }
+        chain
+    }
+
+    pub fn with(mut self, detector: Arc<dyn QuizFraudDetector>) -> Self {
+        self.detectors.push(detector);
+        self
+    }
+
+    /// How much of the user's history the detectors need to see.
+    pub fn lookback(&self) -> TimeDelta {
+        self.detectors
+            .iter()
+            .map(|detector| detector.lookback())
+            .max()
+            .unwrap_or_else(TimeDelta::zero)
+    }
+
+    pub fn inspect(&self, quiz: &QuizHistory, recent: &[QuizHistory]) -> Option<QuizFraudFlag> {
+        self.detectors
+            .iter()
+            .find_map(|detector| detector.inspect(quiz, recent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    fn taken(
+        user_id: Uuid,
+        article_id: Uuid,
+        score: i32,
+        accuracy: f64,
+        at: DateTime<Utc>,
+    ) -> QuizHistory {
+        QuizHistory::with_id(Uuid::new_v4(), user_id, article_id, score, accuracy, at)
+    }
+
+    #[test]
+    fn test_standard_chain_lets_an_ordinary_quiz_through() {
+        let now = Utc::now();
+        let user_id = Uuid::new_v4();
+        let recent = vec![
+            taken(
+                user_id,
+                Uuid::new_v4(),
+                80,
+                90.0,
+                now - TimeDelta::minutes(10),
+            ),
+            taken(
+                user_id,
+                Uuid::new_v4(),
+                60,
+                70.0,
+                now - TimeDelta::minutes(40),
+            ),
+        ];
+        let quiz = taken(user_id, Uuid::new_v4(), 90, 95.0, now);
+
+        let chain = QuizFraudChain::standard(&QuizFraudPolicy::default());
+
+        assert_eq!(chain.inspect(&quiz, &recent), None);
+        assert_eq!(chain.lookback(), TimeDelta::days(1));
+    }
+
+    #[test]
+    fn test_each_heuristic_flags_its_own_pattern() {
+        let now = Utc::now();
+        let user_id = Uuid::new_v4();
+        let article_id = Uuid::new_v4();
+        let chain = QuizFraudChain::standard(&QuizFraudPolicy {
+            max_quizzes_per_hour: 3,
+            ..QuizFraudPolicy::default()
+        });
+        let reason = |quiz: &QuizHistory, recent: &[QuizHistory]| {
+            chain.inspect(quiz, recent).map(|flag| flag.reason)
+        };
+
+        let busy_hour: Vec<_> = (1..=3)
+            .map(|i| {
+                taken(
+                    user_id,
+                    Uuid::new_v4(),
+                    50,
+                    50.0,
+                    now - TimeDelta::minutes(i * 5),
+                )
+            })
+            .collect();
+        assert_eq!(
+            reason(&taken(user_id, article_id, 50, 50.0, now), &busy_hour),
+            Some(QuizFraudReason::RateLimit)
+        );
+
+        let just_now = vec![taken(
+            user_id,
+            Uuid::new_v4(),
+            50,
+            50.0,
+            now - TimeDelta::seconds(4),
+        )];
+        assert_eq!(
+            reason(&taken(user_id, article_id, 50, 50.0, now), &just_now),
+            Some(QuizFraudReason::ImpossibleFrequency)
+        );
+
+        let same_article: Vec<_> = (1..=3)
+            .map(|i| taken(user_id, article_id, 50, 50.0, now - TimeDelta::hours(i * 5)))
+            .collect();
+        assert_eq!(
+            reason(&taken(user_id, article_id, 50, 50.0, now), &same_article),
+            Some(QuizFraudReason::ArticleSpam)
+        );
+
+        assert_eq!(
+            reason(&taken(user_id, article_id, 40, 0.0, now), &[]),
+            Some(QuizFraudReason::ScoreInconsistent)
+        );
+        assert_eq!(
+            reason(&taken(user_id, article_id, 5000, 100.0, now), &[]),
+            Some(QuizFraudReason::ScoreInconsistent)
+        );
+    }
+
+    #[test]
+    fn test_zero_limits_switch_detectors_off() {
+        let now = Utc::now();
+        let user_id = Uuid::new_v4();
+        let chain = QuizFraudChain::standard(&QuizFraudPolicy {
+            max_quizzes_per_hour: 0,
+            min_quiz_interval: TimeDelta::zero(),
+            max_article_repeats_per_day: 0,
+            max_score: 0,
+        });
+        let recent = vec![taken(user_id, Uuid::new_v4(), 50, 50.0, now)];
+
+        assert_eq!(
+            chain.inspect(&taken(user_id, Uuid::new_v4(), 10, 0.0, now), &recent),
+            None
+        );
+        assert_eq!(chain.lookback(), TimeDelta::zero());
+    }
+
+    #[test]
+    fn test_reason_round_trips_through_its_name() {
+        for reason in [
+            QuizFraudReason::RateLimit,
+            QuizFraudReason::ImpossibleFrequency,
+            QuizFraudReason::ArticleSpam,
+            QuizFraudReason::ScoreInconsistent,
+        ] {
+            assert_eq!(reason.as_str().parse::<QuizFraudReason>(), Ok(reason));
+        }
+        assert!("bot".parse::<QuizFraudReason>().is_err());
+    }
+}
@@ -17,6 +17,19 @@ pub struct QuizHistory {
     accuracy: f64,
     #[schema(value_type = String, example = "2024-01-15T10:30:00Z")]
     completed_at: DateTime<Utc>,
+    /// Id of the upstream event that delivered the quiz; retried deliveries reuse it.
+    #[schema(value_type = Option<String>, example = "550e8400-e29b-41d4-a716-446655440000")]
+    event_id: Option<Uuid>,
+}
+
+/// What the first delivery of an upstream quiz event produced, returned again
+/// when the event is delivered a second time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizSyncReceipt {
+    pub quiz_id: Uuid,
+    pub user_id: Uuid,
+    /// `None` while the first delivery is still applying gamification progress.
+    pub missions_updated: Option<i32>,
 }
 
 impl QuizHistory {
@@ -28,9 +41,20 @@ impl QuizHistory {
             score,
             accuracy,
             completed_at: Utc::now(),
+            event_id: None,
         }
     }
 
+    pub fn with_event_id(mut self, event_id: Option<Uuid>) -> Self {
+        self.event_id = event_id;
+        self
+    }
+
+    pub fn with_completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = completed_at;
+        self
+    }
+
     #[allow(dead_code)]
     pub fn with_id(
         id: Uuid,
@@ -47,6 +71,7 @@ impl QuizHistory {
             score,
             accuracy,
             completed_at,
+            event_id: None,
         }
     }
 
@@ -58,6 +83,7 @@ impl QuizHistory {
         score: i32,
         accuracy: f64,
         completed_at: DateTime<Utc>,
+        event_id: Option<Uuid>,
     ) -> Self {
         Self {
             id,
@@ -66,6 +92,7 @@ impl QuizHistory {
             score,
             accuracy,
             completed_at,
+            event_id,
         }
     }
 
@@ -92,6 +119,10 @@ impl QuizHistory {
     pub fn completed_at(&self) -> DateTime<Utc> {
         self.completed_at
     }
+
+    pub fn event_id(&self) -> Option<Uuid> {
+        self.event_id
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +139,16 @@ mod tests {
         assert_eq!(quiz.article_id(), article_id);
         assert_eq!(quiz.score(), 100);
         assert!((quiz.accuracy() - 0.95).abs() < f64::EPSILON);
+        assert_eq!(quiz.event_id(), None);
+    }
+
+    #[test]
+    fn test_quiz_history_keeps_upstream_event_id() {
+        let event_id = Uuid::new_v4();
+        let quiz =
+            QuizHistory::new(Uuid::new_v4(), Uuid::new_v4(), 80, 0.9).with_event_id(Some(event_id));
+
+        assert_eq!(quiz.event_id(), Some(event_id));
     }
 
     #[test]
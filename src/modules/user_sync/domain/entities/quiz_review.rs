@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::modules::user_sync::domain::entities::quiz_fraud::{QuizFraudFlag, QuizFraudReason};
+use crate::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizReviewStatus {
+    Pending,
+    /// Credited as a regular quiz sync completed at the held quiz's time.
+    Approved,
+    /// Kept for the record; nothing is credited.
+    Rejected,
+}
+
+impl QuizReviewStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuizReviewStatus::Pending => "pending",
+            QuizReviewStatus::Approved => "approved",
+            QuizReviewStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl fmt::Display for QuizReviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for QuizReviewStatus {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(QuizReviewStatus::Pending),
+            "approved" => Ok(QuizReviewStatus::Approved),
+            "rejected" => Ok(QuizReviewStatus::Rejected),
+            _ => Err("Status review kuis tidak dikenal"),
+        }
+    }
+}
+
+/// A quiz sync the fraud detectors held back, waiting in the review queue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarantinedQuiz {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub score: i32,
+    pub accuracy: f64,
+    pub event_id: Option<Uuid>,
+    pub reason: QuizFraudReason,
+    pub detail: String,
+    pub status: QuizReviewStatus,
+    /// The quiz recorded when the review was approved.
+    pub quiz_id: Option<Uuid>,
+    /// When the user finished the quiz; approval credits it as of then.
+    pub completed_at: DateTime<Utc>,
+    pub received_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl QuarantinedQuiz {
+    pub fn hold(quiz: &QuizHistory, flag: QuizFraudFlag) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id: quiz.user_id(),
+            article_id: quiz.article_id(),
+            score: quiz.score(),
+            accuracy: quiz.accuracy(),
+            event_id: quiz.event_id(),
+            reason: flag.reason,
+            detail: flag.detail,
+            status: QuizReviewStatus::Pending,
+            quiz_id: None,
+            completed_at: quiz.completed_at(),
+            received_at: quiz.completed_at(),
+            reviewed_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == QuizReviewStatus::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_quiz_keeps_the_sync_and_waits_for_review() {
+        let event_id = Uuid::new_v4();
+        let quiz = QuizHistory::new(Uuid::new_v4(), Uuid::new_v4(), 70, 80.0)
+            .with_event_id(Some(event_id));
+
+        let held = QuarantinedQuiz::hold(
+            &quiz,
+            QuizFraudFlag {
+                reason: QuizFraudReason::ArticleSpam,
+                detail: "berulang".to_string(),
+            },
+        );
+
+        assert!(held.is_pending());
+        assert_eq!(held.user_id, quiz.user_id());
+        assert_eq!(held.score, 70);
+        assert_eq!(held.event_id, Some(event_id));
+        assert_eq!(held.completed_at, quiz.completed_at());
+        assert_eq!(held.received_at, quiz.completed_at());
+        assert_eq!(held.quiz_id, None);
+        assert_eq!("approved".parse(), Ok(QuizReviewStatus::Approved));
+    }
+}
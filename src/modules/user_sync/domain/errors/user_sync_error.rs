@@ -1,3 +1,4 @@
+use crate::shared::domain::base_error::AppError;
 use crate::shared::utils::response::ApiResponse;
 use axum::{
     Json,
@@ -25,6 +26,18 @@ pub enum UserSyncError {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Quiz review not found: {0}")]
+    ReviewNotFound(String),
+
+    #[error("Quiz review already resolved: {0}")]
+    ReviewAlreadyResolved(String),
+}
+
+impl From<AppError> for UserSyncError {
+    fn from(err: AppError) -> Self {
+        UserSyncError::DatabaseError(err.to_string())
+    }
 }
 
 impl IntoResponse for UserSyncError {
@@ -36,6 +49,8 @@ impl IntoResponse for UserSyncError {
             UserSyncError::DatabaseError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
             UserSyncError::InvalidQuizData(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
             UserSyncError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            UserSyncError::ReviewNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            UserSyncError::ReviewAlreadyResolved(msg) => (StatusCode::CONFLICT, msg.clone()),
         };
 
         let body = ApiResponse::<()>::error(&error_message);
@@ -91,4 +106,11 @@ mod tests {
         let response = error.into_response();
         assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[test]
+    fn user_sync_error_review_already_resolved_maps_to_409() {
+        let error = UserSyncError::ReviewAlreadyResolved("review_789".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
 }
@@ -1,5 +1,7 @@
 pub mod quiz_history_repository;
+pub mod quiz_review_repository;
 pub mod user_repository;
 
 pub use quiz_history_repository::QuizHistoryRepository;
+pub use quiz_review_repository::QuizReviewRepository;
 pub use user_repository::UserRepository;
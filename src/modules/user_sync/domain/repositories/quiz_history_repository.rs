@@ -1,11 +1,74 @@
-use crate::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+use crate::modules::user_sync::domain::entities::quiz_history::{QuizHistory, QuizSyncReceipt};
 use crate::shared::domain::base_error::AppError;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[async_trait]
 pub trait QuizHistoryRepository: Send + Sync {
-    async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<(), AppError>;
+    /// Returns `false`, inserting nothing, when a quiz with the same event id is
+    /// already recorded.
+    async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<bool, AppError>;
     async fn get_quiz_histories_by_user(&self, user_id: Uuid)
     -> Result<Vec<QuizHistory>, AppError>;
+    /// The user's quizzes completed at or after `since`, newest first.
+    async fn get_quiz_histories_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<QuizHistory>, AppError>;
+    /// One page of the users with recorded quizzes, ordered by id and starting
+    /// after `after`, so batch jobs can walk all of them.
+    async fn list_quiz_user_ids(
+        &self,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, AppError>;
+    async fn get_sync_receipt(&self, event_id: Uuid) -> Result<Option<QuizSyncReceipt>, AppError>;
+    /// Stores how many missions the quiz advanced, so a replay can report it.
+    async fn record_sync_result(
+        &self,
+        quiz_id: Uuid,
+        missions_updated: i32,
+    ) -> Result<(), AppError>;
+}
+
+/// Lets use cases generic over the repository run on the shared instances handed
+/// out by a unit of work.
+#[async_trait]
+impl<T: QuizHistoryRepository + ?Sized> QuizHistoryRepository for Arc<T> {
+    async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<bool, AppError> {
+        (**self).insert_quiz_history(quiz).await
+    }
+    async fn get_quiz_histories_by_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<QuizHistory>, AppError> {
+        (**self).get_quiz_histories_by_user(user_id).await
+    }
+    async fn get_quiz_histories_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<QuizHistory>, AppError> {
+        (**self).get_quiz_histories_since(user_id, since).await
+    }
+    async fn list_quiz_user_ids(
+        &self,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, AppError> {
+        (**self).list_quiz_user_ids(after, limit).await
+    }
+    async fn get_sync_receipt(&self, event_id: Uuid) -> Result<Option<QuizSyncReceipt>, AppError> {
+        (**self).get_sync_receipt(event_id).await
+    }
+    async fn record_sync_result(
+        &self,
+        quiz_id: Uuid,
+        missions_updated: i32,
+    ) -> Result<(), AppError> {
+        (**self).record_sync_result(quiz_id, missions_updated).await
+    }
 }
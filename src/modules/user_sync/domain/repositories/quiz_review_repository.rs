@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::modules::user_sync::domain::entities::quiz_review::{QuarantinedQuiz, QuizReviewStatus};
+use crate::shared::domain::base_error::AppError;
+
+/// The review queue of quiz syncs held back by the fraud detectors.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait QuizReviewRepository: Send + Sync {
+    /// Returns `false`, storing nothing, when the same event is already held.
+    async fn quarantine(&self, quiz: &QuarantinedQuiz) -> Result<bool, AppError>;
+    async fn get(&self, id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError>;
+    async fn find_by_event(&self, event_id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError>;
+    /// Most recent first, optionally only those in `status`.
+    async fn list(
+        &self,
+        status: Option<QuizReviewStatus>,
+        limit: i64,
+    ) -> Result<Vec<QuarantinedQuiz>, AppError>;
+    /// Closes a pending review. `None` when it does not exist or was already
+    /// resolved, so two reviewers cannot both decide on it.
+    async fn resolve(
+        &self,
+        id: Uuid,
+        status: QuizReviewStatus,
+        quiz_id: Option<Uuid>,
+        at: DateTime<Utc>,
+    ) -> Result<Option<QuarantinedQuiz>, AppError>;
+}
@@ -1,6 +1,8 @@
 use super::super::entities::shadow_user::ShadowUser;
 use crate::shared::domain::base_error::AppError;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
 use uuid::Uuid;
 
 #[async_trait]
@@ -9,5 +11,42 @@ pub trait UserRepository: Send + Sync {
     async fn exists_shadow_user(&self, user_id: Uuid) -> Result<bool, AppError>;
     async fn check_exists(&self, user_id: Uuid) -> bool;
     async fn get_shadow_user(&self, user_id: Uuid) -> Result<Option<ShadowUser>, AppError>;
-    async fn update_total_score(&self, user_id: Uuid, score_to_add: i32) -> Result<(), AppError>;
+    /// Records the quiz score in the points ledger as earned at `occurred_at`,
+    /// when the quiz was completed, and adds it to `total_score`.
+    async fn update_total_score(
+        &self,
+        user_id: Uuid,
+        score_to_add: i32,
+        quiz_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) -> Result<(), AppError>;
+}
+
+/// Lets use cases generic over the repository run on the shared instances handed
+/// out by a unit of work.
+#[async_trait]
+impl<T: UserRepository + ?Sized> UserRepository for Arc<T> {
+    async fn insert_shadow_user(&self, user: &ShadowUser) -> Result<(), AppError> {
+        (**self).insert_shadow_user(user).await
+    }
+    async fn exists_shadow_user(&self, user_id: Uuid) -> Result<bool, AppError> {
+        (**self).exists_shadow_user(user_id).await
+    }
+    async fn check_exists(&self, user_id: Uuid) -> bool {
+        (**self).check_exists(user_id).await
+    }
+    async fn get_shadow_user(&self, user_id: Uuid) -> Result<Option<ShadowUser>, AppError> {
+        (**self).get_shadow_user(user_id).await
+    }
+    async fn update_total_score(
+        &self,
+        user_id: Uuid,
+        score_to_add: i32,
+        quiz_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) -> Result<(), AppError> {
+        (**self)
+            .update_total_score(user_id, score_to_add, quiz_id, occurred_at)
+            .await
+    }
 }
@@ -1,7 +1,9 @@
 // PostgreSQL for User Sync - SQLx user insert
 
 pub mod quiz_history_postgres_repo;
+pub mod quiz_review_postgres_repo;
 pub mod user_postgres_repo;
 
 pub use quiz_history_postgres_repo::QuizHistoryPostgresRepo;
+pub use quiz_review_postgres_repo::QuizReviewPostgresRepo;
 pub use user_postgres_repo::UserPostgresRepo;
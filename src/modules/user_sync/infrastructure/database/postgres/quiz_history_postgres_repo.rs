@@ -1,6 +1,7 @@
-use crate::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+use crate::modules::user_sync::domain::entities::quiz_history::{QuizHistory, QuizSyncReceipt};
 use crate::modules::user_sync::domain::repositories::quiz_history_repository::QuizHistoryRepository;
 use crate::shared::domain::base_error::AppError;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::FromRow;
@@ -15,23 +16,61 @@ struct QuizHistoryRow {
     score: i32,
     accuracy: f64,
     completed_at: DateTime<Utc>,
+    event_id: Option<Uuid>,
+}
+
+impl QuizHistoryRow {
+    fn into_quiz(self) -> QuizHistory {
+        QuizHistory::from_db(
+            self.id,
+            self.user_id,
+            self.article_id,
+            self.score,
+            self.accuracy,
+            self.completed_at,
+            self.event_id,
+        )
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct QuizSyncReceiptRow {
+    id: Uuid,
+    user_id: Uuid,
+    missions_updated: Option<i32>,
 }
 
 pub struct QuizHistoryPostgresRepo {
-    pool: PgPool,
+    db: PgHandle,
 }
 
 impl QuizHistoryPostgresRepo {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
     }
 }
 
 #[async_trait]
 impl QuizHistoryRepository for QuizHistoryPostgresRepo {
-    async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<(), AppError> {
-        sqlx::query(
-            "INSERT INTO quiz_history (id, user_id, article_id, score, accuracy, completed_at) VALUES ($1, $2, $3, $4, $5, $6)"
+    async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<bool, AppError> {
+        // Baris tanpa event_id tidak pernah bentrok: NULL tidak dianggap sama oleh UNIQUE
+        let mut conn = self.conn().await?;
+        let result = sqlx::query(
+            "INSERT INTO quiz_history (id, user_id, article_id, score, accuracy, completed_at, event_id) VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (event_id) DO NOTHING"
         )
         .bind(quiz.id())
         .bind(quiz.user_id())
@@ -39,36 +78,92 @@ impl QuizHistoryRepository for QuizHistoryPostgresRepo {
         .bind(quiz.score())
         .bind(quiz.accuracy())
         .bind(quiz.completed_at())
-        .execute(&self.pool)
+        .bind(quiz.event_id())
+        .execute(&mut *conn)
         .await
         .map_err(|e| AppError::InternalServer(e.to_string()))?;
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
     async fn get_quiz_histories_by_user(
         &self,
         user_id: Uuid,
     ) -> Result<Vec<QuizHistory>, AppError> {
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, QuizHistoryRow>(
+            "SELECT id, user_id, article_id, score, CAST(accuracy AS FLOAT8) as accuracy, completed_at, event_id FROM quiz_history WHERE user_id = $1 ORDER BY completed_at DESC"
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        Ok(rows.into_iter().map(QuizHistoryRow::into_quiz).collect())
+    }
+
+    async fn get_quiz_histories_since(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<QuizHistory>, AppError> {
+        let mut conn = self.conn().await?;
         let rows = sqlx::query_as::<_, QuizHistoryRow>(
-            "SELECT id, user_id, article_id, score, CAST(accuracy AS FLOAT8) as accuracy, completed_at FROM quiz_history WHERE user_id = $1 ORDER BY completed_at DESC"
+            "SELECT id, user_id, article_id, score, CAST(accuracy AS FLOAT8) as accuracy, completed_at, event_id FROM quiz_history WHERE user_id = $1 AND completed_at >= $2 ORDER BY completed_at DESC"
         )
         .bind(user_id)
-        .fetch_all(&self.pool)
+        .bind(since)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?;
+
+        Ok(rows.into_iter().map(QuizHistoryRow::into_quiz).collect())
+    }
+
+    async fn list_quiz_user_ids(
+        &self,
+        after: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query_scalar(
+            "SELECT DISTINCT user_id FROM quiz_history WHERE ($1::uuid IS NULL OR user_id > $1) ORDER BY user_id LIMIT $2",
+        )
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))
+    }
+
+    async fn get_sync_receipt(&self, event_id: Uuid) -> Result<Option<QuizSyncReceipt>, AppError> {
+        let mut conn = self.conn().await?;
+        let row = sqlx::query_as::<_, QuizSyncReceiptRow>(
+            "SELECT id, user_id, missions_updated FROM quiz_history WHERE event_id = $1",
+        )
+        .bind(event_id)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
-        Ok(rows
-            .into_iter()
-            .map(|row| {
-                QuizHistory::from_db(
-                    row.id,
-                    row.user_id,
-                    row.article_id,
-                    row.score,
-                    row.accuracy,
-                    row.completed_at,
-                )
-            })
-            .collect())
+        Ok(row.map(|row| QuizSyncReceipt {
+            quiz_id: row.id,
+            user_id: row.user_id,
+            missions_updated: row.missions_updated,
+        }))
+    }
+
+    async fn record_sync_result(
+        &self,
+        quiz_id: Uuid,
+        missions_updated: i32,
+    ) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query("UPDATE quiz_history SET missions_updated = $2 WHERE id = $1")
+            .bind(quiz_id)
+            .bind(missions_updated)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))?;
+        Ok(())
     }
 }
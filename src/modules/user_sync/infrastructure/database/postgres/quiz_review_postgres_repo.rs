@@ -0,0 +1,177 @@
+use crate::modules::user_sync::domain::entities::quiz_review::{QuarantinedQuiz, QuizReviewStatus};
+use crate::modules::user_sync::domain::repositories::quiz_review_repository::QuizReviewRepository;
+use crate::shared::domain::base_error::AppError;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const REVIEW_COLUMNS: &str = "id, user_id, article_id, score, CAST(accuracy AS FLOAT8) as accuracy, \
+     event_id, reason, detail, status, quiz_id, completed_at, received_at, reviewed_at";
+
+#[derive(Debug, FromRow)]
+struct QuarantinedQuizRow {
+    id: Uuid,
+    user_id: Uuid,
+    article_id: Uuid,
+    score: i32,
+    accuracy: f64,
+    event_id: Option<Uuid>,
+    reason: String,
+    detail: String,
+    status: String,
+    quiz_id: Option<Uuid>,
+    completed_at: DateTime<Utc>,
+    received_at: DateTime<Utc>,
+    reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl QuarantinedQuizRow {
+    fn into_quarantined(self) -> Result<QuarantinedQuiz, AppError> {
+        Ok(QuarantinedQuiz {
+            id: self.id,
+            user_id: self.user_id,
+            article_id: self.article_id,
+            score: self.score,
+            accuracy: self.accuracy,
+            event_id: self.event_id,
+            reason: self
+                .reason
+                .parse()
+                .map_err(|e: &str| AppError::InternalServer(e.to_string()))?,
+            detail: self.detail,
+            status: self
+                .status
+                .parse()
+                .map_err(|e: &str| AppError::InternalServer(e.to_string()))?,
+            quiz_id: self.quiz_id,
+            completed_at: self.completed_at,
+            received_at: self.received_at,
+            reviewed_at: self.reviewed_at,
+        })
+    }
+}
+
+pub struct QuizReviewPostgresRepo {
+    db: PgHandle,
+}
+
+impl QuizReviewPostgresRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl QuizReviewRepository for QuizReviewPostgresRepo {
+    async fn quarantine(&self, quiz: &QuarantinedQuiz) -> Result<bool, AppError> {
+        let mut conn = self.conn().await?;
+        let result = sqlx::query(
+            "INSERT INTO quiz_quarantine (id, user_id, article_id, score, accuracy, event_id, reason, detail, status, completed_at, received_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT (event_id) DO NOTHING",
+        )
+        .bind(quiz.id)
+        .bind(quiz.user_id)
+        .bind(quiz.article_id)
+        .bind(quiz.score)
+        .bind(quiz.accuracy)
+        .bind(quiz.event_id)
+        .bind(quiz.reason.as_str())
+        .bind(&quiz.detail)
+        .bind(quiz.status.as_str())
+        .bind(quiz.completed_at)
+        .bind(quiz.received_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query_as::<_, QuarantinedQuizRow>(&format!(
+            "SELECT {} FROM quiz_quarantine WHERE id = $1",
+            REVIEW_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?
+        .map(QuarantinedQuizRow::into_quarantined)
+        .transpose()
+    }
+
+    async fn find_by_event(&self, event_id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query_as::<_, QuarantinedQuizRow>(&format!(
+            "SELECT {} FROM quiz_quarantine WHERE event_id = $1",
+            REVIEW_COLUMNS
+        ))
+        .bind(event_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?
+        .map(QuarantinedQuizRow::into_quarantined)
+        .transpose()
+    }
+
+    async fn list(
+        &self,
+        status: Option<QuizReviewStatus>,
+        limit: i64,
+    ) -> Result<Vec<QuarantinedQuiz>, AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query_as::<_, QuarantinedQuizRow>(&format!(
+            "SELECT {} FROM quiz_quarantine WHERE ($1::text IS NULL OR status = $1) \
+             ORDER BY received_at DESC LIMIT $2",
+            REVIEW_COLUMNS
+        ))
+        .bind(status.map(|s| s.as_str()))
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?
+        .into_iter()
+        .map(QuarantinedQuizRow::into_quarantined)
+        .collect()
+    }
+
+    async fn resolve(
+        &self,
+        id: Uuid,
+        status: QuizReviewStatus,
+        quiz_id: Option<Uuid>,
+        at: DateTime<Utc>,
+    ) -> Result<Option<QuarantinedQuiz>, AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query_as::<_, QuarantinedQuizRow>(&format!(
+            "UPDATE quiz_quarantine SET status = $2, quiz_id = $3, reviewed_at = $4 \
+             WHERE id = $1 AND status = 'pending' RETURNING {}",
+            REVIEW_COLUMNS
+        ))
+        .bind(id)
+        .bind(status.as_str())
+        .bind(quiz_id)
+        .bind(at)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(e.to_string()))?
+        .map(QuarantinedQuizRow::into_quarantined)
+        .transpose()
+    }
+}
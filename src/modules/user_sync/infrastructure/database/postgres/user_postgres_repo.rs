@@ -2,9 +2,12 @@ use chrono::{DateTime, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::modules::gamification::domain::entities::points_ledger::{PointsEntry, PointsSource};
+use crate::modules::gamification::infrastructure::database::postgres::level_repository::record_points;
 use crate::modules::user_sync::domain::entities::shadow_user::ShadowUser;
 use crate::modules::user_sync::domain::repositories::UserRepository;
 use crate::shared::domain::base_error::AppError;
+use crate::shared::infrastructure::database::{PgConn, PgHandle};
 use async_trait::async_trait;
 use sqlx::PgPool;
 
@@ -16,12 +19,26 @@ pub struct ShadowUserRow {
 }
 
 pub struct UserPostgresRepo {
-    pool: PgPool,
+    db: PgHandle,
 }
 
 impl UserPostgresRepo {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
     }
 
     fn map_row_to_shadow_user(row: &ShadowUserRow) -> Result<ShadowUser, AppError> {
@@ -36,12 +53,13 @@ impl UserRepository for UserPostgresRepo {
         &self,
         user: &ShadowUser,
     ) -> Result<(), crate::shared::domain::base_error::AppError> {
+        let mut conn = self.conn().await?;
         let result = sqlx::query(
             "INSERT INTO shadow_users (user_id, total_score, created_at) VALUES ($1, $2, NOW()) ON CONFLICT (user_id) DO NOTHING"
         )
         .bind(user.user_id())
         .bind(user.total_score())
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await;
 
         match result {
@@ -56,10 +74,11 @@ impl UserRepository for UserPostgresRepo {
         &self,
         user_id: Uuid,
     ) -> Result<bool, crate::shared::domain::base_error::AppError> {
+        let mut conn = self.conn().await?;
         let result =
             sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM shadow_users WHERE user_id = $1")
                 .bind(user_id)
-                .fetch_one(&self.pool)
+                .fetch_one(&mut *conn)
                 .await;
 
         match result {
@@ -71,20 +90,24 @@ impl UserRepository for UserPostgresRepo {
     }
 
     async fn check_exists(&self, user_id: Uuid) -> bool {
+        let Ok(mut conn) = self.db.acquire().await else {
+            return false;
+        };
         sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM shadow_users WHERE user_id = $1")
             .bind(user_id)
-            .fetch_one(&self.pool)
+            .fetch_one(&mut *conn)
             .await
             .map(|count| count > 0)
             .unwrap_or(false)
     }
 
     async fn get_shadow_user(&self, user_id: Uuid) -> Result<Option<ShadowUser>, AppError> {
+        let mut conn = self.conn().await?;
         let result = sqlx::query_as::<_, ShadowUserRow>(
             "SELECT user_id, total_score, created_at FROM shadow_users WHERE user_id = $1",
         )
         .bind(user_id)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await;
 
         match result {
@@ -94,17 +117,54 @@ impl UserRepository for UserPostgresRepo {
         }
     }
 
-    async fn update_total_score(&self, user_id: Uuid, score_to_add: i32) -> Result<(), AppError> {
-        let result = sqlx::query(
-            "UPDATE shadow_users SET total_score = total_score + $1 WHERE user_id = $2",
-        )
-        .bind(score_to_add)
-        .bind(user_id)
-        .execute(&self.pool)
-        .await;
+    async fn update_total_score(
+        &self,
+        user_id: Uuid,
+        score_to_add: i32,
+        quiz_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
+
+        // The strongest score boost still running when the quiz was done adds
+        // its share as a separate entry, so the quiz entry keeps its own score.
+        let boost_percent = if score_to_add > 0 {
+            sqlx::query_scalar::<_, Option<i32>>(
+                "SELECT MAX(ri.boost_percent) FROM user_inventory ui \
+                 JOIN reward_items ri ON ri.id = ui.item_id \
+                 WHERE ui.user_id = $1 AND ri.kind = 'ScoreBoost' AND ui.active_until > $2",
+            )
+            .bind(user_id)
+            .bind(occurred_at)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))?
+            .unwrap_or(0)
+        } else {
+            0
+        };
+
+        let mut entries = vec![PointsEntry::new(
+            user_id,
+            score_to_add,
+            PointsSource::Quiz,
+            Some(quiz_id),
+            occurred_at,
+        )];
+        let boost = score_to_add * boost_percent / 100;
+        if boost > 0 {
+            entries.push(PointsEntry::new(
+                user_id,
+                boost,
+                PointsSource::ScoreBoost,
+                Some(quiz_id),
+                occurred_at,
+            ));
+        }
+        let result = record_points(&mut conn, &entries).await;
 
         match result {
-            Ok(_query_result) => Ok(()),
+            Ok(_recorded) => Ok(()),
             Err(e) => Err(AppError::InternalServer(e.to_string())),
         }
     }
@@ -0,0 +1,129 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::user_sync::application::dto::QuizReviewDto;
+use crate::modules::user_sync::application::{
+    ListQuizReviewsUseCase, ProcessQuizSyncUseCase, RejectQuizReviewUseCase,
+};
+use crate::modules::user_sync::domain::errors::UserSyncError;
+use crate::modules::user_sync::infrastructure::database::postgres::QuizReviewPostgresRepo;
+use crate::shared::infrastructure::database::PostgresUnitOfWorkFactory;
+use crate::shared::infrastructure::metrics::QuizReviewLabels;
+use crate::shared::utils::response::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct QuizReviewsQuery {
+    status: Option<String>,
+    limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/quiz-reviews",
+    params(
+        ("status" = Option<String>, Query, description = "Only reviews that are pending, approved or rejected"),
+        ("limit" = Option<i64>, Query, description = "Most recent reviews to return (1-200, default 50)")
+    ),
+    responses(
+        (status = 200, description = "Quiz syncs held by the anti-cheat checks, most recently held first", body = [QuizReviewDto]),
+        (status = 400, description = "Unknown status or limit out of range"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_quiz_reviews_handler(
+    State(state): State<AppState>,
+    Query(query): Query<QuizReviewsQuery>,
+) -> Result<Json<ApiResponse<Vec<QuizReviewDto>>>, UserSyncError> {
+    let use_case = ListQuizReviewsUseCase::new(Arc::new(QuizReviewPostgresRepo::new(state.db)));
+
+    let reviews = use_case.execute(query.status, query.limit).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Antrean review kuis berhasil diambil",
+        reviews,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/quiz-reviews/{id}/approve",
+    params(
+        ("id" = Uuid, Path, description = "Quiz review ID")
+    ),
+    responses(
+        (status = 200, description = "Quiz credited as a regular sync completed when the user finished it: score, missions, achievements and streak", body = QuizReviewDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Quiz review not found"),
+        (status = 409, description = "The review was already approved or rejected"),
+        (status = 500, description = "Internal server error; nothing was credited")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn approve_quiz_review_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<QuizReviewDto>>, UserSyncError> {
+    let use_case = ProcessQuizSyncUseCase::new(
+        Arc::new(PostgresUnitOfWorkFactory::new(state.db.clone())),
+        state.config.streak_policy(),
+        state.config.default_timezone(),
+    );
+
+    let review = use_case.approve_review(id, Utc::now()).await?;
+    record_decision(&state, &review);
+
+    Ok(Json(ApiResponse::success(
+        "Kuis disetujui dan skornya dihitung",
+        review,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/quiz-reviews/{id}/reject",
+    params(
+        ("id" = Uuid, Path, description = "Quiz review ID")
+    ),
+    responses(
+        (status = 200, description = "Review closed; nothing is credited and redeliveries of the event stay held", body = QuizReviewDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Quiz review not found"),
+        (status = 409, description = "The review was already approved or rejected"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn reject_quiz_review_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<QuizReviewDto>>, UserSyncError> {
+    let use_case =
+        RejectQuizReviewUseCase::new(Arc::new(QuizReviewPostgresRepo::new(state.db.clone())));
+
+    let review = use_case.execute(id, Utc::now()).await?;
+    record_decision(&state, &review);
+
+    Ok(Json(ApiResponse::success("Kuis ditolak", review)))
+}
+
+fn record_decision(state: &AppState, review: &QuizReviewDto) {
+    state
+        .metrics
+        .quiz_reviews
+        .get_or_create(&QuizReviewLabels {
+            decision: review.status.clone(),
+        })
+        .inc();
+}
@@ -6,10 +6,12 @@ use crate::{
         infrastructure::database::postgres::user_postgres_repo::UserPostgresRepo,
     },
     shared::domain::base_error::AppError,
+    shared::infrastructure::database::PostgresUserTimezoneRepository,
     shared::utils::response::ApiResponse,
 };
 use axum::{Json, extract::State, http::StatusCode};
 use serde::Serialize;
+use std::sync::Arc;
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
@@ -24,10 +26,12 @@ pub struct SyncUserResponseDto {
     request_body = SyncUserRequestDto,
     responses(
         (status = 201, description = "User synced successfully"),
+        (status = 401, description = "Missing or invalid x-api-key"),
         (status = 400, description = "Invalid request"),
         (status = 409, description = "User already exists (returns existing user)"),
         (status = 500, description = "Internal server error")
     ),
+    security(("internal_key" = [])),
     tag = "User Sync"
 )]
 pub async fn sync_user_handler(
@@ -35,7 +39,9 @@ pub async fn sync_user_handler(
     Json(dto): Json<SyncUserRequestDto>,
 ) -> Result<(StatusCode, Json<ApiResponse<SyncUserResponseDto>>), AppError> {
     let repository = UserPostgresRepo::new(state.db.clone());
-    let use_case = SyncNewUserUseCase::new(repository);
+    let use_case = SyncNewUserUseCase::new(repository).with_timezones(Arc::new(
+        PostgresUserTimezoneRepository::new(state.db.clone()),
+    ));
     let user_id = dto.user_id;
 
     let _ = use_case
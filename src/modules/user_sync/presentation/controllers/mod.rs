@@ -1,2 +1,3 @@
+pub mod admin_quiz_review_controller;
 pub mod internal_user_controller;
 pub mod quiz_history_controller;
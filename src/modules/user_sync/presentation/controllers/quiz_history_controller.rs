@@ -2,20 +2,25 @@ use crate::{
     AppState,
     modules::user_sync::{
         application::dto::QuizHistoryRequestDto,
-        application::use_cases::sync_quiz_history_usecase::SyncQuizHistoryUseCase,
-        infrastructure::database::postgres::quiz_history_postgres_repo::QuizHistoryPostgresRepo,
-        infrastructure::database::postgres::user_postgres_repo::UserPostgresRepo,
+        application::use_cases::process_quiz_sync_usecase::ProcessQuizSyncUseCase,
+        domain::entities::quiz_fraud::QuizFraudChain,
     },
     shared::domain::base_error::AppError,
+    shared::infrastructure::database::PostgresUnitOfWorkFactory,
+    shared::infrastructure::metrics::QuizQuarantineLabels,
     shared::utils::response::ApiResponse,
 };
 use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
 use utoipa::ToSchema;
 
 #[derive(serde::Serialize, ToSchema)]
 pub struct QuizHistoryApiResponse {
     pub user_id: uuid::Uuid,
     pub missions_updated: i32,
+    /// The quiz was held for review; its score counts only once an admin approves it.
+    pub quarantined: bool,
+    pub review_id: Option<uuid::Uuid>,
     pub message: String,
 }
 
@@ -24,37 +29,52 @@ pub struct QuizHistoryApiResponse {
     path = "/api/internal/quiz-history/sync",
     request_body = QuizHistoryRequestDto,
     responses(
-        (status = 201, description = "Quiz history synced successfully"),
+        (status = 201, description = "Quiz history synced successfully; a retried event_id gets the first delivery's result without being applied again. A quiz the anti-cheat checks flag is accepted with `quarantined` set and waits in the admin review queue"),
+        (status = 401, description = "Missing or invalid x-api-key"),
         (status = 400, description = "Invalid quiz data (negative score or invalid accuracy)"),
         (status = 404, description = "User not found in Engine DB"),
-        (status = 500, description = "Internal server error")
+        (status = 500, description = "Internal server error; nothing was recorded, so the delivery can be retried")
     ),
+    security(("internal_key" = [])),
     tag = "User Sync"
 )]
 pub async fn sync_quiz_history_handler(
     State(state): State<AppState>,
     Json(dto): Json<QuizHistoryRequestDto>,
 ) -> Result<(StatusCode, Json<ApiResponse<QuizHistoryApiResponse>>), AppError> {
-    let user_repo = UserPostgresRepo::new(state.db.clone());
-    let quiz_repo = QuizHistoryPostgresRepo::new(state.db.clone());
-    let use_case = SyncQuizHistoryUseCase::new(user_repo, quiz_repo);
+    let use_case = ProcessQuizSyncUseCase::new(
+        Arc::new(PostgresUnitOfWorkFactory::new(state.db.clone())),
+        state.config.streak_policy(),
+        state.config.default_timezone(),
+    )
+    .with_fraud_detection(QuizFraudChain::standard(&state.config.quiz_fraud_policy()));
 
     let response = use_case
         .execute(dto)
         .await
         .map_err(|e| AppError::InternalServer(e.to_string()))?;
 
+    if let (Some(quarantine), false) = (&response.quarantine, response.replayed) {
+        state
+            .metrics
+            .quiz_quarantined
+            .get_or_create(&QuizQuarantineLabels {
+                reason: quarantine.reason.clone(),
+            })
+            .inc();
+    }
+
+    let message = response.message.clone();
     let api_response = QuizHistoryApiResponse {
         user_id: response.user_id,
         missions_updated: response.missions_updated,
+        quarantined: response.quarantine.is_some(),
+        review_id: response.quarantine.map(|q| q.review_id),
         message: response.message,
     };
 
     Ok((
         StatusCode::CREATED,
-        Json(ApiResponse::success(
-            "Data riwayat kuis berhasil dicatat dan diproses oleh Engine",
-            api_response,
-        )),
+        Json(ApiResponse::success(&message, api_response)),
     ))
 }
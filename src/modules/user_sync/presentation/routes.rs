@@ -1,6 +1,11 @@
-use super::controllers::{internal_user_controller, quiz_history_controller};
+use super::controllers::{
+    admin_quiz_review_controller, internal_user_controller, quiz_history_controller,
+};
 use crate::AppState;
-use axum::{Router, routing::post};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 
 pub fn user_sync_routes() -> Router<AppState> {
     Router::new()
@@ -13,3 +18,20 @@ pub fn user_sync_routes() -> Router<AppState> {
             post(quiz_history_controller::sync_quiz_history_handler),
         )
 }
+
+/// The review queue of held quiz syncs, mounted under `/api/admin` behind `require_admin_key`.
+pub fn user_sync_admin_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/quiz-reviews",
+            get(admin_quiz_review_controller::list_quiz_reviews_handler),
+        )
+        .route(
+            "/quiz-reviews/{id}/approve",
+            post(admin_quiz_review_controller::approve_quiz_review_handler),
+        )
+        .route(
+            "/quiz-reviews/{id}/reject",
+            post(admin_quiz_review_controller::reject_quiz_review_handler),
+        )
+}
@@ -0,0 +1,6 @@
+pub mod webhook_dto;
+
+pub use webhook_dto::{
+    DeliveryAttemptDto, ReplayWebhookDeliveriesDto, ReplayWebhookDeliveriesResponseDto,
+    WebhookDeliveryDto, WebhookSubscriptionDto, WebhookSubscriptionRequestDto,
+};
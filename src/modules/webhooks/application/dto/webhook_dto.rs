@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::modules::webhooks::domain::entities::webhook_delivery::{
+    DeliveryAttempt, WebhookDelivery,
+};
+use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+
+/// Admin payload for subscribing an internal consumer to engine events.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionRequestDto {
+    pub url: String,
+    /// Any of `achievement_unlocked`, `mission_completed`, `clan_tier_changed`,
+    /// `level_up`.
+    pub event_types: Vec<String>,
+    /// At least 16 characters; used to sign `X-Yomu-Signature`. Never returned.
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionDto {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&WebhookSubscription> for WebhookSubscriptionDto {
+    fn from(subscription: &WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id(),
+            url: subscription.url().to_string(),
+            event_types: subscription.event_types().to_vec(),
+            active: subscription.is_active(),
+            created_at: subscription.created_at(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryAttemptDto {
+    pub attempted_at: DateTime<Utc>,
+    /// Absent when no response arrived (timeout, connection refused).
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+}
+
+impl From<&DeliveryAttempt> for DeliveryAttemptDto {
+    fn from(attempt: &DeliveryAttempt) -> Self {
+        Self {
+            attempted_at: attempt.attempted_at,
+            response_status: attempt.response_status,
+            error: attempt.error.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryDto {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    /// `pending`, `delivered` or `dead_letter`.
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// Every attempt so far, replays included, oldest first.
+    pub attempt_log: Vec<DeliveryAttemptDto>,
+}
+
+impl WebhookDeliveryDto {
+    pub fn new(delivery: &WebhookDelivery, attempts: &[DeliveryAttempt]) -> Self {
+        Self {
+            id: delivery.id,
+            event_id: delivery.event.id,
+            event_type: delivery.event.event_type.clone(),
+            status: delivery.status.to_string(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            last_status: delivery.last_status,
+            last_error: delivery.last_error.clone(),
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+            attempt_log: attempts
+                .iter()
+                .filter(|attempt| attempt.delivery_id == delivery.id)
+                .map(DeliveryAttemptDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ReplayWebhookDeliveriesDto {
+    /// Replay only this delivery; all dead-lettered ones when omitted.
+    #[serde(default)]
+    pub delivery_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReplayWebhookDeliveriesResponseDto {
+    pub subscription_id: Uuid,
+    pub replayed: u64,
+}
@@ -0,0 +1,7 @@
+pub mod dto;
+pub mod use_cases;
+
+pub use use_cases::{
+    CreateWebhookSubscriptionUseCase, DeactivateWebhookSubscriptionUseCase,
+    ListWebhookDeliveriesUseCase, ListWebhookSubscriptionsUseCase, ReplayWebhookDeliveriesUseCase,
+};
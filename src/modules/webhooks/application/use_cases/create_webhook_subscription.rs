@@ -0,0 +1,84 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::webhooks::application::dto::{
+    WebhookSubscriptionDto, WebhookSubscriptionRequestDto,
+};
+use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+
+pub struct CreateWebhookSubscriptionUseCase {
+    pub repository: Arc<dyn WebhookRepository>,
+}
+
+impl CreateWebhookSubscriptionUseCase {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Subscribes `url` to the given event types. Only events written after this
+    /// call are delivered to it.
+    pub async fn execute(
+        &self,
+        dto: WebhookSubscriptionRequestDto,
+    ) -> Result<WebhookSubscriptionDto, WebhookError> {
+        let subscription = WebhookSubscription::new(
+            Uuid::new_v4(),
+            dto.url,
+            dto.event_types,
+            dto.secret,
+            Utc::now(),
+        )
+        .map_err(|e| WebhookError::InvalidData(e.to_string()))?;
+
+        self.repository.create_subscription(&subscription).await?;
+
+        Ok(WebhookSubscriptionDto::from(&subscription))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::webhooks::domain::repositories::webhook_repository::MockWebhookRepository;
+
+    fn request(event_types: &[&str]) -> WebhookSubscriptionRequestDto {
+        WebhookSubscriptionRequestDto {
+            url: "https://notifications.yomu.internal/engine".to_string(),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            secret: "rahasia-webhook-yomu".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_persists_subscription_without_echoing_secret() {
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo
+            .expect_create_subscription()
+            .withf(|sub| sub.secret() == "rahasia-webhook-yomu" && sub.is_active())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateWebhookSubscriptionUseCase::new(Arc::new(mock_repo));
+        let created = use_case
+            .execute(request(&["achievement_unlocked"]))
+            .await
+            .unwrap();
+
+        assert_eq!(created.event_types, ["achievement_unlocked"]);
+        assert!(!serde_json::to_string(&created).unwrap().contains("rahasia"));
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_unknown_event_type() {
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo.expect_create_subscription().never();
+
+        let use_case = CreateWebhookSubscriptionUseCase::new(Arc::new(mock_repo));
+        let result = use_case.execute(request(&["quiz_synced"])).await;
+
+        assert!(matches!(result, Err(WebhookError::InvalidData(_))));
+    }
+}
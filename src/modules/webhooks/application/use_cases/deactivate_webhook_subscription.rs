@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::webhooks::application::dto::WebhookSubscriptionDto;
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+
+pub struct DeactivateWebhookSubscriptionUseCase {
+    pub repository: Arc<dyn WebhookRepository>,
+}
+
+impl DeactivateWebhookSubscriptionUseCase {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Stops fanning events out to the subscription and sending its pending
+    /// deliveries. Its delivery history is kept.
+    pub async fn execute(&self, id: Uuid) -> Result<WebhookSubscriptionDto, WebhookError> {
+        if !self.repository.deactivate_subscription(id).await? {
+            return Err(not_found(id));
+        }
+
+        let subscription = self
+            .repository
+            .get_subscription(id)
+            .await?
+            .ok_or_else(|| not_found(id))?;
+        Ok(WebhookSubscriptionDto::from(&subscription))
+    }
+}
+
+pub(crate) fn not_found(id: Uuid) -> WebhookError {
+    WebhookError::SubscriptionNotFound(format!("Webhook {} tidak ditemukan.", id))
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::deactivate_webhook_subscription::not_found;
+use crate::modules::webhooks::application::dto::WebhookDeliveryDto;
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+use crate::shared::domain::outbox::OutboxStatus;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+pub struct ListWebhookDeliveriesUseCase {
+    pub repository: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The subscription's most recent deliveries with the log of every attempt.
+    pub async fn execute(
+        &self,
+        subscription_id: Uuid,
+        status: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<WebhookDeliveryDto>, WebhookError> {
+        let status = status
+            .map(|s| s.parse::<OutboxStatus>())
+            .transpose()
+            .map_err(|_| {
+                WebhookError::InvalidData(
+                    "Status harus pending, delivered, atau dead_letter.".to_string(),
+                )
+            })?;
+        let limit = limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(WebhookError::InvalidData(format!(
+                "Limit harus di antara 1 dan {}.",
+                MAX_LIMIT
+            )));
+        }
+
+        if self
+            .repository
+            .get_subscription(subscription_id)
+            .await?
+            .is_none()
+        {
+            return Err(not_found(subscription_id));
+        }
+
+        let deliveries = self
+            .repository
+            .list_deliveries(subscription_id, status, limit)
+            .await?;
+        let ids: Vec<Uuid> = deliveries.iter().map(|d| d.id).collect();
+        let attempts = self.repository.list_attempts(&ids).await?;
+
+        Ok(deliveries
+            .iter()
+            .map(|delivery| WebhookDeliveryDto::new(delivery, &attempts))
+            .collect())
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::modules::webhooks::application::dto::WebhookSubscriptionDto;
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+
+pub struct ListWebhookSubscriptionsUseCase {
+    pub repository: Arc<dyn WebhookRepository>,
+}
+
+impl ListWebhookSubscriptionsUseCase {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WebhookSubscriptionDto>, WebhookError> {
+        let subscriptions = self.repository.list_subscriptions().await?;
+        Ok(subscriptions
+            .iter()
+            .map(WebhookSubscriptionDto::from)
+            .collect())
+    }
+}
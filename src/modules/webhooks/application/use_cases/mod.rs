@@ -0,0 +1,12 @@
+// Webhook subscription management, delivery history and replays
+pub mod create_webhook_subscription;
+pub mod deactivate_webhook_subscription;
+pub mod list_webhook_deliveries;
+pub mod list_webhook_subscriptions;
+pub mod replay_webhook_deliveries;
+
+pub use create_webhook_subscription::CreateWebhookSubscriptionUseCase;
+pub use deactivate_webhook_subscription::DeactivateWebhookSubscriptionUseCase;
+pub use list_webhook_deliveries::ListWebhookDeliveriesUseCase;
+pub use list_webhook_subscriptions::ListWebhookSubscriptionsUseCase;
+pub use replay_webhook_deliveries::ReplayWebhookDeliveriesUseCase;
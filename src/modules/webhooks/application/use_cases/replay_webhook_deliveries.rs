@@ -0,0 +1,129 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::deactivate_webhook_subscription::not_found;
+use crate::modules::webhooks::application::dto::{
+    ReplayWebhookDeliveriesDto, ReplayWebhookDeliveriesResponseDto,
+};
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+
+pub struct ReplayWebhookDeliveriesUseCase {
+    pub repository: Arc<dyn WebhookRepository>,
+}
+
+impl ReplayWebhookDeliveriesUseCase {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Requeues the subscription's dead-lettered deliveries, or just the given
+    /// one, with a fresh attempt budget. The dispatcher sends them on its next run.
+    pub async fn execute(
+        &self,
+        subscription_id: Uuid,
+        dto: ReplayWebhookDeliveriesDto,
+    ) -> Result<ReplayWebhookDeliveriesResponseDto, WebhookError> {
+        let subscription = self
+            .repository
+            .get_subscription(subscription_id)
+            .await?
+            .ok_or_else(|| not_found(subscription_id))?;
+        if !subscription.is_active() {
+            return Err(WebhookError::InvalidData(format!(
+                "Webhook {} sudah dinonaktifkan.",
+                subscription_id
+            )));
+        }
+
+        let replayed = self
+            .repository
+            .replay_failed(subscription_id, dto.delivery_id, Utc::now())
+            .await?;
+
+        Ok(ReplayWebhookDeliveriesResponseDto {
+            subscription_id,
+            replayed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+    use crate::modules::webhooks::domain::repositories::webhook_repository::MockWebhookRepository;
+
+    fn subscription(active: bool) -> WebhookSubscription {
+        let mut sub = WebhookSubscription::new(
+            Uuid::new_v4(),
+            "https://analytics.yomu.internal/engine".to_string(),
+            vec!["mission_completed".to_string()],
+            "rahasia-webhook-yomu".to_string(),
+            Utc::now(),
+        )
+        .unwrap();
+        sub.set_active(active);
+        sub
+    }
+
+    #[tokio::test]
+    async fn test_replay_requeues_failed_deliveries() {
+        let sub = subscription(true);
+        let id = sub.id();
+        let delivery_id = Uuid::new_v4();
+
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo
+            .expect_get_subscription()
+            .return_once(move |_| Ok(Some(sub)));
+        mock_repo
+            .expect_replay_failed()
+            .withf(move |sub_id, only, _| *sub_id == id && *only == Some(delivery_id))
+            .times(1)
+            .returning(|_, _, _| Ok(1));
+
+        let use_case = ReplayWebhookDeliveriesUseCase::new(Arc::new(mock_repo));
+        let result = use_case
+            .execute(
+                id,
+                ReplayWebhookDeliveriesDto {
+                    delivery_id: Some(delivery_id),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.replayed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_replay_refuses_unknown_or_inactive_subscription() {
+        let inactive = subscription(false);
+
+        let mut mock_repo = MockWebhookRepository::new();
+        mock_repo
+            .expect_get_subscription()
+            .times(1)
+            .return_once(|_| Ok(None));
+        mock_repo
+            .expect_get_subscription()
+            .return_once(move |_| Ok(Some(inactive)));
+        mock_repo.expect_replay_failed().never();
+
+        let use_case = ReplayWebhookDeliveriesUseCase::new(Arc::new(mock_repo));
+        let unknown = use_case
+            .execute(Uuid::new_v4(), ReplayWebhookDeliveriesDto::default())
+            .await;
+        let inactive = use_case
+            .execute(Uuid::new_v4(), ReplayWebhookDeliveriesDto::default())
+            .await;
+
+        assert!(matches!(
+            unknown,
+            Err(WebhookError::SubscriptionNotFound(_))
+        ));
+        assert!(matches!(inactive, Err(WebhookError::InvalidData(_))));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod webhook_delivery;
+pub mod webhook_signature;
+pub mod webhook_subscription;
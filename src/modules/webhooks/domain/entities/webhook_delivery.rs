@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::outbox::{EventEnvelope, OutboxStatus, RetryPolicy};
+
+/// One engine event on its way to one subscription. Deliveries follow the same
+/// lifecycle and retry policy as the outbox: pending until delivered, or
+/// dead-lettered once the policy allows no more attempts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event: EventEnvelope,
+    pub status: OutboxStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    /// HTTP status of the latest attempt, if a response arrived.
+    pub last_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// The log entry of a single attempt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryAttempt {
+    pub delivery_id: Uuid,
+    pub attempted_at: DateTime<Utc>,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+}
+
+impl WebhookDelivery {
+    /// Applies the outcome of an attempt: a 2xx `response_status` delivers,
+    /// anything else schedules a retry or dead-letters the delivery.
+    pub fn record_attempt(
+        &mut self,
+        response_status: Option<i32>,
+        error: Option<String>,
+        at: DateTime<Utc>,
+        policy: &RetryPolicy,
+    ) -> DeliveryAttempt {
+        self.attempts += 1;
+        self.last_status = response_status;
+        self.last_error = error.clone();
+
+        if error.is_none() {
+            self.status = OutboxStatus::Delivered;
+            self.delivered_at = Some(at);
+        } else if self.attempts >= policy.max_attempts {
+            self.status = OutboxStatus::DeadLetter;
+        } else {
+            self.next_attempt_at = at + policy.delay_after(self.attempts);
+        }
+
+        DeliveryAttempt {
+            delivery_id: self.id,
+            attempted_at: at,
+            response_status,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    fn delivery(now: DateTime<Utc>) -> WebhookDelivery {
+        WebhookDelivery {
+            id: Uuid::new_v4(),
+            subscription_id: Uuid::new_v4(),
+            event: EventEnvelope {
+                id: Uuid::new_v4(),
+                event_type: "mission_completed".to_string(),
+                occurred_at: now,
+                payload: serde_json::json!({ "type": "mission_completed" }),
+            },
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    #[test]
+    fn test_failed_attempts_back_off_then_dead_letter() {
+        let policy = RetryPolicy::new(2, TimeDelta::seconds(30), TimeDelta::minutes(5));
+        let now = Utc::now();
+        let mut delivery = delivery(now);
+
+        let first =
+            delivery.record_attempt(Some(503), Some("HTTP status 503".to_string()), now, &policy);
+        assert_eq!(first.response_status, Some(503));
+        assert_eq!(delivery.status, OutboxStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, now + TimeDelta::seconds(30));
+
+        delivery.record_attempt(None, Some("connection refused".to_string()), now, &policy);
+        assert_eq!(delivery.status, OutboxStatus::DeadLetter);
+        assert_eq!(delivery.last_status, None);
+        assert_eq!(delivery.attempts, 2);
+    }
+
+    #[test]
+    fn test_successful_attempt_delivers() {
+        let now = Utc::now();
+        let mut delivery = delivery(now);
+
+        let attempt = delivery.record_attempt(Some(204), None, now, &RetryPolicy::default());
+
+        assert_eq!(delivery.status, OutboxStatus::Delivered);
+        assert_eq!(delivery.delivered_at, Some(now));
+        assert_eq!(attempt.error, None);
+    }
+}
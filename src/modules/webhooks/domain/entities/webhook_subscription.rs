@@ -0,0 +1,137 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::shared::domain::outbox::EngineEvent;
+
+/// Shortest secret accepted for signing deliveries.
+pub const MIN_SECRET_LENGTH: usize = 16;
+
+/// An internal consumer that receives the engine events it subscribed to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookSubscription {
+    id: Uuid,
+    url: String,
+    event_types: Vec<String>,
+    secret: String,
+    active: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn new(
+        id: Uuid,
+        url: String,
+        event_types: Vec<String>,
+        secret: String,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, &'static str> {
+        let url = url.trim().to_string();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err("URL webhook harus diawali http:// atau https://.");
+        }
+        let mut event_types = event_types;
+        event_types.sort();
+        event_types.dedup();
+        if event_types.is_empty() {
+            return Err("Webhook harus berlangganan minimal satu jenis event.");
+        }
+        if event_types
+            .iter()
+            .any(|event_type| !EngineEvent::TYPES.contains(&event_type.as_str()))
+        {
+            return Err(
+                "Jenis event harus achievement_unlocked, mission_completed, clan_tier_changed, atau level_up.",
+            );
+        }
+        if secret.chars().count() < MIN_SECRET_LENGTH {
+            return Err("Secret webhook minimal 16 karakter.");
+        }
+
+        Ok(Self {
+            id,
+            url,
+            event_types,
+            secret,
+            active: true,
+            created_at,
+        })
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn event_types(&self) -> &[String] {
+        &self.event_types
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscription(
+        url: &str,
+        event_types: &[&str],
+        secret: &str,
+    ) -> Result<WebhookSubscription, &'static str> {
+        WebhookSubscription::new(
+            Uuid::new_v4(),
+            url.to_string(),
+            event_types.iter().map(|t| t.to_string()).collect(),
+            secret.to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_event_types_are_deduplicated() {
+        let sub = subscription(
+            " https://analytics.yomu.internal/hooks ",
+            &[
+                "mission_completed",
+                "achievement_unlocked",
+                "mission_completed",
+            ],
+            "rahasia-webhook-yomu",
+        )
+        .unwrap();
+
+        assert_eq!(sub.url(), "https://analytics.yomu.internal/hooks");
+        assert_eq!(
+            sub.event_types(),
+            ["achievement_unlocked", "mission_completed"]
+        );
+        assert!(sub.is_active());
+    }
+
+    #[test]
+    fn test_rejects_bad_url_unknown_event_and_short_secret() {
+        let secret = "rahasia-webhook-yomu";
+
+        assert!(subscription("ftp://host/hook", &["mission_completed"], secret).is_err());
+        assert!(subscription("https://host/hook", &[], secret).is_err());
+        assert!(subscription("https://host/hook", &["quiz_synced"], secret).is_err());
+        assert!(subscription("https://host/hook", &["mission_completed"], "pendek").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+// Domain errors - Custom error types for Webhooks module
+
+mod webhook_error;
+
+pub use webhook_error::WebhookError;
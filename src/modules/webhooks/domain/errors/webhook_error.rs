@@ -0,0 +1,66 @@
+use crate::shared::domain::base_error::AppError;
+use crate::shared::utils::response::ApiResponse;
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("Webhook subscription not found: {0}")]
+    SubscriptionNotFound(String),
+
+    #[error("Invalid data: {0}")]
+    InvalidData(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+}
+
+impl From<AppError> for WebhookError {
+    fn from(error: AppError) -> Self {
+        match error {
+            AppError::NotFound(msg) => WebhookError::SubscriptionNotFound(msg),
+            AppError::BadRequest(msg) => WebhookError::InvalidData(msg),
+            AppError::InternalServer(msg) => WebhookError::DatabaseError(msg),
+        }
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match &self {
+            WebhookError::SubscriptionNotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
+            WebhookError::InvalidData(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            WebhookError::DatabaseError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
+        };
+
+        let body = ApiResponse::<()>::error(&error_message);
+
+        tracing::error!("WebhookError: {:?}", self);
+
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webhook_error_subscription_not_found_maps_to_404() {
+        let error = WebhookError::SubscriptionNotFound("webhook_123".to_string());
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn webhook_error_from_app_error_keeps_the_status() {
+        let error: WebhookError = AppError::InternalServer("connection lost".to_string()).into();
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
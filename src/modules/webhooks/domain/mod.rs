@@ -0,0 +1,5 @@
+pub mod entities;
+pub mod errors;
+pub mod repositories;
+
+pub use repositories::WebhookRepository;
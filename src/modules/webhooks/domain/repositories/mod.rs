@@ -0,0 +1,3 @@
+pub mod webhook_repository;
+
+pub use webhook_repository::WebhookRepository;
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+use crate::modules::webhooks::domain::entities::webhook_delivery::{
+    DeliveryAttempt, WebhookDelivery,
+};
+use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::OutboxStatus;
+
+/// Subscriptions and their deliveries. Deliveries themselves are created by
+/// `OutboxRepository::enqueue`, together with the event.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_subscription(&self, subscription: &WebhookSubscription)
+    -> Result<(), AppError>;
+    /// Every subscription, inactive ones included, oldest first.
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, AppError>;
+    async fn get_subscription(&self, id: Uuid) -> Result<Option<WebhookSubscription>, AppError>;
+    /// Stops new deliveries to the subscription; returns false when it does not exist.
+    async fn deactivate_subscription(&self, id: Uuid) -> Result<bool, AppError>;
+
+    /// Up to `limit` pending deliveries of active subscriptions due at `now`,
+    /// each with its subscription. Each is leased for `lease` so that other
+    /// dispatchers skip it while it is being sent.
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: TimeDelta,
+        limit: i64,
+    ) -> Result<Vec<(WebhookDelivery, WebhookSubscription)>, AppError>;
+    /// Stores the delivery state after an attempt and appends the attempt to its log.
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &DeliveryAttempt,
+    ) -> Result<(), AppError>;
+
+    /// The subscription's most recent deliveries, optionally only those in `status`.
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        status: Option<OutboxStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError>;
+    /// Attempt logs of the given deliveries, oldest attempt first.
+    async fn list_attempts(&self, delivery_ids: &[Uuid]) -> Result<Vec<DeliveryAttempt>, AppError>;
+    /// Puts dead-lettered deliveries of the subscription (only `delivery_id`
+    /// when given) back in the queue with a fresh attempt budget. Returns how
+    /// many were requeued.
+    async fn replay_failed(
+        &self,
+        subscription_id: Uuid,
+        delivery_id: Option<Uuid>,
+        now: DateTime<Utc>,
+    ) -> Result<u64, AppError>;
+}
@@ -0,0 +1,334 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::modules::webhooks::domain::entities::webhook_delivery::{
+    DeliveryAttempt, WebhookDelivery,
+};
+use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::{EventEnvelope, OutboxStatus};
+
+const SUBSCRIPTION_COLUMNS: &str = "id, url, event_types, secret, is_active, created_at";
+
+/// Delivery columns joined with the event they carry (`d` and `o`).
+const DELIVERY_COLUMNS: &str = "d.id, d.subscription_id, d.event_id, o.event_type, \
+     o.occurred_at, o.payload::text AS payload, d.status, d.attempts, d.next_attempt_at, \
+     d.last_status, d.last_error, d.created_at, d.delivered_at";
+
+#[derive(Debug, FromRow)]
+struct SubscriptionRow {
+    id: Uuid,
+    url: String,
+    event_types: Vec<String>,
+    secret: String,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl SubscriptionRow {
+    fn into_subscription(self) -> Result<WebhookSubscription, AppError> {
+        let mut subscription = WebhookSubscription::new(
+            self.id,
+            self.url,
+            self.event_types,
+            self.secret,
+            self.created_at,
+        )
+        .map_err(|e| AppError::InternalServer(format!("Webhook {} tidak valid: {}", self.id, e)))?;
+        subscription.set_active(self.is_active);
+        Ok(subscription)
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct DeliveryRow {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_id: Uuid,
+    event_type: String,
+    occurred_at: DateTime<Utc>,
+    payload: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_status: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl DeliveryRow {
+    fn into_delivery(self) -> Result<WebhookDelivery, AppError> {
+        Ok(WebhookDelivery {
+            id: self.id,
+            subscription_id: self.subscription_id,
+            event: EventEnvelope {
+                id: self.event_id,
+                event_type: self.event_type,
+                occurred_at: self.occurred_at,
+                payload: serde_json::from_str(&self.payload)
+                    .map_err(|e| AppError::InternalServer(format!("Payload event rusak: {}", e)))?,
+            },
+            status: self
+                .status
+                .parse()
+                .map_err(|e: &str| AppError::InternalServer(e.to_string()))?,
+            attempts: self.attempts,
+            next_attempt_at: self.next_attempt_at,
+            last_status: self.last_status,
+            last_error: self.last_error,
+            created_at: self.created_at,
+            delivered_at: self.delivered_at,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct AttemptRow {
+    delivery_id: Uuid,
+    attempted_at: DateTime<Utc>,
+    response_status: Option<i32>,
+    error: Option<String>,
+}
+
+pub struct PostgresWebhookRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn db_error(e: sqlx::Error) -> AppError {
+    AppError::InternalServer(e.to_string())
+}
+
+#[async_trait]
+impl WebhookRepository for PostgresWebhookRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "INSERT INTO webhook_subscriptions (id, url, event_types, secret, is_active, created_at) \
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(subscription.id())
+        .bind(subscription.url())
+        .bind(subscription.event_types())
+        .bind(subscription.secret())
+        .bind(subscription.is_active())
+        .bind(subscription.created_at())
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(())
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, AppError> {
+        let rows = sqlx::query_as::<_, SubscriptionRow>(&format!(
+            "SELECT {} FROM webhook_subscriptions ORDER BY created_at, id",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        rows.into_iter()
+            .map(SubscriptionRow::into_subscription)
+            .collect()
+    }
+
+    async fn get_subscription(&self, id: Uuid) -> Result<Option<WebhookSubscription>, AppError> {
+        let row = sqlx::query_as::<_, SubscriptionRow>(&format!(
+            "SELECT {} FROM webhook_subscriptions WHERE id = $1",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        row.map(SubscriptionRow::into_subscription).transpose()
+    }
+
+    async fn deactivate_subscription(&self, id: Uuid) -> Result<bool, AppError> {
+        let result =
+            sqlx::query("UPDATE webhook_subscriptions SET is_active = false WHERE id = $1")
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map_err(db_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease: TimeDelta,
+        limit: i64,
+    ) -> Result<Vec<(WebhookDelivery, WebhookSubscription)>, AppError> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        let rows = sqlx::query_as::<_, DeliveryRow>(&format!(
+            "WITH due AS ( \
+                 SELECT d.id FROM webhook_deliveries d \
+                 JOIN webhook_subscriptions s ON s.id = d.subscription_id AND s.is_active \
+                 WHERE d.status = 'pending' AND d.next_attempt_at <= $1 \
+                 ORDER BY d.next_attempt_at, d.created_at \
+                 LIMIT $3 \
+                 FOR UPDATE OF d SKIP LOCKED \
+             ), claimed AS ( \
+                 UPDATE webhook_deliveries d SET next_attempt_at = $2 \
+                 FROM due WHERE d.id = due.id \
+                 RETURNING d.* \
+             ) \
+             SELECT {} FROM claimed d JOIN engine_outbox o ON o.id = d.event_id",
+            DELIVERY_COLUMNS
+        ))
+        .bind(now)
+        .bind(now + lease)
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        let subscription_ids: Vec<Uuid> = rows.iter().map(|r| r.subscription_id).collect();
+        let subscriptions = sqlx::query_as::<_, SubscriptionRow>(&format!(
+            "SELECT {} FROM webhook_subscriptions WHERE id = ANY($1)",
+            SUBSCRIPTION_COLUMNS
+        ))
+        .bind(&subscription_ids)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .into_iter()
+        .map(SubscriptionRow::into_subscription)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        tx.commit().await.map_err(db_error)?;
+
+        let mut claimed = Vec::with_capacity(rows.len());
+        for row in rows {
+            let Some(subscription) = subscriptions.iter().find(|s| s.id() == row.subscription_id)
+            else {
+                continue;
+            };
+            claimed.push((row.into_delivery()?, subscription.clone()));
+        }
+        claimed.sort_by_key(|(delivery, _)| delivery.event.occurred_at);
+        Ok(claimed)
+    }
+
+    async fn record_attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        attempt: &DeliveryAttempt,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await.map_err(db_error)?;
+
+        sqlx::query(
+            "UPDATE webhook_deliveries SET status = $2, attempts = $3, next_attempt_at = $4, \
+                 last_status = $5, last_error = $6, delivered_at = $7 \
+             WHERE id = $1",
+        )
+        .bind(delivery.id)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_status)
+        .bind(&delivery.last_error)
+        .bind(delivery.delivered_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        sqlx::query(
+            "INSERT INTO webhook_delivery_attempts (delivery_id, attempted_at, response_status, error) \
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(attempt.delivery_id)
+        .bind(attempt.attempted_at)
+        .bind(attempt.response_status)
+        .bind(&attempt.error)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        status: Option<OutboxStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let rows = sqlx::query_as::<_, DeliveryRow>(&format!(
+            "SELECT {} FROM webhook_deliveries d JOIN engine_outbox o ON o.id = d.event_id \
+             WHERE d.subscription_id = $1 AND ($2::text IS NULL OR d.status = $2) \
+             ORDER BY d.created_at DESC, d.id \
+             LIMIT $3",
+            DELIVERY_COLUMNS
+        ))
+        .bind(subscription_id)
+        .bind(status.map(|s| s.as_str()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        rows.into_iter().map(DeliveryRow::into_delivery).collect()
+    }
+
+    async fn list_attempts(&self, delivery_ids: &[Uuid]) -> Result<Vec<DeliveryAttempt>, AppError> {
+        if delivery_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rows = sqlx::query_as::<_, AttemptRow>(
+            "SELECT delivery_id, attempted_at, response_status, error \
+             FROM webhook_delivery_attempts WHERE delivery_id = ANY($1) ORDER BY id",
+        )
+        .bind(delivery_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DeliveryAttempt {
+                delivery_id: row.delivery_id,
+                attempted_at: row.attempted_at,
+                response_status: row.response_status,
+                error: row.error,
+            })
+            .collect())
+    }
+
+    async fn replay_failed(
+        &self,
+        subscription_id: Uuid,
+        delivery_id: Option<Uuid>,
+        now: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            "UPDATE webhook_deliveries SET status = 'pending', attempts = 0, next_attempt_at = $3 \
+             WHERE subscription_id = $1 AND status = 'dead_letter' AND ($2::uuid IS NULL OR id = $2)",
+        )
+        .bind(subscription_id)
+        .bind(delivery_id)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
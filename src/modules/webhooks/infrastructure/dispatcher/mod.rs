@@ -0,0 +1,3 @@
+pub mod webhook_dispatcher;
+
+pub use webhook_dispatcher::{DispatchReport, WebhookDispatcher, spawn_webhook_dispatcher};
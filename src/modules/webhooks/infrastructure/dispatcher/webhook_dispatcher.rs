@@ -0,0 +1,135 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::modules::webhooks::domain::entities::webhook_delivery::WebhookDelivery;
+use crate::modules::webhooks::domain::entities::webhook_signature::{
+    self, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+};
+use crate::modules::webhooks::domain::entities::webhook_subscription::WebhookSubscription;
+use crate::modules::webhooks::domain::repositories::WebhookRepository;
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::{OutboxStatus, RetryPolicy};
+use crate::shared::infrastructure::http::client::{HttpClient, HttpError};
+
+/// Deliveries claimed per run.
+const BATCH_SIZE: i64 = 50;
+
+/// How long a claimed delivery is hidden from other dispatchers; longer than
+/// the HTTP client timeout.
+const CLAIM_LEASE: TimeDelta = TimeDelta::seconds(60);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DispatchReport {
+    pub delivered: usize,
+    pub failed: usize,
+    pub dead_lettered: usize,
+}
+
+/// Sends pending webhook deliveries, signed with each subscription's secret.
+pub struct WebhookDispatcher {
+    repository: Arc<dyn WebhookRepository>,
+    client: HttpClient,
+    policy: RetryPolicy,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        repository: Arc<dyn WebhookRepository>,
+        client: HttpClient,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            repository,
+            client,
+            policy,
+        }
+    }
+
+    /// Attempts every delivery due at `now` once and logs each attempt.
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<DispatchReport, AppError> {
+        let mut report = DispatchReport::default();
+        let claimed = self
+            .repository
+            .claim_due_deliveries(now, CLAIM_LEASE, BATCH_SIZE)
+            .await?;
+
+        for (mut delivery, subscription) in claimed {
+            let (response_status, error) = match self.send(&delivery, &subscription).await {
+                Ok(status) => (Some(i32::from(status)), None),
+                Err(HttpError::Status(status)) => (
+                    Some(i32::from(status)),
+                    Some(HttpError::Status(status).to_string()),
+                ),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            let attempt = delivery.record_attempt(response_status, error, Utc::now(), &self.policy);
+
+            match delivery.status {
+                OutboxStatus::Delivered => report.delivered += 1,
+                OutboxStatus::DeadLetter => {
+                    tracing::error!(
+                        "Webhook delivery {} to {} dead-lettered after {} attempts: {}",
+                        delivery.id,
+                        subscription.url(),
+                        delivery.attempts,
+                        delivery.last_error.as_deref().unwrap_or_default()
+                    );
+                    report.dead_lettered += 1;
+                }
+                OutboxStatus::Pending => report.failed += 1,
+            }
+            self.repository.record_attempt(&delivery, &attempt).await?;
+        }
+
+        Ok(report)
+    }
+
+    async fn send(
+        &self,
+        delivery: &WebhookDelivery,
+        subscription: &WebhookSubscription,
+    ) -> Result<u16, HttpError> {
+        let body =
+            serde_json::to_string(&delivery.event).map_err(|e| HttpError::Body(e.to_string()))?;
+        let timestamp = Utc::now().timestamp();
+        let headers = [
+            (
+                SIGNATURE_HEADER,
+                webhook_signature::sign(subscription.secret(), timestamp, &body),
+            ),
+            (TIMESTAMP_HEADER, timestamp.to_string()),
+            (EVENT_HEADER, delivery.event.event_type.clone()),
+            (DELIVERY_HEADER, delivery.id.to_string()),
+        ];
+
+        self.client
+            .post_raw(subscription.url(), body, &headers)
+            .await
+    }
+}
+
+/// Runs the dispatcher every `interval`; claims use `SKIP LOCKED`, so several
+/// instances can dispatch side by side.
+pub fn spawn_webhook_dispatcher(
+    dispatcher: WebhookDispatcher,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match dispatcher.run_once(Utc::now()).await {
+                Ok(report) if report == DispatchReport::default() => {}
+                Ok(report) => tracing::info!(
+                    "Webhook dispatcher delivered {}, failed {}, dead-lettered {}",
+                    report.delivered,
+                    report.failed,
+                    report.dead_lettered
+                ),
+                Err(e) => tracing::warn!("Webhook dispatcher failed: {}", e),
+            }
+        }
+    })
+}
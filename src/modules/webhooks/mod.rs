@@ -0,0 +1,5 @@
+// Webhooks Module - Engine events for internal subscribers (Hexagonal Architecture)
+pub mod application;
+pub mod domain;
+pub mod infrastructure;
+pub mod presentation;
@@ -0,0 +1,181 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::modules::webhooks::application::dto::{
+    ReplayWebhookDeliveriesDto, ReplayWebhookDeliveriesResponseDto, WebhookDeliveryDto,
+    WebhookSubscriptionDto, WebhookSubscriptionRequestDto,
+};
+use crate::modules::webhooks::application::{
+    CreateWebhookSubscriptionUseCase, DeactivateWebhookSubscriptionUseCase,
+    ListWebhookDeliveriesUseCase, ListWebhookSubscriptionsUseCase, ReplayWebhookDeliveriesUseCase,
+};
+use crate::modules::webhooks::domain::errors::WebhookError;
+use crate::modules::webhooks::infrastructure::database::postgres::PostgresWebhookRepository;
+use crate::shared::utils::response::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookDeliveriesQuery {
+    status: Option<String>,
+    limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks",
+    responses(
+        (status = 200, description = "Every webhook subscription, inactive ones included; secrets are never returned", body = [WebhookSubscriptionDto]),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_webhook_subscriptions_handler(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<WebhookSubscriptionDto>>>, WebhookError> {
+    let use_case =
+        ListWebhookSubscriptionsUseCase::new(Arc::new(PostgresWebhookRepository::new(state.db)));
+
+    let subscriptions = use_case.execute().await?;
+
+    Ok(Json(ApiResponse::success(
+        "Daftar webhook berhasil diambil",
+        subscriptions,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhooks",
+    request_body = WebhookSubscriptionRequestDto,
+    responses(
+        (status = 201, description = "Subscription created; events written from now on are delivered to it", body = WebhookSubscriptionDto),
+        (status = 400, description = "Invalid URL, unknown event type or secret shorter than 16 characters"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn create_webhook_subscription_handler(
+    State(state): State<AppState>,
+    Json(dto): Json<WebhookSubscriptionRequestDto>,
+) -> Result<(StatusCode, Json<ApiResponse<WebhookSubscriptionDto>>), WebhookError> {
+    let use_case =
+        CreateWebhookSubscriptionUseCase::new(Arc::new(PostgresWebhookRepository::new(state.db)));
+
+    let subscription = use_case.execute(dto).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Webhook berhasil dibuat",
+            subscription,
+        )),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    responses(
+        (status = 200, description = "Subscription deactivated; its delivery history is kept", body = WebhookSubscriptionDto),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Webhook subscription not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn deactivate_webhook_subscription_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<WebhookSubscriptionDto>>, WebhookError> {
+    let use_case = DeactivateWebhookSubscriptionUseCase::new(Arc::new(
+        PostgresWebhookRepository::new(state.db),
+    ));
+
+    let subscription = use_case.execute(id).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Webhook berhasil dinonaktifkan",
+        subscription,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks/{id}/deliveries",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID"),
+        ("status" = Option<String>, Query, description = "Only deliveries that are pending, delivered or dead_letter"),
+        ("limit" = Option<i64>, Query, description = "Most recent deliveries to return (1-200, default 50)")
+    ),
+    responses(
+        (status = 200, description = "Most recent deliveries, newest first, each with the response status of every attempt", body = [WebhookDeliveryDto]),
+        (status = 400, description = "Unknown status or limit out of range"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Webhook subscription not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn list_webhook_deliveries_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<WebhookDeliveriesQuery>,
+) -> Result<Json<ApiResponse<Vec<WebhookDeliveryDto>>>, WebhookError> {
+    let use_case =
+        ListWebhookDeliveriesUseCase::new(Arc::new(PostgresWebhookRepository::new(state.db)));
+
+    let deliveries = use_case.execute(id, query.status, query.limit).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Riwayat pengiriman webhook berhasil diambil",
+        deliveries,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhooks/{id}/deliveries/replay",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    request_body = ReplayWebhookDeliveriesDto,
+    responses(
+        (status = 200, description = "Dead-lettered deliveries requeued with a fresh attempt budget", body = ReplayWebhookDeliveriesResponseDto),
+        (status = 400, description = "Webhook subscription is deactivated"),
+        (status = 401, description = "Missing or invalid x-admin-key"),
+        (status = 404, description = "Webhook subscription not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("admin_key" = [])),
+    tag = "admin"
+)]
+pub async fn replay_webhook_deliveries_handler(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(dto): Json<ReplayWebhookDeliveriesDto>,
+) -> Result<Json<ApiResponse<ReplayWebhookDeliveriesResponseDto>>, WebhookError> {
+    let use_case =
+        ReplayWebhookDeliveriesUseCase::new(Arc::new(PostgresWebhookRepository::new(state.db)));
+
+    let replayed = use_case.execute(id, dto).await?;
+
+    Ok(Json(ApiResponse::success(
+        "Pengiriman webhook yang gagal dijadwalkan ulang",
+        replayed,
+    )))
+}
@@ -0,0 +1,4 @@
+pub mod controllers;
+pub mod routes;
+
+pub use routes::webhook_admin_routes;
@@ -0,0 +1,28 @@
+use super::controllers::admin_webhook_controller;
+use crate::AppState;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+/// Webhook subscription and delivery maintenance routes, mounted under `/api/admin` behind `require_admin_key`.
+pub fn webhook_admin_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/webhooks",
+            get(admin_webhook_controller::list_webhook_subscriptions_handler)
+                .post(admin_webhook_controller::create_webhook_subscription_handler),
+        )
+        .route(
+            "/webhooks/{id}",
+            delete(admin_webhook_controller::deactivate_webhook_subscription_handler),
+        )
+        .route(
+            "/webhooks/{id}/deliveries",
+            get(admin_webhook_controller::list_webhook_deliveries_handler),
+        )
+        .route(
+            "/webhooks/{id}/deliveries/replay",
+            post(admin_webhook_controller::replay_webhook_deliveries_handler),
+        )
+}
@@ -1 +1,6 @@
 pub mod base_error;
+pub mod outbox;
+pub mod outbox_repository;
+pub mod unit_of_work;
+pub mod user_timezone;
+pub mod user_timezone_repository;
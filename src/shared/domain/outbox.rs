@@ -0,0 +1,268 @@
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// A change in the engine that the Java Core service needs to hear about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineEvent {
+    AchievementUnlocked {
+        user_id: Uuid,
+        achievement_id: Uuid,
+        name: String,
+    },
+    MissionCompleted {
+        user_id: Uuid,
+        mission_id: Uuid,
+        /// Start of the period instance that was completed.
+        period_start: NaiveDateTime,
+    },
+    /// Not emitted yet: no code path moves a clan between tiers.
+    ClanTierChanged {
+        clan_id: Uuid,
+        previous_tier: String,
+        tier: String,
+    },
+    LevelUp {
+        user_id: Uuid,
+        level: i32,
+        title: String,
+    },
+}
+
+impl EngineEvent {
+    /// Every `event_type` the engine emits.
+    pub const TYPES: [&'static str; 4] = [
+        "achievement_unlocked",
+        "mission_completed",
+        "clan_tier_changed",
+        "level_up",
+    ];
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            EngineEvent::AchievementUnlocked { .. } => "achievement_unlocked",
+            EngineEvent::MissionCompleted { .. } => "mission_completed",
+            EngineEvent::ClanTierChanged { .. } => "clan_tier_changed",
+            EngineEvent::LevelUp { .. } => "level_up",
+        }
+    }
+
+    /// The user or clan the event is about.
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            EngineEvent::AchievementUnlocked { user_id, .. } => *user_id,
+            EngineEvent::MissionCompleted { user_id, .. } => *user_id,
+            EngineEvent::ClanTierChanged { clan_id, .. } => *clan_id,
+            EngineEvent::LevelUp { user_id, .. } => *user_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutboxStatus {
+    Pending,
+    Delivered,
+    /// Gave up after the last allowed attempt; kept for inspection.
+    DeadLetter,
+}
+
+impl OutboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Delivered => "delivered",
+            OutboxStatus::DeadLetter => "dead_letter",
+        }
+    }
+}
+
+impl fmt::Display for OutboxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OutboxStatus {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(OutboxStatus::Pending),
+            "delivered" => Ok(OutboxStatus::Delivered),
+            "dead_letter" => Ok(OutboxStatus::DeadLetter),
+            _ => Err("Status outbox tidak dikenal"),
+        }
+    }
+}
+
+/// How the relay retries a failed delivery: the wait doubles after every failed
+/// attempt, up to `max_delay`, and the message is dead-lettered once it has
+/// failed `max_attempts` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: i32,
+    pub base_delay: TimeDelta,
+    pub max_delay: TimeDelta,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: i32, base_delay: TimeDelta, max_delay: TimeDelta) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            max_delay: max_delay.max(base_delay),
+        }
+    }
+
+    /// The wait before the next attempt, after `attempts` failed ones.
+    pub fn delay_after(&self, attempts: i32) -> TimeDelta {
+        let exponent = u32::try_from(attempts.saturating_sub(1)).unwrap_or(0);
+        2i32.checked_pow(exponent)
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(8, TimeDelta::seconds(30), TimeDelta::hours(1))
+    }
+}
+
+/// The body every receiver of an engine event gets; `id` is the outbox message
+/// id, so receivers can drop redeliveries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub id: Uuid,
+    pub event_type: String,
+    pub occurred_at: DateTime<Utc>,
+    pub payload: serde_json::Value,
+}
+
+/// One event in `engine_outbox` together with its delivery state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: serde_json::Value,
+    pub status: OutboxStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxMessage {
+    /// A pending message for `event`, due right away.
+    pub fn new(event: &EngineEvent, occurred_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: serde_json::to_value(event).unwrap_or_default(),
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: occurred_at,
+            last_error: None,
+            occurred_at,
+            delivered_at: None,
+        }
+    }
+
+    pub fn envelope(&self) -> EventEnvelope {
+        EventEnvelope {
+            id: self.id,
+            event_type: self.event_type.clone(),
+            occurred_at: self.occurred_at,
+            payload: self.payload.clone(),
+        }
+    }
+
+    pub fn mark_delivered(&mut self, at: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = OutboxStatus::Delivered;
+        self.delivered_at = Some(at);
+        self.last_error = None;
+    }
+
+    /// Schedules the next attempt, or dead-letters the message when the policy
+    /// allows no more.
+    pub fn record_failure(&mut self, error: String, at: DateTime<Utc>, policy: &RetryPolicy) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+        if self.attempts >= policy.max_attempts {
+            self.status = OutboxStatus::DeadLetter;
+        } else {
+            self.next_attempt_at = at + policy.delay_after(self.attempts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlocked() -> EngineEvent {
+        EngineEvent::AchievementUnlocked {
+            user_id: Uuid::new_v4(),
+            achievement_id: Uuid::new_v4(),
+            name: "Kutu Buku".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_message_carries_the_event_type_in_its_payload() {
+        let event = unlocked();
+        let message = OutboxMessage::new(&event, Utc::now());
+
+        assert_eq!(message.event_type, "achievement_unlocked");
+        assert_eq!(message.aggregate_id, event.aggregate_id());
+        assert_eq!(message.payload["type"], "achievement_unlocked");
+        assert_eq!(message.payload["name"], "Kutu Buku");
+        assert_eq!(message.status, OutboxStatus::Pending);
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_is_capped() {
+        let policy = RetryPolicy::new(10, TimeDelta::seconds(10), TimeDelta::seconds(60));
+
+        assert_eq!(policy.delay_after(1), TimeDelta::seconds(10));
+        assert_eq!(policy.delay_after(2), TimeDelta::seconds(20));
+        assert_eq!(policy.delay_after(3), TimeDelta::seconds(40));
+        assert_eq!(policy.delay_after(4), TimeDelta::seconds(60));
+        assert_eq!(policy.delay_after(40), TimeDelta::seconds(60));
+    }
+
+    #[test]
+    fn test_failures_dead_letter_after_max_attempts() {
+        let policy = RetryPolicy::new(2, TimeDelta::seconds(10), TimeDelta::seconds(60));
+        let now = Utc::now();
+        let mut message = OutboxMessage::new(&unlocked(), now);
+
+        message.record_failure("HTTP status 500".to_string(), now, &policy);
+        assert_eq!(message.status, OutboxStatus::Pending);
+        assert_eq!(message.next_attempt_at, now + TimeDelta::seconds(10));
+
+        message.record_failure("HTTP status 500".to_string(), now, &policy);
+        assert_eq!(message.status, OutboxStatus::DeadLetter);
+        assert_eq!(message.attempts, 2);
+        assert_eq!(message.last_error.as_deref(), Some("HTTP status 500"));
+    }
+
+    #[test]
+    fn test_status_round_trips_through_its_stored_name() {
+        for status in [
+            OutboxStatus::Pending,
+            OutboxStatus::Delivered,
+            OutboxStatus::DeadLetter,
+        ] {
+            assert_eq!(status.as_str().parse::<OutboxStatus>(), Ok(status));
+        }
+        assert!("sent".parse::<OutboxStatus>().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::{EngineEvent, OutboxMessage};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Adds the events as pending messages, plus a pending webhook delivery for
+    /// every active subscription to their type. Run it on the unit of work that
+    /// makes the change, so the events are stored exactly when the change is.
+    async fn enqueue(&self, events: &[EngineEvent], at: DateTime<Utc>) -> Result<(), AppError>;
+    /// Up to `limit` pending messages due at `now`, oldest first. Each is leased
+    /// for `lease` so that other relays skip it while it is being delivered.
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease: TimeDelta,
+        limit: i64,
+    ) -> Result<Vec<OutboxMessage>, AppError>;
+    /// Stores the delivery state of a claimed message.
+    async fn save(&self, message: &OutboxMessage) -> Result<(), AppError>;
+}
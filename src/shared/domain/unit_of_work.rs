@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::modules::gamification::domain::repositories::{
+    AchievementRepository, MissionRepository, StreakRepository,
+};
+use crate::modules::league::domain::repositories::ClanRepository;
+use crate::modules::user_sync::domain::repositories::{
+    QuizHistoryRepository, QuizReviewRepository, UserRepository,
+};
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+use crate::shared::domain::user_timezone_repository::UserTimezoneRepository;
+
+/// Repositories whose writes land together or not at all.
+///
+/// Every repository handed out by one unit of work shares a single transaction.
+/// Nothing written through them is visible to others until `commit`; `rollback`,
+/// or dropping the unit of work uncommitted, discards all of it.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn users(&self) -> Arc<dyn UserRepository>;
+    fn quiz_histories(&self) -> Arc<dyn QuizHistoryRepository>;
+    fn quiz_reviews(&self) -> Arc<dyn QuizReviewRepository>;
+    fn missions(&self) -> Arc<dyn MissionRepository>;
+    fn achievements(&self) -> Arc<dyn AchievementRepository>;
+    fn streaks(&self) -> Arc<dyn StreakRepository>;
+    fn clans(&self) -> Arc<dyn ClanRepository>;
+    /// Events enqueued here are stored only if the unit of work commits.
+    fn outbox(&self) -> Arc<dyn OutboxRepository>;
+    fn timezones(&self) -> Arc<dyn UserTimezoneRepository>;
+    /// Makes other units of work that lock `user_id` wait until this one ends.
+    /// Take it before reading progress that will be written back, so concurrent
+    /// writes for the same user cannot lose progress or pay a reward twice.
+    async fn lock_user(&self, user_id: Uuid) -> Result<(), AppError>;
+    async fn commit(&self) -> Result<(), AppError>;
+    async fn rollback(&self) -> Result<(), AppError>;
+}
+
+/// Starts units of work, one per business operation.
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, AppError>;
+}
+
+/// Commits `uow` when `result` succeeded and rolls it back otherwise. A failed
+/// rollback is only logged, so the caller sees the error that caused it.
+pub async fn finish<T, E: From<AppError>>(
+    uow: &dyn UnitOfWork,
+    result: Result<T, E>,
+) -> Result<T, E> {
+    match result {
+        Ok(value) => {
+            uow.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback) = uow.rollback().await {
+                tracing::warn!("Rollback failed: {}", rollback);
+            }
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::user_timezone_repository::UserTimezoneRepository;
+
+/// Offsets outside this range (in minutes) do not exist on any real clock.
+const MIN_UTC_OFFSET_MINUTES: i32 = -12 * 60;
+const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// The timezone a user's days are counted in, as a fixed offset from UTC.
+///
+/// Daily missions, streaks and everything else keyed by "today" read the
+/// calendar on this clock, so a quiz at 06:00 WIB counts towards that local
+/// day rather than the previous UTC one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UserTimezone {
+    utc_offset_minutes: i32,
+}
+
+impl UserTimezone {
+    pub const UTC: Self = Self {
+        utc_offset_minutes: 0,
+    };
+
+    pub fn from_offset_minutes(minutes: i32) -> Result<Self, &'static str> {
+        if !(MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&minutes) {
+            return Err("Offset zona waktu harus di antara -720 dan 840 menit");
+        }
+        Ok(Self {
+            utc_offset_minutes: minutes,
+        })
+    }
+
+    pub fn utc_offset_minutes(&self) -> i32 {
+        self.utc_offset_minutes
+    }
+
+    /// The wall-clock time `at` shows in this timezone.
+    pub fn local_time(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        FixedOffset::east_opt(self.utc_offset_minutes * 60)
+            .map(|offset| at.with_timezone(&offset).naive_local())
+            .unwrap_or_else(|| at.naive_utc())
+    }
+
+    /// The calendar day `at` falls on in this timezone.
+    pub fn local_date(&self, at: DateTime<Utc>) -> NaiveDate {
+        self.local_time(at).date()
+    }
+}
+
+/// Decides which timezone a user's "today" is read in.
+pub struct UserClock {
+    repository: Arc<dyn UserTimezoneRepository>,
+    default_timezone: UserTimezone,
+}
+
+impl UserClock {
+    pub fn new(
+        repository: Arc<dyn UserTimezoneRepository>,
+        default_timezone: UserTimezone,
+    ) -> Self {
+        Self {
+            repository,
+            default_timezone,
+        }
+    }
+
+    /// The timezone the caller reports, else the one last synced for the user,
+    /// else the configured default. A reported timezone that differs from the
+    /// synced one replaces it, so later requests without one agree with it.
+    pub async fn timezone(
+        &self,
+        user_id: Uuid,
+        reported: Option<UserTimezone>,
+    ) -> Result<UserTimezone, AppError> {
+        let synced = self.repository.get_timezone(user_id).await?;
+        match reported {
+            Some(timezone) => {
+                if synced != Some(timezone) {
+                    self.repository.set_timezone(user_id, timezone).await?;
+                }
+                Ok(timezone)
+            }
+            None => Ok(synced.unwrap_or(self.default_timezone)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::domain::user_timezone_repository::MockUserTimezoneRepository;
+    use chrono::TimeZone;
+
+    fn wib() -> UserTimezone {
+        UserTimezone::from_offset_minutes(7 * 60).unwrap()
+    }
+
+    #[test]
+    fn test_local_date_follows_the_offset() {
+        let at = Utc.with_ymd_and_hms(2026, 5, 1, 23, 30, 0).unwrap();
+
+        assert_eq!(
+            UserTimezone::UTC.local_date(at),
+            NaiveDate::from_ymd_opt(2026, 5, 1).unwrap()
+        );
+        assert_eq!(
+            wib().local_date(at),
+            NaiveDate::from_ymd_opt(2026, 5, 2).unwrap()
+        );
+        assert!(UserTimezone::from_offset_minutes(15 * 60).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_clock_falls_back_from_synced_to_default() {
+        let mut repo = MockUserTimezoneRepository::new();
+        repo.expect_get_timezone()
+            .returning(|_| Ok(Some(UserTimezone::from_offset_minutes(-300).unwrap())));
+        repo.expect_set_timezone().never();
+        let clock = UserClock::new(Arc::new(repo), wib());
+
+        let synced = clock.timezone(Uuid::new_v4(), None).await.unwrap();
+
+        assert_eq!(synced.utc_offset_minutes(), -300);
+
+        let mut repo = MockUserTimezoneRepository::new();
+        repo.expect_get_timezone().returning(|_| Ok(None));
+        let clock = UserClock::new(Arc::new(repo), wib());
+
+        assert_eq!(clock.timezone(Uuid::new_v4(), None).await.unwrap(), wib());
+    }
+
+    #[tokio::test]
+    async fn test_clock_stores_a_reported_timezone_that_changed() {
+        let mut repo = MockUserTimezoneRepository::new();
+        repo.expect_get_timezone().returning(|_| Ok(None));
+        repo.expect_set_timezone()
+            .withf(|_, timezone| timezone.utc_offset_minutes() == 420)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let clock = UserClock::new(Arc::new(repo), UserTimezone::UTC);
+
+        let timezone = clock.timezone(Uuid::new_v4(), Some(wib())).await.unwrap();
+
+        assert_eq!(timezone, wib());
+    }
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::user_timezone::UserTimezone;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UserTimezoneRepository: Send + Sync {
+    /// The timezone last synced for the user; `None` when it was never
+    /// reported or the user is unknown.
+    async fn get_timezone(&self, user_id: Uuid) -> Result<Option<UserTimezone>, AppError>;
+    /// Stores the user's timezone. Unknown users are left alone.
+    async fn set_timezone(&self, user_id: Uuid, timezone: UserTimezone) -> Result<(), AppError>;
+}
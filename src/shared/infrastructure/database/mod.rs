@@ -0,0 +1,9 @@
+pub mod outbox_repository;
+pub mod pg_handle;
+pub mod unit_of_work;
+pub mod user_timezone_repository;
+
+pub use outbox_repository::PostgresOutboxRepository;
+pub use pg_handle::{PgConn, PgHandle};
+pub use unit_of_work::{PostgresUnitOfWork, PostgresUnitOfWorkFactory};
+pub use user_timezone_repository::PostgresUserTimezoneRepository;
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::pg_handle::{PgConn, PgHandle};
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::{EngineEvent, OutboxMessage};
+use crate::shared::domain::outbox_repository::OutboxRepository;
+
+#[derive(Debug, FromRow)]
+struct OutboxRow {
+    id: Uuid,
+    event_type: String,
+    aggregate_id: Uuid,
+    payload: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_error: Option<String>,
+    occurred_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxRow {
+    fn into_message(self) -> Result<OutboxMessage, AppError> {
+        Ok(OutboxMessage {
+            id: self.id,
+            event_type: self.event_type,
+            aggregate_id: self.aggregate_id,
+            payload: serde_json::from_str(&self.payload)
+                .map_err(|e| AppError::InternalServer(format!("Payload outbox rusak: {}", e)))?,
+            status: self
+                .status
+                .parse()
+                .map_err(|e: &str| AppError::InternalServer(e.to_string()))?,
+            attempts: self.attempts,
+            next_attempt_at: self.next_attempt_at,
+            last_error: self.last_error,
+            occurred_at: self.occurred_at,
+            delivered_at: self.delivered_at,
+        })
+    }
+}
+
+pub struct PostgresOutboxRepository {
+    db: PgHandle,
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
+    }
+}
+
+/// Stores `events` in the outbox on `conn`, together with a delivery for every
+/// active webhook subscribed to them. For writers that hold a connection rather
+/// than a repository; run it in their transaction.
+pub async fn enqueue_events(
+    conn: &mut PgConnection,
+    events: &[EngineEvent],
+    at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<OutboxMessage> = events
+        .iter()
+        .map(|event| OutboxMessage::new(event, at))
+        .collect();
+    let ids: Vec<Uuid> = messages.iter().map(|m| m.id).collect();
+    let event_types: Vec<&str> = messages.iter().map(|m| m.event_type.as_str()).collect();
+    let aggregate_ids: Vec<Uuid> = messages.iter().map(|m| m.aggregate_id).collect();
+    let payloads: Vec<String> = messages.iter().map(|m| m.payload.to_string()).collect();
+
+    // Fanning out in the same statement gives every active subscriber exactly
+    // the events that get committed.
+    sqlx::query(
+        "WITH inserted AS ( \
+             INSERT INTO engine_outbox (id, event_type, aggregate_id, payload, next_attempt_at, occurred_at) \
+             SELECT e.id, e.event_type, e.aggregate_id, e.payload::jsonb, $5, $5 \
+             FROM UNNEST($1::uuid[], $2::text[], $3::uuid[], $4::text[]) \
+                 AS e(id, event_type, aggregate_id, payload) \
+             RETURNING id, event_type \
+         ) \
+         INSERT INTO webhook_deliveries (id, subscription_id, event_id, next_attempt_at, created_at) \
+         SELECT gen_random_uuid(), s.id, i.id, $5, $5 \
+         FROM inserted i \
+         JOIN webhook_subscriptions s ON s.is_active AND i.event_type = ANY(s.event_types)",
+    )
+    .bind(&ids)
+    .bind(&event_types)
+    .bind(&aggregate_ids)
+    .bind(&payloads)
+    .bind(at)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn enqueue(&self, events: &[EngineEvent], at: DateTime<Utc>) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
+        enqueue_events(&mut conn, events, at)
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal menulis outbox: {}", e)))
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease: TimeDelta,
+        limit: i64,
+    ) -> Result<Vec<OutboxMessage>, AppError> {
+        let mut conn = self.conn().await?;
+        let rows = sqlx::query_as::<_, OutboxRow>(
+            "UPDATE engine_outbox o SET next_attempt_at = $2 \
+             FROM ( \
+                 SELECT id FROM engine_outbox \
+                 WHERE status = 'pending' AND next_attempt_at <= $1 \
+                 ORDER BY next_attempt_at, occurred_at \
+                 LIMIT $3 \
+                 FOR UPDATE SKIP LOCKED \
+             ) due \
+             WHERE o.id = due.id \
+             RETURNING o.id, o.event_type, o.aggregate_id, o.payload::text AS payload, o.status, \
+                 o.attempts, o.next_attempt_at, o.last_error, o.occurred_at, o.delivered_at",
+        )
+        .bind(now)
+        .bind(now + lease)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(format!("Gagal mengambil outbox: {}", e)))?;
+
+        let mut messages = rows
+            .into_iter()
+            .map(OutboxRow::into_message)
+            .collect::<Result<Vec<_>, _>>()?;
+        messages.sort_by_key(|m| m.occurred_at);
+        Ok(messages)
+    }
+
+    async fn save(&self, message: &OutboxMessage) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query(
+            "UPDATE engine_outbox SET status = $2, attempts = $3, next_attempt_at = $4, \
+                 last_error = $5, delivered_at = $6 \
+             WHERE id = $1",
+        )
+        .bind(message.id)
+        .bind(message.status.as_str())
+        .bind(message.attempts)
+        .bind(message.next_attempt_at)
+        .bind(&message.last_error)
+        .bind(message.delivered_at)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(format!("Gagal menyimpan status outbox: {}", e)))?;
+
+        Ok(())
+    }
+}
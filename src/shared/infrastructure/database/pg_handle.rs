@@ -0,0 +1,63 @@
+use sqlx::pool::PoolConnection;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
+
+/// A transaction shared by the repositories of one unit of work. It is `None`
+/// once committed or rolled back.
+pub type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+/// Where a Postgres repository runs its statements: straight on the pool, or
+/// inside a transaction it shares with the other repositories of a unit of work.
+#[derive(Clone)]
+pub enum PgHandle {
+    Pool(PgPool),
+    Transaction(SharedTransaction),
+}
+
+impl PgHandle {
+    /// A connection for the next statements. On a transaction this holds the
+    /// transaction until dropped, so repositories must not keep it across calls.
+    pub async fn acquire(&self) -> Result<PgConn<'_>, sqlx::Error> {
+        match self {
+            PgHandle::Pool(pool) => Ok(PgConn::Pooled(pool.acquire().await?)),
+            PgHandle::Transaction(tx) => {
+                MutexGuard::try_map(tx.lock().await, |tx| tx.as_deref_mut())
+                    .map(PgConn::Transaction)
+                    .map_err(|_| sqlx::Error::Protocol("Transaksi sudah selesai".to_string()))
+            }
+        }
+    }
+}
+
+impl From<PgPool> for PgHandle {
+    fn from(pool: PgPool) -> Self {
+        PgHandle::Pool(pool)
+    }
+}
+
+pub enum PgConn<'a> {
+    Pooled(PoolConnection<Postgres>),
+    Transaction(MappedMutexGuard<'a, PgConnection>),
+}
+
+impl Deref for PgConn<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            PgConn::Pooled(conn) => conn,
+            PgConn::Transaction(conn) => conn,
+        }
+    }
+}
+
+impl DerefMut for PgConn<'_> {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            PgConn::Pooled(conn) => conn,
+            PgConn::Transaction(conn) => conn,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use super::outbox_repository::PostgresOutboxRepository;
+use super::pg_handle::{PgHandle, SharedTransaction};
+use super::user_timezone_repository::PostgresUserTimezoneRepository;
+use crate::modules::gamification::domain::repositories::{
+    AchievementRepository, MissionRepository, StreakRepository,
+};
+use crate::modules::gamification::infrastructure::database::postgres::{
+    PostgresAchievementRepository, PostgresMissionRepository, PostgresStreakRepository,
+};
+use crate::modules::league::domain::repositories::ClanRepository;
+use crate::modules::league::infrastructure::database::postgres::ClanPostgresRepo;
+use crate::modules::user_sync::domain::repositories::{
+    QuizHistoryRepository, QuizReviewRepository, UserRepository,
+};
+use crate::modules::user_sync::infrastructure::database::postgres::{
+    QuizHistoryPostgresRepo, QuizReviewPostgresRepo, UserPostgresRepo,
+};
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox_repository::OutboxRepository;
+use crate::shared::domain::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use crate::shared::domain::user_timezone_repository::UserTimezoneRepository;
+
+/// A unit of work over one Postgres transaction.
+pub struct PostgresUnitOfWork {
+    tx: SharedTransaction,
+}
+
+impl PostgresUnitOfWork {
+    pub async fn begin(pool: &PgPool) -> Result<Self, AppError> {
+        let tx = pool
+            .begin()
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal memulai transaksi: {}", e)))?;
+        Ok(Self {
+            tx: Arc::new(Mutex::new(Some(tx))),
+        })
+    }
+
+    fn handle(&self) -> PgHandle {
+        PgHandle::Transaction(self.tx.clone())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    fn users(&self) -> Arc<dyn UserRepository> {
+        Arc::new(UserPostgresRepo::with_handle(self.handle()))
+    }
+
+    fn quiz_histories(&self) -> Arc<dyn QuizHistoryRepository> {
+        Arc::new(QuizHistoryPostgresRepo::with_handle(self.handle()))
+    }
+
+    fn quiz_reviews(&self) -> Arc<dyn QuizReviewRepository> {
+        Arc::new(QuizReviewPostgresRepo::with_handle(self.handle()))
+    }
+
+    fn missions(&self) -> Arc<dyn MissionRepository> {
+        Arc::new(PostgresMissionRepository::with_handle(self.handle()))
+    }
+
+    fn achievements(&self) -> Arc<dyn AchievementRepository> {
+        Arc::new(PostgresAchievementRepository::with_handle(self.handle()))
+    }
+
+    fn streaks(&self) -> Arc<dyn StreakRepository> {
+        Arc::new(PostgresStreakRepository::with_handle(self.handle()))
+    }
+
+    fn clans(&self) -> Arc<dyn ClanRepository> {
+        Arc::new(ClanPostgresRepo::with_handle(self.handle()))
+    }
+
+    fn outbox(&self) -> Arc<dyn OutboxRepository> {
+        Arc::new(PostgresOutboxRepository::with_handle(self.handle()))
+    }
+
+    fn timezones(&self) -> Arc<dyn UserTimezoneRepository> {
+        Arc::new(PostgresUserTimezoneRepository::with_handle(self.handle()))
+    }
+
+    async fn lock_user(&self, user_id: Uuid) -> Result<(), AppError> {
+        let mut guard = self.tx.lock().await;
+        let tx = guard.as_mut().ok_or_else(finished)?;
+        sqlx::query("SELECT 1 FROM shadow_users WHERE user_id = $1 FOR UPDATE")
+            .bind(user_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal mengunci user: {}", e)))?;
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<(), AppError> {
+        let tx = self.tx.lock().await.take().ok_or_else(finished)?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal menyimpan transaksi: {}", e)))
+    }
+
+    async fn rollback(&self) -> Result<(), AppError> {
+        let tx = self.tx.lock().await.take().ok_or_else(finished)?;
+        tx.rollback()
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal membatalkan transaksi: {}", e)))
+    }
+}
+
+fn finished() -> AppError {
+    AppError::InternalServer("Transaksi sudah di-commit atau di-rollback".to_string())
+}
+
+/// Begins each unit of work on its own transaction from the pool.
+pub struct PostgresUnitOfWorkFactory {
+    pool: PgPool,
+}
+
+impl PostgresUnitOfWorkFactory {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for PostgresUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, AppError> {
+        Ok(Box::new(PostgresUnitOfWork::begin(&self.pool).await?))
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::pg_handle::{PgConn, PgHandle};
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::user_timezone::UserTimezone;
+use crate::shared::domain::user_timezone_repository::UserTimezoneRepository;
+
+/// Keeps the timezone on the user's `shadow_users` row.
+pub struct PostgresUserTimezoneRepository {
+    db: PgHandle,
+}
+
+impl PostgresUserTimezoneRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            db: PgHandle::Pool(pool),
+        }
+    }
+
+    /// Runs on `db`, e.g. the transaction of a unit of work.
+    pub fn with_handle(db: PgHandle) -> Self {
+        Self { db }
+    }
+
+    async fn conn(&self) -> Result<PgConn<'_>, AppError> {
+        self.db
+            .acquire()
+            .await
+            .map_err(|e| AppError::InternalServer(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl UserTimezoneRepository for PostgresUserTimezoneRepository {
+    async fn get_timezone(&self, user_id: Uuid) -> Result<Option<UserTimezone>, AppError> {
+        let mut conn = self.conn().await?;
+        let minutes = sqlx::query_scalar::<_, Option<i32>>(
+            "SELECT utc_offset_minutes FROM shadow_users WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| AppError::InternalServer(format!("Gagal membaca zona waktu: {}", e)))?
+        .flatten();
+
+        minutes
+            .map(|minutes| {
+                UserTimezone::from_offset_minutes(minutes)
+                    .map_err(|e| AppError::InternalServer(e.to_string()))
+            })
+            .transpose()
+    }
+
+    async fn set_timezone(&self, user_id: Uuid, timezone: UserTimezone) -> Result<(), AppError> {
+        let mut conn = self.conn().await?;
+        sqlx::query("UPDATE shadow_users SET utc_offset_minutes = $2 WHERE user_id = $1")
+            .bind(user_id)
+            .bind(timezone.utc_offset_minutes())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| AppError::InternalServer(format!("Gagal menyimpan zona waktu: {}", e)))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use super::api_key::{header_matches, unauthorized};
+use crate::AppState;
+
+pub const ADMIN_KEY_HEADER: &str = "x-admin-key";
+
+/// Rejects requests whose `x-admin-key` header does not match `ADMIN_API_KEY`.
+///
+/// Apply with `axum::middleware::from_fn_with_state` on admin routers only.
+pub async fn require_admin_key(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = state.config.admin_api_key.as_deref() else {
+        tracing::warn!("Admin request rejected: ADMIN_API_KEY is not configured");
+        return unauthorized("API admin dinonaktifkan");
+    };
+
+    if !header_matches(request.headers(), ADMIN_KEY_HEADER, expected) {
+        return unauthorized("Akses ditolak: x-admin-key tidak valid atau hilang");
+    }
+
+    next.run(request).await
+}
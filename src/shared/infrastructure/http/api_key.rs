@@ -0,0 +1,24 @@
+use axum::{
+    Json,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use subtle::ConstantTimeEq;
+
+use crate::shared::utils::response::ApiResponse;
+
+/// Whether `header` carries `expected`. The bytes are compared in constant
+/// time so response timing does not reveal how much of a guess was right.
+pub(crate) fn header_matches(headers: &HeaderMap, header: &str, expected: &str) -> bool {
+    headers
+        .get(header)
+        .is_some_and(|value| bool::from(value.as_bytes().ct_eq(expected.as_bytes())))
+}
+
+pub(crate) fn unauthorized(message: &str) -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(ApiResponse::<()>::error(message)),
+    )
+        .into_response()
+}
@@ -75,6 +75,35 @@ impl HttpClient {
             .await
             .map_err(|e| HttpError::Body(e.to_string()))
     }
+
+    /// Make a POST request to an absolute URL outside Java Core with an already
+    /// serialized JSON body and the given headers; the API key is not sent.
+    /// Returns the response status.
+    pub async fn post_raw(
+        &self,
+        url: &str,
+        body: String,
+        headers: &[(&str, String)],
+    ) -> Result<u16, HttpError> {
+        let mut request = self
+            .inner
+            .post(url)
+            .header("content-type", "application/json")
+            .body(body);
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| HttpError::Request(e.to_string()))?;
+
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            return Err(HttpError::Status(status));
+        }
+        Ok(status)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use super::api_key::{header_matches, unauthorized};
+use crate::AppState;
+
+pub const INTERNAL_KEY_HEADER: &str = "x-api-key";
+
+/// Rejects requests whose `x-api-key` header does not match `INTERNAL_API_KEY`.
+///
+/// Apply with `axum::middleware::from_fn_with_state` on the `/api/internal`
+/// router, which only the Java core calls.
+pub async fn require_internal_key(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = state.config.internal_api_key.as_deref() else {
+        tracing::warn!("Internal request rejected: INTERNAL_API_KEY is not configured");
+        return unauthorized("API internal dinonaktifkan");
+    };
+
+    if !header_matches(request.headers(), INTERNAL_KEY_HEADER, expected) {
+        return unauthorized("Akses ditolak: x-api-key tidak valid atau hilang");
+    }
+
+    next.run(request).await
+}
@@ -1 +1,4 @@
+pub mod admin_auth;
+mod api_key;
 pub mod client;
+pub mod internal_auth;
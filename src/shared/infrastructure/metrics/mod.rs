@@ -13,6 +13,16 @@ pub struct HttpLabels {
     pub status: u16,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct QuizQuarantineLabels {
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct QuizReviewLabels {
+    pub decision: String,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
 pub enum HttpMethod {
     GET,
@@ -46,6 +56,8 @@ pub struct AppMetrics {
     pub redis_pool_active: Gauge,
     pub cache_hits: Counter,
     pub cache_misses: Counter,
+    pub quiz_quarantined: Family<QuizQuarantineLabels, Counter>,
+    pub quiz_reviews: Family<QuizReviewLabels, Counter>,
 }
 
 impl AppMetrics {
@@ -102,6 +114,19 @@ impl AppMetrics {
         registry.register("cache_hits_total", "Cache hits", cache_hits.clone());
         registry.register("cache_misses_total", "Cache misses", cache_misses.clone());
 
+        let quiz_quarantined: Family<QuizQuarantineLabels, Counter> = Family::default();
+        registry.register(
+            "quiz_sync_quarantined",
+            "Quiz syncs held for review, by detector",
+            quiz_quarantined.clone(),
+        );
+        let quiz_reviews: Family<QuizReviewLabels, Counter> = Family::default();
+        registry.register(
+            "quiz_reviews_resolved",
+            "Held quiz syncs approved or rejected by an admin",
+            quiz_reviews.clone(),
+        );
+
         Self {
             registry,
             http_requests,
@@ -112,6 +137,8 @@ impl AppMetrics {
             redis_pool_active,
             cache_hits,
             cache_misses,
+            quiz_quarantined,
+            quiz_reviews,
         }
     }
 
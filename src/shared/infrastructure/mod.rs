@@ -1,4 +1,6 @@
+pub mod database;
 pub mod http;
 pub mod logging;
 pub mod metrics;
+pub mod outbox;
 pub mod telemetry;
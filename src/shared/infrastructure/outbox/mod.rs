@@ -0,0 +1,3 @@
+pub mod relay;
+
+pub use relay::{OutboxRelay, RelayReport, spawn_outbox_relay};
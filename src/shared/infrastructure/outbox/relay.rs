@@ -0,0 +1,104 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::shared::domain::base_error::AppError;
+use crate::shared::domain::outbox::{OutboxMessage, OutboxStatus, RetryPolicy};
+use crate::shared::domain::outbox_repository::OutboxRepository;
+use crate::shared::infrastructure::http::client::HttpClient;
+
+/// Java Core endpoint that receives engine events.
+pub const ENGINE_EVENTS_PATH: &str = "/api/internal/engine-events";
+
+/// Messages claimed per run.
+const BATCH_SIZE: i64 = 50;
+
+/// How long a claimed message is hidden from other relays. Longer than the
+/// HTTP client timeout, so a message is never sent twice concurrently.
+const CLAIM_LEASE: TimeDelta = TimeDelta::seconds(60);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RelayReport {
+    pub delivered: usize,
+    pub failed: usize,
+    pub dead_lettered: usize,
+}
+
+/// Delivers pending outbox messages to Java Core.
+pub struct OutboxRelay {
+    outbox: Arc<dyn OutboxRepository>,
+    client: HttpClient,
+    policy: RetryPolicy,
+}
+
+impl OutboxRelay {
+    pub fn new(outbox: Arc<dyn OutboxRepository>, client: HttpClient, policy: RetryPolicy) -> Self {
+        Self {
+            outbox,
+            client,
+            policy,
+        }
+    }
+
+    /// Sends every message due at `now` once and stores the outcome.
+    pub async fn run_once(&self, now: DateTime<Utc>) -> Result<RelayReport, AppError> {
+        let mut report = RelayReport::default();
+
+        for mut message in self.outbox.claim_due(now, CLAIM_LEASE, BATCH_SIZE).await? {
+            match self.deliver(&message).await {
+                Ok(()) => {
+                    message.mark_delivered(Utc::now());
+                    report.delivered += 1;
+                }
+                Err(error) => {
+                    message.record_failure(error, Utc::now(), &self.policy);
+                    if message.status == OutboxStatus::DeadLetter {
+                        tracing::error!(
+                            "Outbox message {} ({}) dead-lettered after {} attempts: {}",
+                            message.id,
+                            message.event_type,
+                            message.attempts,
+                            message.last_error.as_deref().unwrap_or_default()
+                        );
+                        report.dead_lettered += 1;
+                    } else {
+                        report.failed += 1;
+                    }
+                }
+            }
+            self.outbox.save(&message).await?;
+        }
+
+        Ok(report)
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> Result<(), String> {
+        self.client
+            .post(ENGINE_EVENTS_PATH, &message.envelope())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the relay every `interval`. Claims are leased with `SKIP LOCKED`, so
+/// several instances can relay side by side.
+pub fn spawn_outbox_relay(relay: OutboxRelay, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match relay.run_once(Utc::now()).await {
+                Ok(report) if report == RelayReport::default() => {}
+                Ok(report) => tracing::info!(
+                    "Outbox relay delivered {}, failed {}, dead-lettered {}",
+                    report.delivered,
+                    report.failed,
+                    report.dead_lettered
+                ),
+                Err(e) => tracing::warn!("Outbox relay failed: {}", e),
+            }
+        }
+    })
+}
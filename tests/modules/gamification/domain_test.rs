@@ -63,7 +63,7 @@ mod misson_test {
         assert_eq!(mission.reward_points(), 50);
 
         let user_id = Uuid::new_v4();
-        let mut user_mission = UserMission::new(user_id, mission.id());
+        let mut user_mission = UserMission::new(user_id, mission.id(), mission.window().starts_at);
 
         user_mission.add_progress(1, mission.target_count());
         
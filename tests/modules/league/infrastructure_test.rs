@@ -329,3 +329,232 @@ mod redis_tests {
         }
     }
 }
+
+mod redis_ranking_tests {
+    use super::*;
+    use std::time::Duration;
+    use yomu_backend_rust::modules::league::domain::entities::leaderboard_ranking::RankingMode;
+    use yomu_backend_rust::modules::league::domain::repositories::LeaderboardCache;
+    use yomu_backend_rust::modules::league::infrastructure::database::redis::LeaderboardRedisRepo;
+
+    async fn setup_redis() -> redis::aio::MultiplexedConnection {
+        let client = redis::Client::open(TEST_REDIS_URL).expect("Failed to create Redis client");
+        client
+            .get_multiplexed_async_connection()
+            .await
+            .expect("Failed to connect to Redis")
+    }
+
+    async fn cleanup(con: &mut redis::aio::MultiplexedConnection, tier: &str, clans: &[Uuid]) {
+        let members: Vec<String> = clans.iter().map(|id| id.to_string()).collect();
+        let _: () = redis::cmd("DEL")
+            .arg(format!("leaderboard:{}", tier))
+            .arg(format!("leaderboard:{}:reached_at", tier))
+            .query_async(con)
+            .await
+            .expect("Failed to clean up tier keys");
+        let _: () = redis::cmd("ZREM")
+            .arg("leaderboard:global")
+            .arg(&members)
+            .query_async(con)
+            .await
+            .expect("Failed to clean up global leaderboard");
+        let _: () = redis::cmd("HDEL")
+            .arg("leaderboard:global:reached_at")
+            .arg(&members)
+            .query_async(con)
+            .await
+            .expect("Failed to clean up global timestamps");
+    }
+
+    /// Three clans where the first two tie on 100; ids are chosen so that the
+    /// clan reaching 100 first sorts *after* the other lexicographically.
+    async fn seed_tie(repo: &LeaderboardRedisRepo, tier: &str) -> (Uuid, Uuid, Uuid) {
+        let first = Uuid::parse_str("ffffffff-0000-4000-8000-000000000000").unwrap();
+        let second = Uuid::new_v4();
+        let third = Uuid::new_v4();
+
+        repo.update_clan_score(first, tier, 100).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        repo.update_clan_score(second, tier, 60).await.unwrap();
+        repo.update_clan_score(third, tier, 50).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        repo.update_clan_score(second, tier, 40).await.unwrap();
+
+        (first, second, third)
+    }
+
+    #[tokio::test]
+    async fn test_redis_ties_go_to_clan_reaching_score_first() {
+        let mut con = setup_redis().await;
+        let tier = format!("Tie{}", Uuid::new_v4().simple());
+        let repo = LeaderboardRedisRepo::new(con.clone());
+
+        let (first, second, third) = seed_tie(&repo, &tier).await;
+
+        let top_one = repo.get_top_clans(&tier, 1).await;
+        let top_three = repo.get_top_clans(&tier, 3).await;
+
+        cleanup(&mut con, &tier, &[first, second, third]).await;
+
+        let top_one = top_one.expect("Failed to read leaderboard");
+        assert_eq!(top_one.len(), 1);
+        assert_eq!(top_one[0].clan_id, first);
+
+        let top_three = top_three.expect("Failed to read leaderboard");
+        let ids: Vec<Uuid> = top_three.iter().map(|e| e.clan_id).collect();
+        let ranks: Vec<usize> = top_three.iter().map(|e| e.rank).collect();
+        assert_eq!(ids, vec![first, second, third]);
+        assert_eq!(ranks, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_redis_shared_ranking_mode() {
+        let mut con = setup_redis().await;
+        let tier = format!("Shared{}", Uuid::new_v4().simple());
+        let repo = LeaderboardRedisRepo::new(con.clone()).with_ranking_mode(RankingMode::Shared);
+
+        let (first, second, third) = seed_tie(&repo, &tier).await;
+
+        let top = repo.get_top_clans(&tier, 10).await;
+
+        cleanup(&mut con, &tier, &[first, second, third]).await;
+
+        let top = top.expect("Failed to read leaderboard");
+        let ids: Vec<Uuid> = top.iter().map(|e| e.clan_id).collect();
+        let ranks: Vec<usize> = top.iter().map(|e| e.rank).collect();
+        assert_eq!(ids, vec![first, second, third]);
+        assert_eq!(ranks, vec![1, 1, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_redis_clan_standing() {
+        let mut con = setup_redis().await;
+        let tier = format!("Standing{}", Uuid::new_v4().simple());
+        let repo = LeaderboardRedisRepo::new(con.clone());
+        let shared_repo =
+            LeaderboardRedisRepo::new(con.clone()).with_ranking_mode(RankingMode::Shared);
+
+        let (first, second, third) = seed_tie(&repo, &tier).await;
+
+        let leader = repo.get_clan_standing(&tier, first).await;
+        let runner_up = repo.get_clan_standing(&tier, second).await;
+        let last = repo.get_clan_standing(&tier, third).await;
+        let shared_runner_up = shared_repo.get_clan_standing(&tier, second).await;
+        let shared_last = shared_repo.get_clan_standing(&tier, third).await;
+        let missing = repo.get_clan_standing(&tier, Uuid::new_v4()).await;
+
+        cleanup(&mut con, &tier, &[first, second, third]).await;
+
+        let leader = leader.unwrap().expect("Leader should be ranked");
+        assert_eq!(leader.rank, 1);
+        assert_eq!(leader.total, 3);
+        assert_eq!(leader.points_to_next_rank, None);
+
+        let runner_up = runner_up.unwrap().expect("Runner-up should be ranked");
+        assert_eq!(runner_up.rank, 2);
+        assert_eq!(runner_up.points_to_next_rank, Some(1));
+
+        let last = last.unwrap().expect("Last clan should be ranked");
+        assert_eq!(last.rank, 3);
+        assert_eq!(last.score, 50);
+        assert_eq!(last.points_to_next_rank, Some(51));
+
+        let shared_runner_up = shared_runner_up
+            .unwrap()
+            .expect("Runner-up should be ranked");
+        assert_eq!(shared_runner_up.rank, 1);
+        assert_eq!(shared_runner_up.points_to_next_rank, None);
+
+        let shared_last = shared_last.unwrap().expect("Last clan should be ranked");
+        assert_eq!(shared_last.rank, 3);
+        assert_eq!(shared_last.points_to_next_rank, Some(50));
+
+        assert!(missing.unwrap().is_none());
+    }
+}
+
+mod realtime_tests {
+    use super::*;
+    use std::time::Duration;
+    use yomu_backend_rust::modules::league::domain::entities::leaderboard_update::LeaderboardUpdate;
+    use yomu_backend_rust::modules::league::domain::repositories::LeaderboardPublisher;
+    use yomu_backend_rust::modules::league::infrastructure::realtime::{
+        LeaderboardHub, LeaderboardSubscription, spawn_redis_relay,
+    };
+
+    #[tokio::test]
+    async fn test_subscription_debounces_bursts_into_one_change() {
+        let hub = LeaderboardHub::local();
+        let mut subscription =
+            LeaderboardSubscription::new(hub.subscribe(), "Gold", Duration::from_millis(50));
+
+        for score in 1..=5 {
+            hub.publish(&LeaderboardUpdate::new(Uuid::new_v4(), "Gold", score))
+                .await
+                .unwrap();
+        }
+
+        assert!(subscription.changed().await);
+
+        let second = tokio::time::timeout(Duration::from_millis(200), subscription.changed()).await;
+        assert!(second.is_err(), "burst should collapse into one change");
+    }
+
+    #[tokio::test]
+    async fn test_subscription_ignores_other_tiers() {
+        let hub = LeaderboardHub::local();
+        let mut subscription =
+            LeaderboardSubscription::new(hub.subscribe(), "Gold", Duration::from_millis(10));
+
+        hub.dispatch(LeaderboardUpdate::new(Uuid::new_v4(), "Bronze", 10));
+
+        let result = tokio::time::timeout(Duration::from_millis(100), subscription.changed()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_subscription_ends_when_hub_dropped() {
+        let hub = LeaderboardHub::local();
+        let mut subscription =
+            LeaderboardSubscription::new(hub.subscribe(), "Gold", Duration::from_millis(10));
+
+        drop(hub);
+
+        assert!(!subscription.changed().await);
+    }
+
+    #[tokio::test]
+    async fn test_redis_hub_relays_published_updates() {
+        let client = redis::Client::open(TEST_REDIS_URL).expect("Failed to create Redis client");
+        let conn = client
+            .get_multiplexed_async_connection()
+            .await
+            .expect("Failed to connect to Redis");
+
+        let hub = LeaderboardHub::redis(conn);
+        let mut receiver = hub.subscribe();
+        let relay = spawn_redis_relay(client, hub.clone());
+
+        let update = LeaderboardUpdate::new(Uuid::new_v4(), "Silver", 77);
+
+        // The relay subscribes asynchronously; keep publishing until it is listening.
+        let received = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                hub.publish(&update).await.expect("Failed to publish");
+                if let Ok(Ok(received)) =
+                    tokio::time::timeout(Duration::from_millis(100), receiver.recv()).await
+                {
+                    return received;
+                }
+            }
+        })
+        .await
+        .expect("Update was not relayed");
+
+        relay.abort();
+
+        assert_eq!(received.clan_id, update.clan_id);
+        assert_eq!(received.total_score, 77);
+    }
+}
@@ -18,6 +18,7 @@ use yomu_backend_rust::modules::league::domain::entities::clan::Clan;
 use yomu_backend_rust::modules::league::domain::entities::clan::ClanTier;
 use yomu_backend_rust::modules::league::domain::entities::clan_member::ClanMember;
 use yomu_backend_rust::modules::league::domain::entities::clan_member::MemberRole;
+use yomu_backend_rust::modules::league::domain::entities::leaderboard_ranking::ClanStanding;
 use yomu_backend_rust::modules::league::domain::repositories::ClanRepository;
 use yomu_backend_rust::modules::league::domain::repositories::LeaderboardCache;
 use yomu_backend_rust::shared::domain::base_error::AppError;
@@ -40,8 +41,9 @@ mock! {
     LeaderboardCacheRepo {}
     #[async_trait]
     impl LeaderboardCache for LeaderboardCacheRepo {
-        async fn update_clan_score(&self, clan_id: Uuid, score: i64) -> Result<(), AppError>;
+        async fn update_clan_score(&self, clan_id: Uuid, tier: &str, score: i64) -> Result<(), AppError>;
         async fn get_top_clans(&self, tier: &str, limit: usize) -> Result<Vec<LeaderboardEntry>, AppError>;
+        async fn get_clan_standing(&self, tier: &str, clan_id: Uuid) -> Result<Option<ClanStanding>, AppError>;
     }
 }
 
@@ -171,9 +173,24 @@ async fn test_update_score_success() {
     let multiplier = 1.5f64;
     let expected_final_score = 150i64;
 
+    let clan = Clan::with_id(
+        clan_id,
+        "Test Clan".to_string(),
+        Uuid::new_v4(),
+        ClanTier::Gold,
+        0,
+        chrono::Utc::now(),
+    );
+
     let mut mock_repo = MockClanRepositoryRepo::new();
     let mut mock_leaderboard = MockLeaderboardCacheRepo::new();
 
+    mock_repo
+        .expect_get_clan_by_id()
+        .with(mockall::predicate::eq(clan_id))
+        .return_once(move |_| Ok(Some(clan)))
+        .once();
+
     mock_repo
         .expect_add_score()
         .with(
@@ -187,9 +204,10 @@ async fn test_update_score_success() {
         .expect_update_clan_score()
         .with(
             mockall::predicate::eq(clan_id),
+            mockall::predicate::eq("Gold"),
             mockall::predicate::eq(expected_final_score),
         )
-        .return_once(|_, _| Ok(()))
+        .return_once(|_, _, _| Ok(()))
         .once();
 
     let use_case = UpdateScoreUseCase::new(mock_repo, mock_leaderboard);
@@ -206,6 +224,33 @@ async fn test_update_score_success() {
     assert_eq!(result.unwrap(), expected_final_score);
 }
 
+#[tokio::test]
+async fn test_update_score_clan_not_found() {
+    let clan_id = Uuid::new_v4();
+
+    let mut mock_repo = MockClanRepositoryRepo::new();
+    let mut mock_leaderboard = MockLeaderboardCacheRepo::new();
+
+    mock_repo
+        .expect_get_clan_by_id()
+        .return_once(|_| Ok(None))
+        .once();
+    mock_repo.expect_add_score().never();
+    mock_leaderboard.expect_update_clan_score().never();
+
+    let use_case = UpdateScoreUseCase::new(mock_repo, mock_leaderboard);
+    let dto = UpdateScoreDto {
+        clan_id,
+        user_id: Uuid::new_v4(),
+        base_score: 100,
+        multiplier: 1.0,
+    };
+
+    let result = use_case.execute(dto).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
 #[tokio::test]
 async fn test_get_leaderboard_success() {
     let tier = "Diamond".to_string();
@@ -561,7 +606,7 @@ async fn get_user_tier_has_clan() {
         "Tier Clan".to_string(),
         leader_id,
         ClanTier::Gold,
-        750,
+        21_000,
         chrono::Utc::now(),
     );
 
@@ -575,7 +620,41 @@ async fn get_user_tier_has_clan() {
         .expect_get_clan_by_id()
         .return_once(|_| Ok(Some(clan)));
 
-    let use_case = GetUserTierUseCase::new(mock_repo);
+    let mut mock_leaderboard = MockLeaderboardCacheRepo::new();
+
+    mock_leaderboard
+        .expect_get_clan_standing()
+        .with(
+            mockall::predicate::eq("Gold"),
+            mockall::predicate::eq(clan_id),
+        )
+        .return_once(|_, _| {
+            Ok(Some(ClanStanding {
+                rank: 2,
+                total: 4,
+                score: 20_400,
+                points_to_next_rank: Some(301),
+            }))
+        })
+        .once();
+
+    mock_leaderboard
+        .expect_get_clan_standing()
+        .with(
+            mockall::predicate::eq("global"),
+            mockall::predicate::eq(clan_id),
+        )
+        .return_once(|_, _| {
+            Ok(Some(ClanStanding {
+                rank: 7,
+                total: 40,
+                score: 20_400,
+                points_to_next_rank: Some(12),
+            }))
+        })
+        .once();
+
+    let use_case = GetUserTierUseCase::new(mock_repo, mock_leaderboard);
 
     let result: Result<UserTierDto, AppError> = use_case.execute(user_id).await;
 
@@ -585,6 +664,53 @@ async fn get_user_tier_has_clan() {
     assert_eq!(dto.clan_id, Some(clan_id));
     assert_eq!(dto.clan_name, Some("Tier Clan".to_string()));
     assert_eq!(dto.tier, Some("Gold".to_string()));
+    assert_eq!(dto.tier_rank, Some(2));
+    assert_eq!(dto.global_rank, Some(7));
+    assert_eq!(dto.percentile, Some(75.0));
+    assert_eq!(dto.points_to_next_rank, Some(301));
+    assert_eq!(dto.next_tier, Some("Diamond".to_string()));
+    // the leaderboard may lag behind; the tier threshold follows the clans table
+    assert_eq!(dto.points_to_next_tier, Some(29_000));
+}
+
+#[tokio::test]
+async fn get_user_tier_clan_not_on_leaderboard_yet() {
+    let user_id = Uuid::new_v4();
+    let clan_id = Uuid::new_v4();
+
+    let clan = Clan::with_id(
+        clan_id,
+        "Fresh Clan".to_string(),
+        Uuid::new_v4(),
+        ClanTier::Bronze,
+        1_200,
+        chrono::Utc::now(),
+    );
+
+    let mut mock_repo = MockClanRepositoryRepo::new();
+    mock_repo
+        .expect_get_user_clan_id()
+        .return_once(move |_| Ok(Some(clan_id)));
+    mock_repo
+        .expect_get_clan_by_id()
+        .return_once(|_| Ok(Some(clan)));
+
+    let mut mock_leaderboard = MockLeaderboardCacheRepo::new();
+    mock_leaderboard
+        .expect_get_clan_standing()
+        .returning(|_, _| Ok(None))
+        .times(2);
+
+    let use_case = GetUserTierUseCase::new(mock_repo, mock_leaderboard);
+
+    let dto = use_case.execute(user_id).await.unwrap();
+
+    assert_eq!(dto.tier, Some("Bronze".to_string()));
+    assert!(dto.tier_rank.is_none());
+    assert!(dto.global_rank.is_none());
+    assert!(dto.percentile.is_none());
+    assert_eq!(dto.next_tier, Some("Silver".to_string()));
+    assert_eq!(dto.points_to_next_tier, Some(3_800));
 }
 
 #[tokio::test]
@@ -597,7 +723,7 @@ async fn get_user_tier_no_clan() {
         .expect_get_user_clan_id()
         .return_once(|_| Ok(None));
 
-    let use_case = GetUserTierUseCase::new(mock_repo);
+    let use_case = GetUserTierUseCase::new(mock_repo, MockLeaderboardCacheRepo::new());
 
     let result: Result<UserTierDto, AppError> = use_case.execute(user_id).await;
 
@@ -621,7 +747,7 @@ async fn get_user_tier_empty_optionals() {
 
     mock_repo.expect_get_clan_by_id().return_once(|_| Ok(None));
 
-    let use_case = GetUserTierUseCase::new(mock_repo);
+    let use_case = GetUserTierUseCase::new(mock_repo, MockLeaderboardCacheRepo::new());
 
     let result: Result<UserTierDto, AppError> = use_case.execute(user_id).await;
 
@@ -675,7 +801,7 @@ async fn empty_database_get_user_tier() {
         .expect_get_user_clan_id()
         .return_once(|_| Ok(None));
 
-    let use_case = GetUserTierUseCase::new(mock_repo);
+    let use_case = GetUserTierUseCase::new(mock_repo, MockLeaderboardCacheRepo::new());
 
     let result: Result<UserTierDto, AppError> = use_case.execute(user_id).await;
 
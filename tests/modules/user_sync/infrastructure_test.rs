@@ -8,6 +8,8 @@ use yomu_backend_rust::modules::user_sync::domain::repositories::UserRepository;
 use yomu_backend_rust::modules::user_sync::domain::repositories::quiz_history_repository::QuizHistoryRepository;
 use yomu_backend_rust::modules::user_sync::infrastructure::database::postgres::user_postgres_repo::UserPostgresRepo;
 use yomu_backend_rust::modules::user_sync::infrastructure::database::postgres::quiz_history_postgres_repo::QuizHistoryPostgresRepo;
+use yomu_backend_rust::shared::domain::unit_of_work::UnitOfWork;
+use yomu_backend_rust::shared::infrastructure::database::PostgresUnitOfWork;
 
 mod pg_tests {
     use super::*;
@@ -172,7 +174,7 @@ mod pg_tests {
             .await
             .expect("Insert should succeed");
 
-        repo.update_total_score(user_id, 100)
+        repo.update_total_score(user_id, 100, Uuid::new_v4(), chrono::Utc::now())
             .await
             .expect("Update should succeed");
 
@@ -208,13 +210,13 @@ mod pg_tests {
             .await
             .expect("Insert should succeed");
 
-        repo.update_total_score(user_id, 50)
+        repo.update_total_score(user_id, 50, Uuid::new_v4(), chrono::Utc::now())
             .await
             .expect("First update should succeed");
-        repo.update_total_score(user_id, 30)
+        repo.update_total_score(user_id, 30, Uuid::new_v4(), chrono::Utc::now())
             .await
             .expect("Second update should succeed");
-        repo.update_total_score(user_id, 20)
+        repo.update_total_score(user_id, 20, Uuid::new_v4(), chrono::Utc::now())
             .await
             .expect("Third update should succeed");
 
@@ -287,6 +289,109 @@ mod pg_tests {
         pool.close().await;
     }
 
+    #[tokio::test]
+    async fn test_pg_insert_quiz_history_dedupes_on_event_id() {
+        let pool = setup_pg_pool().await;
+        let user_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+
+        sqlx::query("INSERT INTO engine_users (user_id, total_score) VALUES ($1, 0)")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert engine user");
+
+        let repo = QuizHistoryPostgresRepo::new(pool.clone());
+        let first =
+            QuizHistory::new(user_id, Uuid::new_v4(), 85, 0.92).with_event_id(Some(event_id));
+        let retry =
+            QuizHistory::new(user_id, Uuid::new_v4(), 85, 0.92).with_event_id(Some(event_id));
+
+        let first_inserted = repo
+            .insert_quiz_history(&first)
+            .await
+            .expect("First insert should succeed");
+        let retry_inserted = repo
+            .insert_quiz_history(&retry)
+            .await
+            .expect("Retry should not fail");
+        repo.record_sync_result(first.id(), 3)
+            .await
+            .expect("Recording the result should succeed");
+        let receipt = repo
+            .get_sync_receipt(event_id)
+            .await
+            .expect("Query should succeed")
+            .expect("Receipt should exist");
+        let histories = repo
+            .get_quiz_histories_by_user(user_id)
+            .await
+            .expect("Query should succeed");
+
+        cleanup_all_test_data(&pool, user_id).await;
+        pool.close().await;
+
+        assert!(first_inserted);
+        assert!(!retry_inserted, "Retried event should not be inserted");
+        assert_eq!(histories.len(), 1);
+        assert_eq!(histories[0].event_id(), Some(event_id));
+        assert_eq!(receipt.quiz_id, first.id());
+        assert_eq!(receipt.missions_updated, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_pg_unit_of_work_applies_writes_only_on_commit() {
+        let pool = setup_pg_pool().await;
+        let user_id = Uuid::new_v4();
+
+        sqlx::query("INSERT INTO engine_users (user_id, total_score) VALUES ($1, 0)")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert engine user");
+        let user_repo = UserPostgresRepo::new(pool.clone());
+        user_repo
+            .insert_shadow_user(&ShadowUser::new(user_id))
+            .await
+            .expect("Insert should succeed");
+
+        for commit in [false, true] {
+            let uow = PostgresUnitOfWork::begin(&pool)
+                .await
+                .expect("Transaction should start");
+            let quiz = QuizHistory::new(user_id, Uuid::new_v4(), 40, 0.9);
+            uow.quiz_histories()
+                .insert_quiz_history(&quiz)
+                .await
+                .expect("Insert should succeed");
+            uow.users()
+                .update_total_score(user_id, 40, quiz.id(), quiz.completed_at())
+                .await
+                .expect("Score update should succeed");
+            if commit {
+                uow.commit().await.expect("Commit should succeed");
+            } else {
+                uow.rollback().await.expect("Rollback should succeed");
+            }
+        }
+
+        let histories = QuizHistoryPostgresRepo::new(pool.clone())
+            .get_quiz_histories_by_user(user_id)
+            .await
+            .expect("Query should succeed");
+        let user = user_repo
+            .get_shadow_user(user_id)
+            .await
+            .expect("Query should succeed")
+            .expect("User should exist");
+
+        cleanup_all_test_data(&pool, user_id).await;
+        pool.close().await;
+
+        assert_eq!(histories.len(), 1, "Only the committed quiz should remain");
+        assert_eq!(user.total_score(), 40);
+    }
+
     #[tokio::test]
     async fn test_pg_get_quiz_histories_by_user_returns_histories() {
         let pool = setup_pg_pool().await;
@@ -368,7 +473,7 @@ mod pg_tests {
             .expect("Insert quiz history should succeed");
 
         user_repo
-            .update_total_score(user_id, 75)
+            .update_total_score(user_id, 75, quiz.id(), quiz.completed_at())
             .await
             .expect("Update score should succeed");
 
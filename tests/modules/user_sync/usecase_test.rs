@@ -2,17 +2,39 @@
 // Tests SyncNewUserUseCase and SyncQuizHistoryUseCase with mocked repositories
 
 use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
 use mockall::mock;
+use std::sync::Arc;
 use uuid::Uuid;
+use yomu_backend_rust::modules::gamification::domain::entities::user_streak::StreakPolicy;
+use yomu_backend_rust::modules::gamification::domain::repositories::{
+    AchievementRepository, MissionRepository, StreakRepository,
+};
+use yomu_backend_rust::modules::league::domain::repositories::ClanRepository;
+use yomu_backend_rust::modules::user_sync::application::ProcessQuizSyncUseCase;
 use yomu_backend_rust::modules::user_sync::application::SyncNewUserUseCase;
 use yomu_backend_rust::modules::user_sync::application::SyncQuizHistoryUseCase;
 use yomu_backend_rust::modules::user_sync::application::dto::QuizHistoryRequestDto;
 use yomu_backend_rust::modules::user_sync::application::dto::SyncUserRequestDto;
-use yomu_backend_rust::modules::user_sync::domain::entities::quiz_history::QuizHistory;
+use yomu_backend_rust::modules::user_sync::domain::entities::quiz_fraud::{
+    QuizFraudChain, QuizFraudFlag, QuizFraudPolicy, QuizFraudReason,
+};
+use yomu_backend_rust::modules::user_sync::domain::entities::quiz_history::{
+    QuizHistory, QuizSyncReceipt,
+};
+use yomu_backend_rust::modules::user_sync::domain::entities::quiz_review::{
+    QuarantinedQuiz, QuizReviewStatus,
+};
 use yomu_backend_rust::modules::user_sync::domain::entities::shadow_user::ShadowUser;
+use yomu_backend_rust::modules::user_sync::domain::errors::UserSyncError;
 use yomu_backend_rust::modules::user_sync::domain::repositories::QuizHistoryRepository;
+use yomu_backend_rust::modules::user_sync::domain::repositories::QuizReviewRepository;
 use yomu_backend_rust::modules::user_sync::domain::repositories::UserRepository;
 use yomu_backend_rust::shared::domain::base_error::AppError;
+use yomu_backend_rust::shared::domain::outbox_repository::OutboxRepository;
+use yomu_backend_rust::shared::domain::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use yomu_backend_rust::shared::domain::user_timezone::UserTimezone;
+use yomu_backend_rust::shared::domain::user_timezone_repository::UserTimezoneRepository;
 
 // Mock definitions for UserRepository
 mock! {
@@ -24,7 +46,7 @@ mock! {
         async fn exists_shadow_user(&self, user_id: Uuid) -> Result<bool, AppError>;
         async fn check_exists(&self, user_id: Uuid) -> bool;
         async fn get_shadow_user(&self, user_id: Uuid) -> Result<Option<ShadowUser>, AppError>;
-        async fn update_total_score(&self, user_id: Uuid, score_to_add: i32) -> Result<(), AppError>;
+        async fn update_total_score(&self, user_id: Uuid, score_to_add: i32, quiz_id: Uuid, occurred_at: DateTime<Utc>) -> Result<(), AppError>;
     }
 }
 
@@ -34,8 +56,56 @@ mock! {
 
     #[async_trait]
     impl QuizHistoryRepository for QuizRepo {
-        async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<(), AppError>;
+        async fn insert_quiz_history(&self, quiz: &QuizHistory) -> Result<bool, AppError>;
         async fn get_quiz_histories_by_user(&self, user_id: Uuid) -> Result<Vec<QuizHistory>, AppError>;
+        async fn get_quiz_histories_since(&self, user_id: Uuid, since: DateTime<Utc>) -> Result<Vec<QuizHistory>, AppError>;
+        async fn list_quiz_user_ids(&self, after: Option<Uuid>, limit: i64) -> Result<Vec<Uuid>, AppError>;
+        async fn get_sync_receipt(&self, event_id: Uuid) -> Result<Option<QuizSyncReceipt>, AppError>;
+        async fn record_sync_result(&self, quiz_id: Uuid, missions_updated: i32) -> Result<(), AppError>;
+    }
+}
+
+// Mock definitions for QuizReviewRepository
+mock! {
+    ReviewRepo {}
+
+    #[async_trait]
+    impl QuizReviewRepository for ReviewRepo {
+        async fn quarantine(&self, quiz: &QuarantinedQuiz) -> Result<bool, AppError>;
+        async fn get(&self, id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError>;
+        async fn find_by_event(&self, event_id: Uuid) -> Result<Option<QuarantinedQuiz>, AppError>;
+        async fn list(&self, status: Option<QuizReviewStatus>, limit: i64) -> Result<Vec<QuarantinedQuiz>, AppError>;
+        async fn resolve(&self, id: Uuid, status: QuizReviewStatus, quiz_id: Option<Uuid>, at: DateTime<Utc>) -> Result<Option<QuarantinedQuiz>, AppError>;
+    }
+}
+
+// Mock definitions for UnitOfWork and its factory
+mock! {
+    Uow {}
+
+    #[async_trait]
+    impl UnitOfWork for Uow {
+        fn users(&self) -> Arc<dyn UserRepository>;
+        fn quiz_histories(&self) -> Arc<dyn QuizHistoryRepository>;
+        fn quiz_reviews(&self) -> Arc<dyn QuizReviewRepository>;
+        fn missions(&self) -> Arc<dyn MissionRepository>;
+        fn achievements(&self) -> Arc<dyn AchievementRepository>;
+        fn streaks(&self) -> Arc<dyn StreakRepository>;
+        fn clans(&self) -> Arc<dyn ClanRepository>;
+        fn outbox(&self) -> Arc<dyn OutboxRepository>;
+        fn timezones(&self) -> Arc<dyn UserTimezoneRepository>;
+        async fn lock_user(&self, user_id: Uuid) -> Result<(), AppError>;
+        async fn commit(&self) -> Result<(), AppError>;
+        async fn rollback(&self) -> Result<(), AppError>;
+    }
+}
+
+mock! {
+    UowFactory {}
+
+    #[async_trait]
+    impl UnitOfWorkFactory for UowFactory {
+        async fn begin(&self) -> Result<Box<dyn UnitOfWork>, AppError>;
     }
 }
 
@@ -61,7 +131,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_ok());
@@ -88,7 +161,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_ok());
@@ -112,7 +188,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id: nil_uuid };
+        let dto = SyncUserRequestDto {
+            user_id: nil_uuid,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_ok());
@@ -130,7 +209,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_err());
@@ -153,7 +235,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id };
+        let dto = SyncUserRequestDto {
+            user_id,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_err());
@@ -177,7 +262,10 @@ mod sync_new_user_tests {
             .once();
 
         let use_case = SyncNewUserUseCase::new(mock_repo);
-        let dto = SyncUserRequestDto { user_id: max_uuid };
+        let dto = SyncUserRequestDto {
+            user_id: max_uuid,
+            utc_offset_minutes: None,
+        };
 
         let result = use_case.execute(dto).await;
         assert!(result.is_ok());
@@ -204,13 +292,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(100))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(100),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -219,6 +312,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -246,6 +341,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -265,6 +362,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: -10,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -284,6 +383,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: -0.1,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -303,6 +404,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 100.1,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -324,13 +427,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(0))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(0),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -339,6 +447,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 0,
             accuracy: 0.0,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -360,13 +470,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(100))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(100),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -375,6 +490,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 1.0,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -405,6 +522,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -426,13 +545,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(100))
-            .return_once(|_, _| Err(AppError::InternalServer("Update failed".to_string())))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(100),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Err(AppError::InternalServer("Update failed".to_string())))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -441,6 +565,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -466,6 +592,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 0.95,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -487,13 +615,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(nil_uuid), mockall::predicate::eq(50))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(nil_uuid),
+                mockall::predicate::eq(50),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -502,6 +635,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 50,
             accuracy: 0.8,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -523,13 +658,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(75))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(75),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -538,6 +678,8 @@ mod sync_quiz_history_tests {
             article_id: nil_uuid,
             score: 75,
             accuracy: 0.9,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -559,7 +701,7 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
@@ -567,8 +709,10 @@ mod sync_quiz_history_tests {
             .with(
                 mockall::predicate::eq(user_id),
                 mockall::predicate::eq(i32::MAX),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
             )
-            .return_once(|_, _| Ok(()))
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -577,6 +721,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: i32::MAX,
             accuracy: 1.0,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -598,13 +744,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(0))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(0),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -613,6 +764,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 0,
             accuracy: 0.0,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -634,13 +787,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(100))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(100),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -649,6 +807,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 100,
             accuracy: 100.0,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -670,13 +830,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(10))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(10),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -685,6 +850,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 10,
             accuracy: 0.0000001,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -706,13 +873,18 @@ mod sync_quiz_history_tests {
 
         quiz_mock
             .expect_insert_quiz_history()
-            .return_once(|_| Ok(()))
+            .return_once(|_| Ok(true))
             .once();
 
         user_mock
             .expect_update_total_score()
-            .with(mockall::predicate::eq(user_id), mockall::predicate::eq(85))
-            .return_once(|_, _| Ok(()))
+            .with(
+                mockall::predicate::eq(user_id),
+                mockall::predicate::eq(85),
+                mockall::predicate::always(),
+                mockall::predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(()))
             .once();
 
         let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
@@ -721,6 +893,8 @@ mod sync_quiz_history_tests {
             article_id,
             score: 85,
             accuracy: 0.92,
+            utc_offset_minutes: None,
+            event_id: None,
         };
 
         let result = use_case.execute(dto).await;
@@ -729,4 +903,361 @@ mod sync_quiz_history_tests {
         assert_eq!(response.user_id, user_id);
         assert!(response.missions_updated >= 0);
     }
+
+    #[tokio::test]
+    async fn sync_quiz_history_replay_returns_first_result_without_rescoring() {
+        let user_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        let quiz_id = Uuid::new_v4();
+        let mut user_mock = MockUserRepo::new();
+        let mut quiz_mock = MockQuizRepo::new();
+
+        user_mock
+            .expect_exists_shadow_user()
+            .return_once(|_| Ok(true))
+            .once();
+
+        quiz_mock
+            .expect_insert_quiz_history()
+            .withf(move |quiz| quiz.event_id() == Some(event_id))
+            .return_once(|_| Ok(false))
+            .once();
+
+        quiz_mock
+            .expect_get_sync_receipt()
+            .with(mockall::predicate::eq(event_id))
+            .return_once(move |_| {
+                Ok(Some(QuizSyncReceipt {
+                    quiz_id,
+                    user_id,
+                    missions_updated: Some(2),
+                }))
+            })
+            .once();
+
+        user_mock.expect_update_total_score().never();
+
+        let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
+        let dto = QuizHistoryRequestDto {
+            user_id,
+            article_id: Uuid::new_v4(),
+            score: 85,
+            accuracy: 0.92,
+            utc_offset_minutes: None,
+            event_id: Some(event_id),
+        };
+
+        let response = use_case.execute(dto).await.unwrap();
+        assert!(response.replayed);
+        assert_eq!(response.quiz_id, quiz_id);
+        assert_eq!(response.missions_updated, 2);
+    }
+
+    #[tokio::test]
+    async fn sync_quiz_history_first_delivery_is_not_a_replay() {
+        let user_id = Uuid::new_v4();
+        let mut user_mock = MockUserRepo::new();
+        let mut quiz_mock = MockQuizRepo::new();
+
+        user_mock
+            .expect_exists_shadow_user()
+            .return_once(|_| Ok(true))
+            .once();
+
+        quiz_mock
+            .expect_insert_quiz_history()
+            .return_once(|_| Ok(true))
+            .once();
+        quiz_mock.expect_get_sync_receipt().never();
+
+        user_mock
+            .expect_update_total_score()
+            .return_once(|_, _, _, _| Ok(()))
+            .once();
+
+        let use_case = SyncQuizHistoryUseCase::new(user_mock, quiz_mock);
+        let dto = QuizHistoryRequestDto {
+            user_id,
+            article_id: Uuid::new_v4(),
+            score: 85,
+            accuracy: 0.92,
+            utc_offset_minutes: None,
+            event_id: Some(Uuid::new_v4()),
+        };
+
+        let response = use_case.execute(dto).await.unwrap();
+        assert!(!response.replayed);
+    }
+}
+
+// ============== ProcessQuizSyncUseCase Tests ==============
+
+mod process_quiz_sync_tests {
+    use super::*;
+
+    fn factory_for(uow: MockUow) -> Arc<dyn UnitOfWorkFactory> {
+        let mut factory = MockUowFactory::new();
+        factory
+            .expect_begin()
+            .return_once(move || Ok(Box::new(uow)))
+            .once();
+        Arc::new(factory)
+    }
+
+    fn quiz_dto(user_id: Uuid, event_id: Option<Uuid>) -> QuizHistoryRequestDto {
+        QuizHistoryRequestDto {
+            user_id,
+            article_id: Uuid::new_v4(),
+            score: 85,
+            accuracy: 92.0,
+            utc_offset_minutes: None,
+            event_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn process_quiz_sync_rolls_back_when_a_step_fails() {
+        let mut user_mock = MockUserRepo::new();
+        user_mock
+            .expect_exists_shadow_user()
+            .return_once(|_| Ok(true))
+            .once();
+        user_mock
+            .expect_update_total_score()
+            .return_once(|_, _, _, _| Err(AppError::InternalServer("db down".to_string())))
+            .once();
+
+        let mut quiz_mock = MockQuizRepo::new();
+        quiz_mock
+            .expect_insert_quiz_history()
+            .return_once(|_| Ok(true))
+            .once();
+
+        let mut uow = MockUow::new();
+        uow.expect_lock_user().returning(|_| Ok(()));
+        uow.expect_users()
+            .return_const(Arc::new(user_mock) as Arc<dyn UserRepository>);
+        uow.expect_quiz_histories()
+            .return_const(Arc::new(quiz_mock) as Arc<dyn QuizHistoryRepository>);
+        uow.expect_missions().never();
+        uow.expect_commit().never();
+        uow.expect_rollback().return_once(|| Ok(())).once();
+
+        let use_case = ProcessQuizSyncUseCase::new(
+            factory_for(uow),
+            StreakPolicy::default(),
+            UserTimezone::UTC,
+        );
+        let result = use_case.execute(quiz_dto(Uuid::new_v4(), None)).await;
+
+        assert!(matches!(result, Err(UserSyncError::DatabaseError(_))));
+    }
+
+    #[tokio::test]
+    async fn process_quiz_sync_commits_a_replay_without_gamification() {
+        let user_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        let quiz_id = Uuid::new_v4();
+
+        let mut user_mock = MockUserRepo::new();
+        user_mock
+            .expect_exists_shadow_user()
+            .return_once(|_| Ok(true))
+            .once();
+        user_mock.expect_update_total_score().never();
+
+        let mut quiz_mock = MockQuizRepo::new();
+        quiz_mock
+            .expect_insert_quiz_history()
+            .return_once(|_| Ok(false))
+            .once();
+        quiz_mock
+            .expect_get_sync_receipt()
+            .return_once(move |_| {
+                Ok(Some(QuizSyncReceipt {
+                    quiz_id,
+                    user_id,
+                    missions_updated: Some(1),
+                }))
+            })
+            .once();
+
+        let mut uow = MockUow::new();
+        uow.expect_lock_user().returning(|_| Ok(()));
+        uow.expect_users()
+            .return_const(Arc::new(user_mock) as Arc<dyn UserRepository>);
+        uow.expect_quiz_histories()
+            .return_const(Arc::new(quiz_mock) as Arc<dyn QuizHistoryRepository>);
+        uow.expect_missions().never();
+        uow.expect_achievements().never();
+        uow.expect_commit().return_once(|| Ok(())).once();
+        uow.expect_rollback().never();
+
+        let use_case = ProcessQuizSyncUseCase::new(
+            factory_for(uow),
+            StreakPolicy::default(),
+            UserTimezone::UTC,
+        );
+        let response = use_case
+            .execute(quiz_dto(user_id, Some(event_id)))
+            .await
+            .unwrap();
+
+        assert!(response.replayed);
+        assert_eq!(response.quiz_id, quiz_id);
+        assert_eq!(response.missions_updated, 1);
+    }
+}
+
+// ============== Quiz Fraud Screening Tests ==============
+
+mod quiz_screening_tests {
+    use super::*;
+
+    fn quiz_dto(user_id: Uuid, event_id: Option<Uuid>) -> QuizHistoryRequestDto {
+        QuizHistoryRequestDto {
+            user_id,
+            article_id: Uuid::new_v4(),
+            score: 85,
+            accuracy: 92.0,
+            utc_offset_minutes: None,
+            event_id,
+        }
+    }
+
+    fn known_user() -> MockUserRepo {
+        let mut user_mock = MockUserRepo::new();
+        user_mock
+            .expect_exists_shadow_user()
+            .return_once(|_| Ok(true))
+            .once();
+        user_mock.expect_update_total_score().never();
+        user_mock
+    }
+
+    #[tokio::test]
+    async fn flagged_quiz_is_held_instead_of_recorded() {
+        let user_id = Uuid::new_v4();
+        let mut quiz_mock = MockQuizRepo::new();
+        quiz_mock
+            .expect_get_quiz_histories_since()
+            .return_once(move |_, _| {
+                Ok(vec![QuizHistory::with_id(
+                    Uuid::new_v4(),
+                    user_id,
+                    Uuid::new_v4(),
+                    70,
+                    80.0,
+                    Utc::now() - TimeDelta::seconds(3),
+                )])
+            })
+            .once();
+        quiz_mock.expect_insert_quiz_history().never();
+
+        let mut review_mock = MockReviewRepo::new();
+        review_mock
+            .expect_quarantine()
+            .withf(move |held| held.user_id == user_id && held.score == 85)
+            .return_once(|_| Ok(true))
+            .once();
+
+        let use_case = SyncQuizHistoryUseCase::new(known_user(), quiz_mock).with_fraud_screening(
+            QuizFraudChain::standard(&QuizFraudPolicy::default()),
+            Arc::new(review_mock),
+        );
+        let response = use_case.execute(quiz_dto(user_id, None)).await.unwrap();
+
+        let quarantine = response.quarantine.unwrap();
+        assert_eq!(quarantine.reason, "impossible_frequency");
+        assert_eq!(response.missions_updated, 0);
+        assert!(!response.replayed);
+    }
+
+    #[tokio::test]
+    async fn redelivered_held_event_is_answered_from_the_review_queue() {
+        let user_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        let held = QuarantinedQuiz::hold(
+            &QuizHistory::new(user_id, Uuid::new_v4(), 85, 92.0).with_event_id(Some(event_id)),
+            QuizFraudFlag {
+                reason: QuizFraudReason::ArticleSpam,
+                detail: "berulang".to_string(),
+            },
+        );
+        let review_id = held.id;
+
+        let mut quiz_mock = MockQuizRepo::new();
+        quiz_mock
+            .expect_get_sync_receipt()
+            .return_once(|_| Ok(None))
+            .once();
+        quiz_mock.expect_get_quiz_histories_since().never();
+        quiz_mock.expect_insert_quiz_history().never();
+
+        let mut review_mock = MockReviewRepo::new();
+        review_mock
+            .expect_find_by_event()
+            .with(mockall::predicate::eq(event_id))
+            .return_once(move |_| Ok(Some(held)))
+            .once();
+        review_mock.expect_quarantine().never();
+
+        let use_case = SyncQuizHistoryUseCase::new(known_user(), quiz_mock).with_fraud_screening(
+            QuizFraudChain::standard(&QuizFraudPolicy::default()),
+            Arc::new(review_mock),
+        );
+        let response = use_case
+            .execute(quiz_dto(user_id, Some(event_id)))
+            .await
+            .unwrap();
+
+        assert!(response.replayed);
+        assert_eq!(response.quarantine.unwrap().review_id, review_id);
+    }
+
+    #[tokio::test]
+    async fn process_quiz_sync_commits_a_held_quiz_without_gamification() {
+        let user_id = Uuid::new_v4();
+        let mut quiz_mock = MockQuizRepo::new();
+        quiz_mock
+            .expect_get_quiz_histories_since()
+            .return_once(|_, _| Ok(vec![]))
+            .once();
+        quiz_mock.expect_insert_quiz_history().never();
+        let mut review_mock = MockReviewRepo::new();
+        review_mock
+            .expect_quarantine()
+            .return_once(|_| Ok(true))
+            .once();
+
+        let mut uow = MockUow::new();
+        uow.expect_lock_user().returning(|_| Ok(()));
+        uow.expect_users()
+            .return_const(Arc::new(known_user()) as Arc<dyn UserRepository>);
+        uow.expect_quiz_histories()
+            .return_const(Arc::new(quiz_mock) as Arc<dyn QuizHistoryRepository>);
+        uow.expect_quiz_reviews()
+            .return_const(Arc::new(review_mock) as Arc<dyn QuizReviewRepository>);
+        uow.expect_missions().never();
+        uow.expect_timezones().never();
+        uow.expect_commit().return_once(|| Ok(())).once();
+        uow.expect_rollback().never();
+        let mut factory = MockUowFactory::new();
+        factory
+            .expect_begin()
+            .return_once(move || Ok(Box::new(uow)))
+            .once();
+
+        let use_case = ProcessQuizSyncUseCase::new(
+            Arc::new(factory),
+            StreakPolicy::default(),
+            UserTimezone::UTC,
+        )
+        .with_fraud_detection(QuizFraudChain::standard(&QuizFraudPolicy::default()));
+        let mut dto = quiz_dto(user_id, None);
+        dto.accuracy = 0.0;
+        let response = use_case.execute(dto).await.unwrap();
+
+        assert_eq!(response.quarantine.unwrap().reason, "score_inconsistent");
+    }
 }